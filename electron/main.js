@@ -2,6 +2,7 @@ import { app, BrowserWindow, globalShortcut, ipcMain, screen, Tray, Menu, native
 import path from 'path';
 import { fileURLToPath } from 'url';
 import fs from 'fs';
+import { randomUUID } from 'crypto';
 import yaml from 'yaml';
 import { StartupTimer, LazyModuleLoader, ConfigCache, DeferredInitializer } from './startupOptimization.js';
 import { MemoryOptimizer } from './memoryOptimization.js';
@@ -195,10 +196,10 @@ function saveConfig() {
   }
 }
 
-// Create default configuration
+// Create default configuration (config schema 1.1, shared with the Tauri app)
 function createDefaultConfig() {
   return {
-    version: '1.0',
+    version: '1.1',
     ui: {
       summon: {
         hotkeys: ['F11'],
@@ -235,49 +236,42 @@ function createDefaultConfig() {
             cols: 6,
             buttons: [
               {
+                id: randomUUID(),
                 position: { row: 1, col: 1 },
-                action_type: 'LaunchApp',
                 label: 'Notepad',
                 icon: '📝',
-                config: {
-                  path: 'notepad.exe'
-                },
                 action: {
-                  action_type: 'app'
+                  type: 'LaunchApp',
+                  path: 'notepad.exe'
                 }
               },
               {
+                id: randomUUID(),
                 position: { row: 1, col: 2 },
-                action_type: 'Open',
                 label: 'Documents',
                 icon: '📁',
-                config: {
-                  target: process.env.USERPROFILE + '\\Documents'
-                },
                 action: {
-                  action_type: 'app'
+                  type: 'Open',
+                  target: process.env.USERPROFILE + '\\Documents'
                 }
               },
               {
+                id: randomUUID(),
                 position: { row: 1, col: 3 },
-                action_type: 'LaunchApp',
                 label: 'Calculator',
                 icon: '🔢',
-                config: {
-                  path: 'calc.exe'
-                },
                 action: {
-                  action_type: 'app'
+                  type: 'LaunchApp',
+                  path: 'calc.exe'
                 }
               },
               {
+                id: randomUUID(),
                 position: { row: 2, col: 1 },
-                action_type: 'system',
                 label: 'Settings',
                 icon: '⚙️',
-                config: {},
                 action: {
-                  action_type: 'system',
+                  type: 'System',
                   system_action: 'config'
                 }
               }
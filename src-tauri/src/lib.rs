@@ -1,18 +1,25 @@
 use std::sync::Mutex;
-use tauri::{State, Manager, Emitter};
+use tauri::{State, Manager, Emitter, Listener};
 use anyhow::Result;
 
 mod modules;
 
 use modules::{
-    config::{ConfigManager, QDeckConfig},
+    config::{ConfigBackup, ConfigManager, QDeckConfig},
+    validation::ValidationReport,
     logger::{LoggerService, ActionLog},
-    hotkey::{HotkeyService, HotkeyConfig, ParsedHotkey},
-    action::ActionRunner,
+    hotkey::{HotkeyService, HotkeyConfig, HotkeyConflictReport, ParsedHotkey},
+    keys::{self, KeyInfo},
+    action::{ActionConfig, ActionContext, ActionOutputLine, ActionRunner, ActionStarted, SystemAction},
+    action_registry::{ActionRegistry, RunningAction},
     profile::ProfileManager,
-    window::{WindowManager, WindowConfig},
+    window::{WindowManager, WindowConfig, TauriCursorSource},
+    placement::{MonitorPolicy, OverlayGrid, Placement},
     icon::{IconService, IconInfo, CacheStats},
     drag_drop::{DragDropService, DroppedFile, ButtonGenerationRequest, ButtonGenerationResult, UndoOperation},
+    config_watcher::ConfigWatcher,
+    foreground::{self, ForegroundWatcher, ForegroundWindow, ProfileAutoSwitcher},
+    edge_trigger::EdgeTriggerWatcher,
 };
 
 // Application state
@@ -20,11 +27,17 @@ pub struct AppState {
     config_manager: Mutex<ConfigManager>,
     logger_service: Mutex<LoggerService>,
     hotkey_service: Mutex<HotkeyService>,
+    hotkey_report: Mutex<HotkeyConflictReport>, // From the last time the config's hotkeys were registered
     action_runner: Mutex<ActionRunner>,
+    action_registry: ActionRegistry, // Actions still running, by invocation ID
     profile_manager: Mutex<ProfileManager>,
     window_manager: Mutex<WindowManager>,
+    hovered_button: Mutex<Option<String>>, // Button under the pointer, run when a held summon key is let go
     icon_service: Mutex<IconService>,
     drag_drop_service: Mutex<DragDropService>,
+    _config_watcher: ConfigWatcher, // Stops polling when the app state is dropped
+    _foreground_watcher: Option<ForegroundWatcher>, // None when the platform can't report the focused window
+    edge_trigger_watcher: Mutex<Option<EdgeTriggerWatcher>>, // None while the edge trigger is off
 }
 
 // Tauri commands
@@ -35,25 +48,65 @@ async fn get_config(state: State<'_, AppState>) -> Result<QDeckConfig, String> {
 }
 
 #[tauri::command]
-async fn save_config(config: QDeckConfig, state: State<'_, AppState>) -> Result<(), String> {
+async fn save_config(config: QDeckConfig, app: tauri::AppHandle, state: State<'_, AppState>) -> Result<(), String> {
     tracing::info!("💾 save_config command called");
     tracing::debug!("💾 Config to save: {:#?}", config);
     
-    let mut config_manager = state.config_manager.lock().map_err(|e| {
-        tracing::error!("❌ Failed to lock config_manager: {}", e);
-        e.to_string()
-    })?;
-    
-    match config_manager.update_config(config) {
-        Ok(_) => {
-            tracing::info!("✅ Configuration saved successfully");
-            Ok(())
-        }
-        Err(e) => {
+    let config = {
+        let mut config_manager = state.config_manager.lock().map_err(|e| {
+            tracing::error!("❌ Failed to lock config_manager: {}", e);
+            e.to_string()
+        })?;
+        
+        if let Err(e) = config_manager.update_config(config) {
             tracing::error!("❌ Failed to save configuration: {}", e);
-            Err(e.to_string())
+            return Err(e.to_string());
         }
-    }
+        tracing::info!("✅ Configuration saved successfully");
+        config_manager.get_config().clone()
+    };
+    
+    // The watcher sees our own write as unchanged, so apply hotkeys, summon and placement here
+    apply_config(&app, &config);
+    Ok(())
+}
+
+#[tauri::command]
+async fn validate_config(config: Option<QDeckConfig>, state: State<'_, AppState>) -> Result<ValidationReport, String> {
+    let config_manager = state.config_manager.lock().map_err(|e| e.to_string())?;
+    let report = config_manager.validation_report(config.as_ref());
+    tracing::debug!("🔍 Config validation: {} error(s), {} warning(s)", report.errors().count(), report.warnings().count());
+    Ok(report)
+}
+
+// Why config.yaml couldn't be loaded at startup (a backup or the defaults are in use instead)
+#[tauri::command]
+async fn get_config_load_error(state: State<'_, AppState>) -> Result<Option<String>, String> {
+    let config_manager = state.config_manager.lock().map_err(|e| e.to_string())?;
+    Ok(config_manager.load_error().map(str::to_string))
+}
+
+#[tauri::command]
+async fn list_config_backups(state: State<'_, AppState>) -> Result<Vec<ConfigBackup>, String> {
+    let config_manager = state.config_manager.lock().map_err(|e| e.to_string())?;
+    config_manager.list_backups().map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+async fn restore_config_backup(file_name: String, app: tauri::AppHandle, state: State<'_, AppState>) -> Result<QDeckConfig, String> {
+    tracing::info!("♻️ restore_config_backup command called: {}", file_name);
+    
+    let config = {
+        let mut config_manager = state.config_manager.lock().map_err(|e| e.to_string())?;
+        config_manager.restore_backup(&file_name).map_err(|e| {
+            tracing::error!("❌ Failed to restore config backup: {:#}", e);
+            format!("{:#}", e)
+        })?;
+        config_manager.get_config().clone()
+    };
+    
+    apply_config(&app, &config);
+    Ok(config)
 }
 
 #[tauri::command]
@@ -88,64 +141,110 @@ async fn update_overlay_config(config: WindowConfig, state: State<'_, AppState>)
 
 #[tauri::command]
 async fn position_overlay(placement: String, state: State<'_, AppState>) -> Result<(), String> {
+    let placement = Placement::from_name(&placement).map_err(|e| e.to_string())?;
     let window_manager = state.window_manager.lock().map_err(|e| e.to_string())?;
-    match placement.as_str() {
-        "dropdown-top" => window_manager.position_overlay_dropdown_top().map_err(|e| e.to_string()),
-        "center" => window_manager.position_overlay_center().map_err(|e| e.to_string()),
-        _ => Err(format!("Unknown placement: {}", placement)),
-    }
+    window_manager.position_overlay(placement).map_err(|e| e.to_string())
 }
 
 #[tauri::command]
-async fn execute_action(action_id: String, state: State<'_, AppState>) -> Result<modules::action::ActionResult, String> {
+async fn execute_action(action_id: String, app: tauri::AppHandle) -> Result<modules::action::ActionResult, String> {
     tracing::info!("🎯 Execute action command called: {}", action_id);
+    run_button(&app, &action_id).await
+}
+
+// Run a button by ID; shared by the execute_action command and button hotkeys
+async fn run_button(app: &tauri::AppHandle, action_id: &str) -> Result<modules::action::ActionResult, String> {
+    let state = app.state::<AppState>();
     
-    // Clone the config to avoid holding locks across await
-    let config = {
+    // Resolve the button through the ID index and clone what we need to avoid holding locks across await
+    let (config, location, button) = {
         let config_manager = state.config_manager.lock().map_err(|e| {
             tracing::error!("❌ Failed to lock config_manager: {}", e);
             e.to_string()
         })?;
-        config_manager.get_config().clone()
+        let (location, button) = config_manager.find_button(action_id)
+            .ok_or_else(|| format!("Action not found: {}", action_id))?;
+        (config_manager.get_config().clone(), location.clone(), button.clone())
     };
+    tracing::info!("✅ Found button '{}' at {:?}", button.label, location);
     
     // Create a new action runner to avoid holding locks across await
     let action_runner = modules::action::ActionRunner::new().map_err(|e| e.to_string())?;
     
-    // Find the action in the configuration
-    let mut action_config = None;
-    tracing::info!("🔍 Searching for action: {}", action_id);
-    
-    'outer: for profile in &config.profiles {
-        tracing::debug!("🔍 Checking profile: {}", profile.name);
-        for page in &profile.pages {
-            tracing::debug!("🔍 Checking page: {}", page.name);
-            for button in &page.buttons {
-                // Create a unique ID for each button (profile:page:row:col)
-                let button_id = format!("{}:{}:{}:{}", 
-                    profile.name, page.name, button.position.row, button.position.col);
-                
-                tracing::debug!("🔍 Checking button: {} (ID: {}, Label: {})", button.label, button_id, button.label);
-                
-                if button_id == action_id || button.label == action_id {
-                    tracing::info!("✅ Found matching button: {}", button.label);
-                    // Convert button config to ActionConfig
-                    if let Some(config) = button_to_action_config(button) {
-                        action_config = Some(config);
-                        break 'outer;
-                    } else {
-                        tracing::warn!("⚠️ Failed to convert button config for: {}", button.label);
-                    }
-                }
+    // Folder buttons navigate instead of running an action
+    if button.folder().is_some() {
+        let start_time = std::time::Instant::now();
+        let context = {
+            let mut profile_manager = state.profile_manager.lock().map_err(|e| e.to_string())?;
+            profile_manager.open_folder_at(&location, &config).map_err(|e| e.to_string())?;
+            fit_overlay_to_page(app, &profile_manager, &config);
+            profile_manager.get_navigation_context(&config).map_err(|e| e.to_string())?
+        };
+        
+        if let Err(e) = app.emit("folder-changed", &context) {
+            tracing::error!("Failed to emit folder-changed event: {}", e);
+        }
+        
+        return Ok(modules::action::ActionResult {
+            success: true,
+            message: format!("Opened folder '{}'", context.page_name),
+            execution_time_ms: start_time.elapsed().as_millis() as u64,
+            output: None,
+            error_code: None,
+            invocation_id: None,
+        });
+    }
+    
+    // System buttons drive the overlay; the settings screen is opened by the frontend
+    if let ActionConfig::System { system_action } = button.action {
+        let start_time = std::time::Instant::now();
+        {
+            let window_manager = state.window_manager.lock().map_err(|e| e.to_string())?;
+            match system_action {
+                SystemAction::Config => window_manager.show_overlay(),
+                SystemAction::ToggleOverlay => window_manager.toggle_overlay(),
+                SystemAction::Back | SystemAction::ExitConfig | SystemAction::HideOverlay => window_manager.hide_overlay(),
+            }.map_err(|e| e.to_string())?;
+        }
+        
+        if system_action == SystemAction::Config {
+            if let Err(e) = app.emit("system-action", system_action) {
+                tracing::error!("Failed to emit system-action event: {}", e);
             }
         }
+        
+        return Ok(modules::action::ActionResult {
+            success: true,
+            message: format!("Ran system action {:?}", system_action),
+            execution_time_ms: start_time.elapsed().as_millis() as u64,
+            output: None,
+            error_code: None,
+            invocation_id: None,
+        });
     }
     
-    let action_config = action_config.ok_or_else(|| format!("Action not found: {}", action_id))?;
+    // Output of actions that wait on their process is streamed to the frontend line by line
+    let output_app = app.clone();
+    let ctx = ActionContext::begin(&state.action_registry, Some(action_id), &button.action)
+        .with_output_sink(std::sync::Arc::new(move |line: &ActionOutputLine| {
+            if let Err(e) = output_app.emit("action-output", line) {
+                tracing::debug!("Failed to emit action-output event: {}", e);
+            }
+        }));
+    
+    // The result only arrives once the process exits; announce the invocation ID now
+    let started = ActionStarted { invocation_id: ctx.invocation_id.clone(), button_id: Some(action_id.to_string()) };
+    if let Err(e) = app.emit("action-started", &started) {
+        tracing::error!("Failed to emit action-started event: {}", e);
+    }
+    let execution = action_runner.execute_action(&button.action, &ctx).await;
+    let cancelled = state.action_registry.finish(&ctx.invocation_id);
     
-    match action_runner.execute_action_config(&action_config).await {
+    match execution {
         Ok(result) => {
-            if result.success {
+            if cancelled {
+                tracing::warn!("🛑 Action '{}' was cancelled after {}ms", action_id, result.execution_time_ms);
+            } else if result.success {
                 tracing::info!("✅ Action '{}' executed successfully in {}ms", action_id, result.execution_time_ms);
             } else {
                 tracing::error!("❌ Action '{}' failed: {}", action_id, result.message);
@@ -157,15 +256,17 @@ async fn execute_action(action_id: String, state: State<'_, AppState>) -> Result
                 let log_entry = modules::logger::ActionLog {
                     timestamp: chrono::Utc::now(),
                     action_type: "execute_action".to_string(),
-                    action_id: action_id.clone(),
-                    result: if result.success { 
+                    action_id: action_id.to_string(),
+                    result: if cancelled {
+                        modules::logger::ActionResult::Cancelled
+                    } else if result.success { 
                         modules::logger::ActionResult::Success 
                     } else { 
                         modules::logger::ActionResult::Failed 
                     },
                     execution_time_ms: result.execution_time_ms,
                     error_message: if result.success { None } else { Some(result.message.clone()) },
-                    context: std::collections::HashMap::new(),
+                    context: invocation_log_context(&ctx.invocation_id),
                 };
                 
                 if let Err(e) = logger.log_action(log_entry) {
@@ -182,6 +283,42 @@ async fn execute_action(action_id: String, state: State<'_, AppState>) -> Result
     }
 }
 
+// Ties a log entry to the run it records
+fn invocation_log_context(invocation_id: &str) -> std::collections::HashMap<String, serde_json::Value> {
+    std::collections::HashMap::from([("invocation_id".to_string(), serde_json::Value::from(invocation_id))])
+}
+
+#[tauri::command]
+async fn list_running_actions(state: State<'_, AppState>) -> Result<Vec<RunningAction>, String> {
+    Ok(state.action_registry.list())
+}
+
+#[tauri::command]
+async fn cancel_action(invocation_id: String, state: State<'_, AppState>) -> Result<RunningAction, String> {
+    tracing::info!("🛑 Cancel action command called: {}", invocation_id);
+    let cancelled = state.action_registry.cancel(&invocation_id).map_err(|e| e.to_string())?;
+    
+    // An action call that is still running logs the cancellation itself when it returns
+    if cancelled.returned {
+        let logger = state.logger_service.lock().map_err(|e| e.to_string())?;
+        let log_entry = modules::logger::ActionLog {
+            timestamp: chrono::Utc::now(),
+            action_type: "execute_action".to_string(),
+            action_id: cancelled.action.button_id.clone().unwrap_or_else(|| invocation_id.clone()),
+            result: modules::logger::ActionResult::Cancelled,
+            execution_time_ms: cancelled.action.elapsed_ms,
+            error_message: None,
+            context: invocation_log_context(&invocation_id),
+        };
+        
+        if let Err(e) = logger.log_action(log_entry) {
+            tracing::warn!("⚠️ Failed to log action cancellation: {}", e);
+        }
+    }
+    
+    Ok(cancelled.action)
+}
+
 #[tauri::command]
 async fn get_recent_logs(limit: usize, state: State<'_, AppState>) -> Result<Vec<ActionLog>, String> {
     let logger = state.logger_service.lock().map_err(|e| e.to_string())?;
@@ -200,6 +337,12 @@ async fn unregister_hotkey(id: u32, state: State<'_, AppState>) -> Result<(), St
     hotkey_service.unregister_hotkey(id).map_err(|e| e.to_string())
 }
 
+#[tauri::command]
+async fn replace_hotkey(id: u32, hotkey_str: String, state: State<'_, AppState>) -> Result<(), String> {
+    let mut hotkey_service = state.hotkey_service.lock().map_err(|e| e.to_string())?;
+    hotkey_service.replace_hotkey(id, &hotkey_str).map_err(|e| e.to_string())
+}
+
 #[tauri::command]
 async fn register_multiple_hotkeys(hotkey_configs: Vec<HotkeyConfig>, state: State<'_, AppState>) -> Result<Vec<u32>, String> {
     let mut hotkey_service = state.hotkey_service.lock().map_err(|e| e.to_string())?;
@@ -212,6 +355,24 @@ async fn get_registered_hotkeys(state: State<'_, AppState>) -> Result<Vec<Parsed
     Ok(hotkey_service.get_registered_hotkeys())
 }
 
+#[tauri::command]
+async fn get_hotkey_conflict_report(state: State<'_, AppState>) -> Result<HotkeyConflictReport, String> {
+    let hotkey_report = state.hotkey_report.lock().map_err(|e| e.to_string())?;
+    Ok(hotkey_report.clone())
+}
+
+#[tauri::command]
+async fn set_hovered_button(button_id: Option<String>, state: State<'_, AppState>) -> Result<(), String> {
+    let mut hovered_button = state.hovered_button.lock().map_err(|e| e.to_string())?;
+    *hovered_button = button_id;
+    Ok(())
+}
+
+#[tauri::command]
+async fn list_supported_keys() -> Result<Vec<KeyInfo>, String> {
+    Ok(keys::supported_keys())
+}
+
 #[tauri::command]
 async fn is_hotkey_available(hotkey_str: String, state: State<'_, AppState>) -> Result<bool, String> {
     let hotkey_service = state.hotkey_service.lock().map_err(|e| e.to_string())?;
@@ -259,19 +420,23 @@ async fn get_current_profile(state: State<'_, AppState>) -> Result<modules::prof
 }
 
 #[tauri::command]
-async fn switch_to_profile(profile_index: usize, state: State<'_, AppState>) -> Result<modules::profile::ProfileInfo, String> {
+async fn switch_to_profile(profile_index: usize, app: tauri::AppHandle, state: State<'_, AppState>) -> Result<modules::profile::ProfileInfo, String> {
     let mut profile_manager = state.profile_manager.lock().map_err(|e| e.to_string())?;
     let config_manager = state.config_manager.lock().map_err(|e| e.to_string())?;
     
-    profile_manager.switch_to_profile(profile_index, config_manager.get_config()).map_err(|e| e.to_string())
+    let profile_info = profile_manager.switch_to_profile(profile_index, config_manager.get_config()).map_err(|e| e.to_string())?;
+    fit_overlay_to_page(&app, &profile_manager, config_manager.get_config());
+    Ok(profile_info)
 }
 
 #[tauri::command]
-async fn switch_to_profile_by_name(profile_name: String, state: State<'_, AppState>) -> Result<modules::profile::ProfileInfo, String> {
+async fn switch_to_profile_by_name(profile_name: String, app: tauri::AppHandle, state: State<'_, AppState>) -> Result<modules::profile::ProfileInfo, String> {
     let mut profile_manager = state.profile_manager.lock().map_err(|e| e.to_string())?;
     let config_manager = state.config_manager.lock().map_err(|e| e.to_string())?;
     
-    profile_manager.switch_to_profile_by_name(&profile_name, config_manager.get_config()).map_err(|e| e.to_string())
+    let profile_info = profile_manager.switch_to_profile_by_name(&profile_name, config_manager.get_config()).map_err(|e| e.to_string())?;
+    fit_overlay_to_page(&app, &profile_manager, config_manager.get_config());
+    Ok(profile_info)
 }
 
 #[tauri::command]
@@ -291,27 +456,33 @@ async fn get_current_page(state: State<'_, AppState>) -> Result<modules::profile
 }
 
 #[tauri::command]
-async fn switch_to_page(page_index: usize, state: State<'_, AppState>) -> Result<modules::profile::PageInfo, String> {
+async fn switch_to_page(page_index: usize, app: tauri::AppHandle, state: State<'_, AppState>) -> Result<modules::profile::PageInfo, String> {
     let mut profile_manager = state.profile_manager.lock().map_err(|e| e.to_string())?;
     let config_manager = state.config_manager.lock().map_err(|e| e.to_string())?;
     
-    profile_manager.switch_to_page(page_index, config_manager.get_config()).map_err(|e| e.to_string())
+    let page_info = profile_manager.switch_to_page(page_index, config_manager.get_config()).map_err(|e| e.to_string())?;
+    fit_overlay_to_page(&app, &profile_manager, config_manager.get_config());
+    Ok(page_info)
 }
 
 #[tauri::command]
-async fn next_page(state: State<'_, AppState>) -> Result<modules::profile::PageInfo, String> {
+async fn next_page(app: tauri::AppHandle, state: State<'_, AppState>) -> Result<modules::profile::PageInfo, String> {
     let mut profile_manager = state.profile_manager.lock().map_err(|e| e.to_string())?;
     let config_manager = state.config_manager.lock().map_err(|e| e.to_string())?;
     
-    profile_manager.next_page(config_manager.get_config()).map_err(|e| e.to_string())
+    let page_info = profile_manager.next_page(config_manager.get_config()).map_err(|e| e.to_string())?;
+    fit_overlay_to_page(&app, &profile_manager, config_manager.get_config());
+    Ok(page_info)
 }
 
 #[tauri::command]
-async fn previous_page(state: State<'_, AppState>) -> Result<modules::profile::PageInfo, String> {
+async fn previous_page(app: tauri::AppHandle, state: State<'_, AppState>) -> Result<modules::profile::PageInfo, String> {
     let mut profile_manager = state.profile_manager.lock().map_err(|e| e.to_string())?;
     let config_manager = state.config_manager.lock().map_err(|e| e.to_string())?;
     
-    profile_manager.previous_page(config_manager.get_config()).map_err(|e| e.to_string())
+    let page_info = profile_manager.previous_page(config_manager.get_config()).map_err(|e| e.to_string())?;
+    fit_overlay_to_page(&app, &profile_manager, config_manager.get_config());
+    Ok(page_info)
 }
 
 #[tauri::command]
@@ -322,6 +493,287 @@ async fn get_navigation_context(state: State<'_, AppState>) -> Result<modules::p
     profile_manager.get_navigation_context(config_manager.get_config()).map_err(|e| e.to_string())
 }
 
+#[tauri::command]
+async fn enter_folder(row: u32, col: u32, app: tauri::AppHandle, state: State<'_, AppState>) -> Result<modules::profile::PageInfo, String> {
+    let mut profile_manager = state.profile_manager.lock().map_err(|e| e.to_string())?;
+    let config_manager = state.config_manager.lock().map_err(|e| e.to_string())?;
+    
+    let page_info = profile_manager.enter_folder(row, col, config_manager.get_config()).map_err(|e| e.to_string())?;
+    emit_folder_changed(&app, &profile_manager, config_manager.get_config());
+    fit_overlay_to_page(&app, &profile_manager, config_manager.get_config());
+    Ok(page_info)
+}
+
+#[tauri::command]
+async fn exit_folder(app: tauri::AppHandle, state: State<'_, AppState>) -> Result<modules::profile::PageInfo, String> {
+    let mut profile_manager = state.profile_manager.lock().map_err(|e| e.to_string())?;
+    let config_manager = state.config_manager.lock().map_err(|e| e.to_string())?;
+    
+    let page_info = profile_manager.exit_folder(config_manager.get_config()).map_err(|e| e.to_string())?;
+    emit_folder_changed(&app, &profile_manager, config_manager.get_config());
+    fit_overlay_to_page(&app, &profile_manager, config_manager.get_config());
+    Ok(page_info)
+}
+
+#[tauri::command]
+async fn exit_all_folders(app: tauri::AppHandle, state: State<'_, AppState>) -> Result<modules::profile::PageInfo, String> {
+    let mut profile_manager = state.profile_manager.lock().map_err(|e| e.to_string())?;
+    let config_manager = state.config_manager.lock().map_err(|e| e.to_string())?;
+    
+    let page_info = profile_manager.exit_all_folders(config_manager.get_config()).map_err(|e| e.to_string())?;
+    emit_folder_changed(&app, &profile_manager, config_manager.get_config());
+    fit_overlay_to_page(&app, &profile_manager, config_manager.get_config());
+    Ok(page_info)
+}
+
+// Apply an externally edited config.yaml to the running app
+fn reload_config(app: &tauri::AppHandle) {
+    let state = match app.try_state::<AppState>() {
+        Some(state) => state,
+        None => return,
+    };
+    
+    let config = {
+        let mut config_manager = match state.config_manager.lock() {
+            Ok(cm) => cm,
+            Err(e) => {
+                tracing::error!("Failed to lock config manager: {}", e);
+                return;
+            }
+        };
+        
+        match config_manager.reload() {
+            Ok(true) => config_manager.get_config().clone(),
+            Ok(false) => {
+                tracing::debug!("Config file matches the loaded config, nothing to reload");
+                return;
+            }
+            Err(e) => {
+                // Keep running with the last valid config
+                tracing::error!("❌ Ignoring invalid config edit: {:#}", e);
+                if let Err(emit_err) = app.emit("config-reload-failed", format!("{:#}", e)) {
+                    tracing::error!("Failed to emit config-reload-failed event: {}", emit_err);
+                }
+                return;
+            }
+        }
+    };
+    
+    apply_config(app, &config);
+}
+
+// Re-register hotkeys and reset profile state for a config that replaced the loaded one
+fn apply_config(app: &tauri::AppHandle, config: &QDeckConfig) {
+    let state = match app.try_state::<AppState>() {
+        Some(state) => state,
+        None => return,
+    };
+    
+    match state.hotkey_service.lock() {
+        Ok(mut hotkey_service) => {
+            hotkey_service.unregister_all();
+            let report = hotkey_service.register_config(config);
+            match state.hotkey_report.lock() {
+                Ok(mut hotkey_report) => *hotkey_report = report,
+                Err(e) => tracing::error!("Failed to lock hotkey report: {}", e),
+            }
+        }
+        Err(e) => tracing::error!("Failed to lock hotkey service: {}", e),
+    }
+    
+    match state.profile_manager.lock() {
+        Ok(mut profile_manager) => {
+            if let Err(e) = profile_manager.initialize_from_config(config) {
+                tracing::error!("Failed to re-initialize profile manager: {}", e);
+            }
+            fit_overlay_to_page(app, &profile_manager, config);
+        }
+        Err(e) => tracing::error!("Failed to lock profile manager: {}", e),
+    }
+    
+    let window_manager = match state.window_manager.lock() {
+        Ok(window_manager) => Some(window_manager.clone()),
+        Err(e) => {
+            tracing::error!("Failed to lock window manager: {}", e);
+            None
+        }
+    };
+    if let Some(window_manager) = window_manager {
+        apply_window_placement(&window_manager, config);
+        match state.edge_trigger_watcher.lock() {
+            Ok(mut edge_trigger_watcher) => {
+                // Stop the old watcher before the new one starts polling
+                *edge_trigger_watcher = None;
+                *edge_trigger_watcher = spawn_edge_trigger(&window_manager, config);
+            }
+            Err(e) => tracing::error!("Failed to lock edge trigger watcher: {}", e),
+        }
+    }
+    
+    tracing::info!("✅ Applied reloaded configuration");
+    if let Err(e) = app.emit("config-reloaded", config) {
+        tracing::error!("Failed to emit config-reloaded event: {}", e);
+    }
+}
+
+// Take the overlay placement and monitor from `ui.window`; validation warns about an unknown placement
+fn apply_window_placement(window_manager: &WindowManager, config: &QDeckConfig) {
+    let placement = Placement::from_name(&config.ui.window.placement).unwrap_or_else(|e| {
+        tracing::warn!("{}; using dropdown-top", e);
+        Placement::DropdownTop
+    });
+    window_manager.set_placement(placement, MonitorPolicy::from_name(&config.ui.window.monitor));
+}
+
+// Show the overlay when the pointer rests on a configured screen edge
+fn spawn_edge_trigger(window_manager: &WindowManager, config: &QDeckConfig) -> Option<EdgeTriggerWatcher> {
+    let edge_trigger = config.ui.summon.edge_trigger.as_ref()?;
+    let source = TauriCursorSource::new(window_manager.get_app_handle().clone());
+    let window_manager = window_manager.clone();
+    EdgeTriggerWatcher::spawn(
+        Box::new(source),
+        edge_trigger,
+        std::time::Duration::from_millis(50),
+        move |hit| {
+            if window_manager.is_overlay_visible() {
+                return;
+            }
+            tracing::info!("Edge trigger: {:?} edge of monitor {}", hit.edge, hit.monitor);
+            if let Err(e) = window_manager.show_overlay() {
+                tracing::error!("Failed to show overlay from edge trigger: {}", e);
+            }
+        },
+    )
+}
+
+// Switch to the profile whose `match` rule fits the newly focused window
+fn auto_switch_profile(app: &tauri::AppHandle, switcher: &Mutex<ProfileAutoSwitcher>, window: &ForegroundWindow) {
+    let state = match app.try_state::<AppState>() {
+        Some(state) => state,
+        None => return,
+    };
+    
+    let mut switcher = match switcher.lock() {
+        Ok(switcher) => switcher,
+        Err(e) => {
+            tracing::error!("Failed to lock profile auto-switcher: {}", e);
+            return;
+        }
+    };
+    
+    let mut profile_manager = match state.profile_manager.lock() {
+        Ok(pm) => pm,
+        Err(e) => {
+            tracing::error!("Failed to lock profile manager: {}", e);
+            return;
+        }
+    };
+    
+    let config_manager = match state.config_manager.lock() {
+        Ok(cm) => cm,
+        Err(e) => {
+            tracing::error!("Failed to lock config manager: {}", e);
+            return;
+        }
+    };
+    
+    let profile_index = match switcher.on_focus_changed(config_manager.get_config(), window) {
+        Some(index) => index,
+        None => return,
+    };
+    if profile_manager.get_state().current_profile_index == profile_index {
+        return;
+    }
+    
+    match profile_manager.switch_to_profile(profile_index, config_manager.get_config()) {
+        Ok(profile_info) => {
+            tracing::info!("🪟 Auto-switched to profile '{}' for {:?}", profile_info.name, window.exe);
+            fit_overlay_to_page(app, &profile_manager, config_manager.get_config());
+            if let Err(e) = app.emit("profile-changed", &profile_info) {
+                tracing::error!("Failed to emit profile-changed event: {}", e);
+            }
+        }
+        Err(e) => {
+            tracing::error!("❌ Failed to auto-switch profile: {}", e);
+        }
+    }
+}
+
+// Jump to the page bound to a page hotkey
+fn switch_page_from_hotkey(app: &tauri::AppHandle, profile_name: &str, page_name: &str) {
+    let state = match app.try_state::<AppState>() {
+        Some(state) => state,
+        None => return,
+    };
+    
+    let mut profile_manager = match state.profile_manager.lock() {
+        Ok(pm) => pm,
+        Err(e) => {
+            tracing::error!("Failed to lock profile manager: {}", e);
+            return;
+        }
+    };
+    
+    let config_manager = match state.config_manager.lock() {
+        Ok(cm) => cm,
+        Err(e) => {
+            tracing::error!("Failed to lock config manager: {}", e);
+            return;
+        }
+    };
+    
+    match profile_manager.switch_to_page_by_name(profile_name, page_name, config_manager.get_config()) {
+        Ok(profile_info) => {
+            tracing::info!("✅ Switched to page '{}' of profile '{}'", page_name, profile_info.name);
+            fit_overlay_to_page(app, &profile_manager, config_manager.get_config());
+            if let Err(e) = app.emit("profile-changed", &profile_info) {
+                tracing::error!("Failed to emit profile-changed event: {}", e);
+            }
+        }
+        Err(e) => {
+            tracing::error!("❌ Failed to switch to page '{}/{}': {}", profile_name, page_name, e);
+        }
+    }
+}
+
+// Size the overlay for the page that is now showing
+fn fit_overlay_to_page(app: &tauri::AppHandle, profile_manager: &ProfileManager, config: &QDeckConfig) {
+    let page = match profile_manager.get_current_profile_and_page(config) {
+        Ok((_, page)) => page,
+        Err(e) => {
+            tracing::debug!("No current page to size the overlay for: {}", e);
+            return;
+        }
+    };
+    let state = match app.try_state::<AppState>() {
+        Some(state) => state,
+        None => return,
+    };
+    let window_manager = match state.window_manager.lock() {
+        Ok(window_manager) => window_manager.clone(),
+        Err(e) => {
+            tracing::error!("Failed to lock window manager: {}", e);
+            return;
+        }
+    };
+    
+    if let Err(e) = window_manager.set_grid(OverlayGrid::for_page(page, &config.ui.window)) {
+        tracing::error!("Failed to resize overlay for page '{}': {}", page.name, e);
+    }
+}
+
+// Notify the frontend that the folder breadcrumb changed
+fn emit_folder_changed(app: &tauri::AppHandle, profile_manager: &ProfileManager, config: &QDeckConfig) {
+    match profile_manager.get_navigation_context(config) {
+        Ok(context) => {
+            if let Err(e) = app.emit("folder-changed", &context) {
+                tracing::error!("Failed to emit folder-changed event: {}", e);
+            }
+        }
+        Err(e) => tracing::error!("Failed to build navigation context: {}", e),
+    }
+}
+
 #[tauri::command]
 async fn get_log_stats(state: State<'_, AppState>) -> Result<modules::logger::LogStats, String> {
     let logger = state.logger_service.lock().map_err(|e| e.to_string())?;
@@ -396,9 +848,9 @@ async fn generate_buttons_from_files(request: ButtonGenerationRequest, state: St
             tracing::info!("✅ TAURI_COMMAND - {} conflicts, {} errors", result.conflicts.len(), result.errors.len());
             
             for (index, button) in result.generated_buttons.iter().enumerate() {
-                if let Some(target) = button.config.get("target") {
+                if let modules::action::ActionConfig::Open { target, .. } = &button.action {
                     tracing::info!("✅ TAURI_COMMAND - Generated button[{}]: '{}' -> target: '{}'", 
-                        index, button.label, target.as_str().unwrap_or("N/A"));
+                        index, button.label, target);
                 }
             }
             
@@ -450,6 +902,7 @@ pub fn run() {
         .expect("Failed to initialize logging service");
     
     // Initialize other services
+    // Only fails when no config location can be determined; a broken config.yaml falls back
     let config_manager = ConfigManager::new()
         .expect("Failed to initialize config manager");
     
@@ -546,6 +999,27 @@ pub fn run() {
                             tracing::error!("Failed to toggle overlay from hotkey: {}", e);
                         }
                     }
+                    "release_overlay" => {
+                        // Hold mode: letting go hides the overlay and runs the button under the pointer
+                        if !window_manager_for_callback.is_overlay_visible() {
+                            return;
+                        }
+                        let app_handle = window_manager_for_callback.get_app_handle().clone();
+                        let hovered = app_handle.try_state::<AppState>()
+                            .and_then(|app_state| app_state.hovered_button.lock().ok().and_then(|mut hovered| hovered.take()));
+
+                        if let Err(e) = window_manager_for_callback.hide_overlay() {
+                            tracing::error!("Failed to hide overlay after hold: {}", e);
+                        }
+                        if let Some(button_id) = hovered {
+                            tracing::info!("Running hovered button on release: {}", button_id);
+                            tauri::async_runtime::spawn(async move {
+                                if let Err(e) = run_button(&app_handle, &button_id).await {
+                                    tracing::error!("❌ Failed to run button '{}' on release: {}", button_id, e);
+                                }
+                            });
+                        }
+                    }
                     action if action.starts_with("switch_profile:") => {
                         let profile_name = &action[15..]; // Remove "switch_profile:" prefix
                         tracing::info!("Switch profile hotkey triggered: {}", profile_name);
@@ -572,6 +1046,7 @@ pub fn run() {
                             match profile_manager.switch_to_profile_by_name(profile_name, config_manager.get_config()) {
                                 Ok(profile_info) => {
                                     tracing::info!("✅ Switched to profile: {} ({})", profile_info.name, profile_info.index);
+                                    fit_overlay_to_page(app_handle, &profile_manager, config_manager.get_config());
                                     
                                     // Emit an event to notify the frontend
                                     if let Err(e) = app_handle.emit("profile-changed", &profile_info) {
@@ -584,6 +1059,33 @@ pub fn run() {
                             }
                         }
                     }
+                    action if action.starts_with("run_button:") => {
+                        let button_id = action["run_button:".len()..].to_string();
+                        tracing::info!("Run button hotkey triggered: {}", button_id);
+                        
+                        // Actions are async; run them off the hotkey thread
+                        let app_handle = window_manager_for_callback.get_app_handle().clone();
+                        tauri::async_runtime::spawn(async move {
+                            if let Err(e) = run_button(&app_handle, &button_id).await {
+                                tracing::error!("❌ Failed to run button '{}' from hotkey: {}", button_id, e);
+                            }
+                        });
+                    }
+                    action if action.starts_with("switch_page:") => {
+                        let target = &action["switch_page:".len()..];
+                        tracing::info!("Switch page hotkey triggered: {}", target);
+                        
+                        match target.split_once('/') {
+                            Some((profile_name, page_name)) => {
+                                switch_page_from_hotkey(window_manager_for_callback.get_app_handle(), profile_name, page_name);
+                                // Jumping to a page is only useful if the page is visible
+                                if let Err(e) = window_manager_for_callback.show_overlay() {
+                                    tracing::error!("Failed to show overlay from hotkey: {}", e);
+                                }
+                            }
+                            None => tracing::error!("❌ Invalid page hotkey target: {}", target),
+                        }
+                    }
                     _ => {
                         tracing::info!("Unknown hotkey action: {}", action);
                     }
@@ -592,81 +1094,82 @@ pub fn run() {
             
             hotkey_service.set_callback(callback);
 
+            // Start the hotkey message loop first so registrations report real OS results
+            if let Err(e) = hotkey_service.start_message_loop() {
+                tracing::error!("Failed to start hotkey message loop: {}", e);
+            }
+
             // Register default hotkeys from config
             let config = config_manager.get_config();
-            
-            // Register summon hotkeys
-            for hotkey_str in &config.ui.summon.hotkeys {
-                // First check if hotkey is available
-                let is_available = hotkey_service.is_hotkey_available(hotkey_str);
-                tracing::info!("🔍 Hotkey '{}' availability check: {}", hotkey_str, is_available);
-                
-                match hotkey_service.register_hotkey(hotkey_str, "toggle_overlay".to_string()) {
-                    Ok(id) => {
-                        tracing::info!("✅ Registered summon hotkey '{}' with ID {}", hotkey_str, id);
-                    }
-                    Err(e) => {
-                        tracing::error!("❌ Failed to register summon hotkey '{}': {}", hotkey_str, e);
-                        
-                        // Try alternative hotkeys if the primary one fails
-                        let alternatives = vec!["Ctrl+Alt+Q", "Ctrl+F11", "Alt+F12"];
-                        for alt_hotkey in alternatives {
-                            tracing::info!("🔄 Trying alternative hotkey: {}", alt_hotkey);
-                            match hotkey_service.register_hotkey(alt_hotkey, "toggle_overlay".to_string()) {
-                                Ok(alt_id) => {
-                                    tracing::info!("✅ Successfully registered alternative hotkey '{}' with ID {}", alt_hotkey, alt_id);
-                                    break;
-                                }
-                                Err(alt_e) => {
-                                    tracing::warn!("⚠️ Alternative hotkey '{}' also failed: {}", alt_hotkey, alt_e);
-                                }
-                            }
-                        }
-                    }
-                }
-            }
-            
-            // Register profile-specific hotkeys
-            for profile in &config.profiles {
-                if let Some(hotkey_str) = &profile.hotkey {
-                    let action = format!("switch_profile:{}", profile.name);
-                    match hotkey_service.register_hotkey(hotkey_str, action) {
-                        Ok(id) => {
-                            tracing::info!("Registered profile hotkey '{}' for profile '{}' with ID {}", hotkey_str, profile.name, id);
-                        }
-                        Err(e) => {
-                            tracing::error!("Failed to register profile hotkey '{}' for profile '{}': {}", hotkey_str, profile.name, e);
-                        }
-                    }
-                }
-            }
+            let hotkey_report = hotkey_service.register_config(config);
 
             // Note: Escape key should be handled by the frontend when overlay is visible,
             // not as a global hotkey. Global Escape would interfere with other applications.
 
-            // Start hotkey message loop
-            if let Err(e) = hotkey_service.start_message_loop() {
-                tracing::error!("Failed to start hotkey message loop: {}", e);
-            }
-
-            // Initialize profile manager with config
-            let mut profile_manager = ProfileManager::new()
+            // Initialize profile manager with config, restoring the last active profile and pages
+            let state_path = config_manager.config_path().with_file_name(modules::profile::PROFILE_STATE_FILE);
+            let mut profile_manager = ProfileManager::with_state_file(state_path)
                 .expect("Failed to initialize profile manager");
             
             if let Err(e) = profile_manager.initialize_from_config(&config) {
                 tracing::error!("Failed to initialize profile manager from config: {}", e);
             }
+            // The app state isn't managed yet, so size the overlay for the restored page directly
+            if let Ok((_, page)) = profile_manager.get_current_profile_and_page(config) {
+                if let Err(e) = window_manager.set_grid(OverlayGrid::for_page(page, &config.ui.window)) {
+                    tracing::error!("Failed to size overlay for page '{}': {}", page.name, e);
+                }
+            }
+
+            // Watch config.yaml so hand edits apply without a restart
+            let app_handle_for_watcher = app.handle().clone();
+            let config_watcher = ConfigWatcher::spawn(
+                config_manager.config_path().to_path_buf(),
+                std::time::Duration::from_millis(500),
+                move || reload_config(&app_handle_for_watcher),
+            );
+
+            // Follow the focused application for profiles with a `match` rule
+            let foreground_watcher = foreground::platform_source().map(|source| {
+                let app_handle_for_focus = app.handle().clone();
+                let switcher = std::sync::Arc::new(Mutex::new(ProfileAutoSwitcher::default()));
+
+                // Title patterns are compiled once per config
+                let switcher_for_reload = switcher.clone();
+                app.listen("config-reloaded", move |_| {
+                    if let Ok(mut switcher) = switcher_for_reload.lock() {
+                        switcher.config_reloaded();
+                    }
+                });
+
+                ForegroundWatcher::spawn(
+                    source,
+                    std::time::Duration::from_millis(500),
+                    move |window| auto_switch_profile(&app_handle_for_focus, &switcher, window),
+                )
+            });
+
+            apply_window_placement(&window_manager, config);
+
+            // Summon the overlay by resting the pointer on a screen edge
+            let edge_trigger_watcher = spawn_edge_trigger(&window_manager, config);
 
             // Create application state
             let app_state = AppState {
                 config_manager: Mutex::new(config_manager),
                 logger_service: Mutex::new(logger_service),
                 hotkey_service: Mutex::new(hotkey_service),
+                hotkey_report: Mutex::new(hotkey_report),
                 action_runner: Mutex::new(action_runner),
                 profile_manager: Mutex::new(profile_manager),
                 window_manager: Mutex::new(window_manager),
+                action_registry: ActionRegistry::new(),
+                hovered_button: Mutex::new(None),
                 icon_service: Mutex::new(icon_service),
                 drag_drop_service: Mutex::new(drag_drop_service),
+                _config_watcher: config_watcher,
+                _foreground_watcher: foreground_watcher,
+                edge_trigger_watcher: Mutex::new(edge_trigger_watcher),
             };
 
             app.manage(app_state);
@@ -676,6 +1179,10 @@ pub fn run() {
         .invoke_handler(tauri::generate_handler![
             get_config,
             save_config,
+            validate_config,
+            get_config_load_error,
+            list_config_backups,
+            restore_config_backup,
             show_overlay,
             hide_overlay,
             toggle_overlay,
@@ -683,14 +1190,20 @@ pub fn run() {
             update_overlay_config,
             position_overlay,
             execute_action,
+            list_running_actions,
+            cancel_action,
             get_recent_logs,
             get_log_stats,
             rotate_logs,
             cleanup_logs_by_size,
             register_hotkey,
             unregister_hotkey,
+            replace_hotkey,
             register_multiple_hotkeys,
             get_registered_hotkeys,
+            get_hotkey_conflict_report,
+            list_supported_keys,
+            set_hovered_button,
             is_hotkey_available,
             process_icon,
             extract_executable_icon,
@@ -706,6 +1219,9 @@ pub fn run() {
             next_page,
             previous_page,
             get_navigation_context,
+            enter_folder,
+            exit_folder,
+            exit_all_folders,
             analyze_dropped_files,
             generate_buttons_from_files,
             add_undo_operation,
@@ -717,109 +1233,3 @@ pub fn run() {
         .run(tauri::generate_context!())
         .expect("error while running tauri application");
 }
-// Helper function to convert ActionButton to ActionConfig
-fn button_to_action_config(button: &modules::config::ActionButton) -> Option<modules::action::ActionConfig> {
-    use modules::config::ActionType;
-    
-    tracing::debug!("🔄 Converting button config for: {} (type: {:?})", button.label, button.action_type);
-    
-    match button.action_type {
-        ActionType::LaunchApp => {
-            tracing::debug!("🚀 Processing LaunchApp action");
-            let path = button.config.get("path")?.as_str()?.to_string();
-            tracing::debug!("📁 App path: {}", path);
-            
-            let args = button.config.get("args")
-                .and_then(|v| v.as_array())
-                .map(|arr| arr.iter().filter_map(|v| v.as_str().map(|s| s.to_string())).collect());
-            let workdir = button.config.get("workdir")
-                .and_then(|v| v.as_str())
-                .map(|s| s.to_string());
-            let env = button.config.get("env")
-                .and_then(|v| v.as_object())
-                .map(|obj| obj.iter()
-                    .filter_map(|(k, v)| v.as_str().map(|s| (k.clone(), s.to_string())))
-                    .collect());
-            
-            let action_config = modules::action::ActionConfig::LaunchApp { path, args, workdir, env };
-            tracing::debug!("✅ Created LaunchApp config");
-            Some(action_config)
-        }
-        ActionType::Open => {
-            tracing::debug!("📂 Processing Open action");
-            let target = button.config.get("target")?.as_str()?.to_string();
-            tracing::debug!("🎯 Open target: {}", target);
-            
-            let verb = button.config.get("verb")
-                .and_then(|v| v.as_str())
-                .map(|s| s.to_string());
-            
-            let action_config = modules::action::ActionConfig::Open { target, verb };
-            tracing::debug!("✅ Created Open config");
-            Some(action_config)
-        }
-        ActionType::Terminal => {
-            tracing::debug!("💻 Processing Terminal action");
-            let terminal = button.config.get("terminal")?.as_str()?.to_string();
-            tracing::debug!("🖥️ Terminal type: {}", terminal);
-            
-            let profile = button.config.get("profile")
-                .and_then(|v| v.as_str())
-                .map(|s| s.to_string());
-            let workdir = button.config.get("workdir")
-                .and_then(|v| v.as_str())
-                .map(|s| s.to_string());
-            let command = button.config.get("command")
-                .and_then(|v| v.as_str())
-                .map(|s| s.to_string());
-            let env = button.config.get("env")
-                .and_then(|v| v.as_object())
-                .map(|obj| obj.iter()
-                    .filter_map(|(k, v)| v.as_str().map(|s| (k.clone(), s.to_string())))
-                    .collect());
-            let args = button.config.get("args")
-                .and_then(|v| v.as_array())
-                .map(|arr| arr.iter().filter_map(|v| v.as_str().map(|s| s.to_string())).collect());
-            
-            let action_config = modules::action::ActionConfig::Terminal { terminal, profile, workdir, command, env, args };
-            tracing::debug!("✅ Created Terminal config");
-            Some(action_config)
-        }
-        ActionType::MultiAction => {
-            let actions_array = button.config.get("actions")?.as_array()?;
-            let mut actions = Vec::new();
-            
-            for action_value in actions_array {
-                if let Some(action_obj) = action_value.as_object() {
-                    // Create a temporary ActionButton to convert
-                    let temp_button = modules::config::ActionButton {
-                        position: modules::config::Position { row: 0, col: 0 },
-                        action_type: serde_json::from_value(action_obj.get("action_type")?.clone()).ok()?,
-                        label: "temp".to_string(),
-                        icon: None,
-                        config: action_obj.get("config")?.as_object()?.iter()
-                            .map(|(k, v)| (k.clone(), v.clone()))
-                            .collect(),
-                        style: None,
-                        action: None,
-                    };
-                    
-                    if let Some(action_config) = button_to_action_config(&temp_button) {
-                        actions.push(action_config);
-                    }
-                }
-            }
-            
-            let delay_between_ms = button.config.get("delay_between_ms")
-                .and_then(|v| v.as_u64());
-            let stop_on_error = button.config.get("stop_on_error")
-                .and_then(|v| v.as_bool());
-            
-            Some(modules::action::ActionConfig::MultiAction { actions, delay_between_ms, stop_on_error })
-        }
-        _ => {
-            tracing::warn!("⚠️ Unsupported action type: {:?}", button.action_type);
-            None // SendKeys, PowerShell, Folder not implemented yet
-        }
-    }
-}
\ No newline at end of file
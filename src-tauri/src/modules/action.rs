@@ -6,9 +6,17 @@ use std::collections::HashMap;
 
 
 use std::process::{Command, Stdio};
+use std::sync::Arc;
+use tokio::io::{AsyncBufReadExt, AsyncRead, BufReader};
+use tokio::sync::oneshot;
 use tokio::time::{sleep, Duration};
 use tracing::{info, error, warn, debug};
 
+use crate::modules::action_registry::{own_process_group, ActionRegistry, ProcessEnd};
+use crate::modules::config::{ActionType, FolderConfig};
+use crate::modules::input::{default_input_backend, InputBackend};
+use crate::modules::keys::{parse_key_sequence, KeyStroke};
+
 #[cfg(windows)]
 use winapi::um::shellapi::ShellExecuteW;
 #[cfg(windows)]
@@ -26,6 +34,8 @@ pub struct ActionResult {
     pub execution_time_ms: u64,
     pub output: Option<String>,
     pub error_code: Option<i32>,
+    #[serde(default)]
+    pub invocation_id: Option<String>, // Set by the runner; pass it to cancel_action
 }
 
 // Action configuration for different action types
@@ -37,18 +47,23 @@ pub enum ActionConfig {
         args: Option<Vec<String>>,
         workdir: Option<String>,
         env: Option<HashMap<String, String>>,
+        timeout_ms: Option<u64>, // Kill the app, and anything it started, after this long
+        wait: Option<bool>,      // Wait for the app to exit and report its exit code
+        capture: Option<bool>,   // Wait, streaming and returning its output
     },
     Open {
         target: String,
         verb: Option<String>, // "open", "edit", "print", etc.
+        fallback_editor: Option<String>, // Used for "edit" and when no default handler exists
     },
     Terminal {
-        terminal: String, // "WindowsTerminal", "PowerShell", "Cmd", "WSL"
+        terminal: String, // "WindowsTerminal", "PowerShell", "Cmd", "WSL", "gnome-terminal", "konsole", "alacritty", "kitty", "wezterm", "xterm", "$TERMINAL", "auto"
         profile: Option<String>,
         workdir: Option<String>,
         command: Option<String>,
         env: Option<HashMap<String, String>>,
         args: Option<Vec<String>>,
+        timeout_ms: Option<u64>, // Close the terminal after this long
     },
     SendKeys {
         keys: String,
@@ -58,18 +73,135 @@ pub enum ActionConfig {
         script: String,
         workdir: Option<String>,
         execution_policy: Option<String>,
+        shell: Option<String>, // Interpreter override, e.g. "pwsh" or "/bin/bash"
+        wait: Option<bool>,    // Wait for the script to finish and report its exit code
+        timeout_ms: Option<u64>, // Kill the script after this long
+        capture: Option<bool>, // Wait, streaming and returning its output
     },
     MultiAction {
         actions: Vec<ActionConfig>,
         delay_between_ms: Option<u64>,
         stop_on_error: Option<bool>,
     },
+    Folder(FolderConfig), // Navigates to a sub-page; handled by the profile manager, not an executor
+    System { system_action: SystemAction }, // Drives the overlay itself; handled by the app, not an executor
+}
+
+/// Built-in overlay commands for System buttons
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SystemAction {
+    Config, // Open the settings screen
+    Back,
+    ExitConfig,
+    HideOverlay,
+    ToggleOverlay,
+}
+
+impl ActionConfig {
+    pub fn action_type(&self) -> ActionType {
+        match self {
+            ActionConfig::LaunchApp { .. } => ActionType::LaunchApp,
+            ActionConfig::Open { .. } => ActionType::Open,
+            ActionConfig::Terminal { .. } => ActionType::Terminal,
+            ActionConfig::SendKeys { .. } => ActionType::SendKeys,
+            ActionConfig::PowerShell { .. } => ActionType::PowerShell,
+            ActionConfig::MultiAction { .. } => ActionType::MultiAction,
+            ActionConfig::Folder(_) => ActionType::Folder,
+            ActionConfig::System { .. } => ActionType::System,
+        }
+    }
+
+    /// How long the action's process may run before it is killed
+    pub fn timeout_ms(&self) -> Option<u64> {
+        match self {
+            ActionConfig::LaunchApp { timeout_ms, .. }
+            | ActionConfig::Terminal { timeout_ms, .. }
+            | ActionConfig::PowerShell { timeout_ms, .. } => *timeout_ms,
+            _ => None,
+        }
+    }
+}
+
+/// Which pipe an output line came from
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum OutputStream {
+    Stdout,
+    Stderr,
+}
+
+/// A line printed by a process an action is waiting on (the `action-output` event)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ActionOutputLine {
+    pub invocation_id: String,
+    pub stream: OutputStream,
+    pub line: String,
+}
+
+/// A button action that has begun running (the `action-started` event)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ActionStarted {
+    pub invocation_id: String,
+    pub button_id: Option<String>,
+}
+
+/// Receives output lines as they are printed
+pub type OutputSink = Arc<dyn Fn(&ActionOutputLine) + Send + Sync>;
+
+/// One run of an action: its invocation ID and the registry its processes are tracked in
+#[derive(Clone)]
+pub struct ActionContext {
+    pub invocation_id: String,
+    pub registry: ActionRegistry,
+    output_sink: Option<OutputSink>,
+}
+
+impl ActionContext {
+    /// Register a new invocation of `config`, run from `button_id` if it came from a button
+    pub fn begin(registry: &ActionRegistry, button_id: Option<&str>, config: &ActionConfig) -> Self {
+        let invocation_id = uuid::Uuid::new_v4().to_string();
+        registry.begin(&invocation_id, button_id, config.action_type());
+        Self { invocation_id, registry: registry.clone(), output_sink: None }
+    }
+
+    /// Stream the output of processes this invocation waits on to `sink`
+    pub fn with_output_sink(mut self, sink: OutputSink) -> Self {
+        self.output_sink = Some(sink);
+        self
+    }
+
+    /// An invocation in a registry of its own, for running an action outside the app state
+    #[cfg(test)]
+    pub fn detached(config: &ActionConfig) -> Self {
+        Self::begin(&ActionRegistry::new(), None, config)
+    }
+
+    /// Hand a spawned process to the registry so it can be listed, cancelled and timed out
+    pub fn track(&self, child: tokio::process::Child, command: &str, timeout_ms: Option<u64>) -> oneshot::Receiver<ProcessEnd> {
+        self.registry.track(&self.invocation_id, child, command, timeout_ms.map(Duration::from_millis))
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.registry.is_cancelled(&self.invocation_id)
+    }
+
+    fn emit_output(&self, stream: OutputStream, line: &[u8]) {
+        if let Some(sink) = &self.output_sink {
+            let line = String::from_utf8_lossy(line);
+            sink(&ActionOutputLine {
+                invocation_id: self.invocation_id.clone(),
+                stream,
+                line: line.trim_end_matches(['\r', '\n']).to_string(),
+            });
+        }
+    }
 }
 
 // Trait for action executors
 #[async_trait::async_trait]
 pub trait ActionExecutor: Send + Sync {
-    async fn execute(&self, config: &ActionConfig) -> Result<ActionResult>;
+    async fn execute(&self, config: &ActionConfig, ctx: &ActionContext) -> Result<ActionResult>;
     fn supports_action_type(&self, config: &ActionConfig) -> bool;
 }
 
@@ -78,13 +210,15 @@ pub struct LaunchAppActionExecutor;
 
 #[async_trait::async_trait]
 impl ActionExecutor for LaunchAppActionExecutor {
-    async fn execute(&self, config: &ActionConfig) -> Result<ActionResult> {
+    async fn execute(&self, config: &ActionConfig, ctx: &ActionContext) -> Result<ActionResult> {
         let start_time = std::time::Instant::now();
         
-        if let ActionConfig::LaunchApp { path, args, workdir, env } = config {
+        if let ActionConfig::LaunchApp { path, args, workdir, env, timeout_ms, wait, capture } = config {
             info!("🚀 Launching application: {}", path);
+            let capture = capture.unwrap_or(false);
+            let wait = wait.unwrap_or(false) || capture;
             
-            let mut command = Command::new(path);
+            let mut command = tokio::process::Command::new(path);
             
             // Set arguments
             if let Some(args) = args {
@@ -109,7 +243,32 @@ impl ActionExecutor for LaunchAppActionExecutor {
             }
             
             // Configure stdio
-            command.stdout(Stdio::null()).stderr(Stdio::null());
+            if capture {
+                command.stdin(Stdio::null()).stdout(Stdio::piped()).stderr(Stdio::piped());
+            } else {
+                command.stdout(Stdio::null()).stderr(Stdio::null());
+            }
+            own_process_group(&mut command);
+            
+            if wait {
+                let child = match command.spawn() {
+                    Ok(child) => child,
+                    Err(e) => {
+                        error!("❌ Failed to launch application '{}': {}", path, e);
+                        return Ok(ActionResult {
+                            success: false,
+                            message: format!("Failed to launch application '{}': {}", path, e),
+                            execution_time_ms: start_time.elapsed().as_millis() as u64,
+                            output: None,
+                            error_code: None,
+                            invocation_id: None,
+                        });
+                    }
+                };
+                let (end, output) = wait_for_process(ctx, child, path, *timeout_ms).await;
+                let execution_time = start_time.elapsed().as_millis() as u64;
+                return Ok(waited_result(&format!("Application '{}'", path), end, *timeout_ms, output, execution_time));
+            }
             
             match command.spawn() {
                 Ok(mut child) => {
@@ -126,6 +285,7 @@ impl ActionExecutor for LaunchAppActionExecutor {
                                     execution_time_ms: execution_time,
                                     output: None,
                                     error_code: None,
+                                    invocation_id: None,
                                 })
                             } else {
                                 let error_code = status.code();
@@ -136,11 +296,13 @@ impl ActionExecutor for LaunchAppActionExecutor {
                                     execution_time_ms: execution_time,
                                     output: None,
                                     error_code,
+                                    invocation_id: None,
                                 })
                             }
                         }
                         Ok(None) => {
                             // Process is still running, which is expected for GUI applications
+                            ctx.track(child, path, *timeout_ms);
                             info!("✅ Application launched successfully in {}ms", execution_time);
                             Ok(ActionResult {
                                 success: true,
@@ -148,6 +310,7 @@ impl ActionExecutor for LaunchAppActionExecutor {
                                 execution_time_ms: execution_time,
                                 output: None,
                                 error_code: None,
+                                invocation_id: None,
                             })
                         }
                         Err(e) => {
@@ -158,6 +321,7 @@ impl ActionExecutor for LaunchAppActionExecutor {
                                 execution_time_ms: start_time.elapsed().as_millis() as u64,
                                 output: None,
                                 error_code: None,
+                                invocation_id: None,
                             })
                         }
                     }
@@ -171,6 +335,7 @@ impl ActionExecutor for LaunchAppActionExecutor {
                         execution_time_ms: execution_time,
                         output: None,
                         error_code: None,
+                        invocation_id: None,
                     })
                 }
             }
@@ -189,10 +354,10 @@ pub struct OpenActionExecutor;
 
 #[async_trait::async_trait]
 impl ActionExecutor for OpenActionExecutor {
-    async fn execute(&self, config: &ActionConfig) -> Result<ActionResult> {
+    async fn execute(&self, config: &ActionConfig, _ctx: &ActionContext) -> Result<ActionResult> {
         let start_time = std::time::Instant::now();
         
-        if let ActionConfig::Open { target, verb } = config {
+        if let ActionConfig::Open { target, verb, fallback_editor } = config {
             info!("📂 Opening target: {}", target);
             
             #[cfg(windows)]
@@ -289,6 +454,7 @@ impl ActionExecutor for OpenActionExecutor {
                         execution_time_ms: execution_time,
                         output: None,
                         error_code: None,
+                        invocation_id: None,
                     })
                 } else {
                     warn!("⚠️ Failed to open target '{}': ShellExecute error code {}", target, result as i32);
@@ -297,11 +463,12 @@ impl ActionExecutor for OpenActionExecutor {
                     if result as i32 == 2 { // File not found or no default program
                         let path = std::path::Path::new(&final_target);
                         if path.exists() && path.is_file() {
-                            info!("🔄 Trying fallback: opening with Notepad");
+                            let editor = fallback_editor.as_deref().unwrap_or("notepad");
+                            info!("🔄 Trying fallback: opening with {}", editor);
                             
-                            // Try to open with Notepad as fallback
+                            // Try to open with the fallback editor
                             let fallback_result = unsafe {
-                                let notepad_wide: Vec<u16> = OsStr::new("notepad")
+                                let notepad_wide: Vec<u16> = OsStr::new(editor)
                                     .encode_wide()
                                     .chain(std::iter::once(0))
                                     .collect();
@@ -322,22 +489,24 @@ impl ActionExecutor for OpenActionExecutor {
                             };
                             
                             if fallback_result as usize > 32 {
-                                info!("✅ Target opened with Notepad successfully");
+                                info!("✅ Target opened with {} successfully", editor);
                                 Ok(ActionResult {
                                     success: true,
-                                    message: format!("Opened '{}' with Notepad", target),
+                                    message: format!("Opened '{}' with {}", target, editor),
                                     execution_time_ms: start_time.elapsed().as_millis() as u64,
                                     output: None,
                                     error_code: None,
+                                    invocation_id: None,
                                 })
                             } else {
-                                error!("❌ Fallback also failed: Notepad error code {}", fallback_result as i32);
+                                error!("❌ Fallback also failed: {} error code {}", editor, fallback_result as i32);
                                 Ok(ActionResult {
                                     success: false,
-                                    message: format!("Failed to open '{}': No default program and Notepad fallback failed", target),
+                                    message: format!("Failed to open '{}': No default program and {} fallback failed", target, editor),
                                     execution_time_ms: start_time.elapsed().as_millis() as u64,
                                     output: None,
                                     error_code: Some(result as i32),
+                                    invocation_id: None,
                                 })
                             }
                         } else {
@@ -348,6 +517,7 @@ impl ActionExecutor for OpenActionExecutor {
                                 execution_time_ms: execution_time,
                                 output: None,
                                 error_code: Some(result as i32),
+                                invocation_id: None,
                             })
                         }
                     } else {
@@ -358,6 +528,7 @@ impl ActionExecutor for OpenActionExecutor {
                             execution_time_ms: execution_time,
                             output: None,
                             error_code: Some(result as i32),
+                            invocation_id: None,
                         })
                     }
                 }
@@ -365,13 +536,66 @@ impl ActionExecutor for OpenActionExecutor {
             
             #[cfg(not(windows))]
             {
-                let execution_time = start_time.elapsed().as_millis() as u64;
+                let expanded_target = expand_environment_variables(target);
+                let is_url = is_url_target(&expanded_target);
+                let absolute_target = if is_url {
+                    expanded_target.clone()
+                } else {
+                    absolutize_path(&expanded_target)
+                };
+                debug!("🎯 Absolute target: {}", absolute_target);
+
+                let path = std::path::Path::new(&absolute_target);
+                if !is_url && !path.exists() {
+                    error!("❌ File does not exist: {}", absolute_target);
+                    return Ok(ActionResult {
+                        success: false,
+                        message: format!("File '{}' does not exist", target),
+                        execution_time_ms: start_time.elapsed().as_millis() as u64,
+                        output: None,
+                        error_code: None,
+                        invocation_id: None,
+                    });
+                }
+
+                let verb_str = verb.as_deref().unwrap_or("open").to_lowercase();
+                let default_handler = if is_url || path.is_dir() {
+                    None
+                } else {
+                    query_default_handler(&absolute_target)
+                };
+                debug!("🔧 Verb: {}, default handler: {:?}", verb_str, default_handler);
+
+                let editor = fallback_editor.clone().or_else(default_fallback_editor);
+                let candidates = open_candidates(&absolute_target, &verb_str, path.is_dir(), is_url, default_handler.is_some(), editor.as_deref());
+
+                for (program, args) in &candidates {
+                    debug!("🔄 Trying: {} {:?}", program, args);
+                    match run_opener(program, args).await {
+                        Ok(()) => {
+                            let execution_time = start_time.elapsed().as_millis() as u64;
+                            info!("✅ Target opened with {} in {}ms", program, execution_time);
+                            return Ok(ActionResult {
+                                success: true,
+                                message: format!("Opened '{}' with {}", target, program),
+                                execution_time_ms: execution_time,
+                                output: None,
+                                error_code: None,
+                                invocation_id: None,
+                            });
+                        }
+                        Err(e) => warn!("⚠️ {} could not open '{}': {}", program, absolute_target, e),
+                    }
+                }
+
+                error!("❌ Failed to open target '{}': no opener succeeded", target);
                 Ok(ActionResult {
                     success: false,
-                    message: "Open action is only supported on Windows".to_string(),
-                    execution_time_ms: execution_time,
+                    message: format!("Failed to open '{}': no default program or fallback editor available", target),
+                    execution_time_ms: start_time.elapsed().as_millis() as u64,
                     output: None,
                     error_code: None,
+                    invocation_id: None,
                 })
             }
         } else {
@@ -384,18 +608,166 @@ impl ActionExecutor for OpenActionExecutor {
     }
 }
 
+// Non-Windows open helpers (xdg-open / gio on Linux, `open` on macOS)
+#[cfg(not(windows))]
+fn is_url_target(target: &str) -> bool {
+    match target.split_once("://") {
+        Some((scheme, _)) => !scheme.is_empty() && scheme.chars().all(|c| c.is_ascii_alphanumeric() || c == '+' || c == '-' || c == '.'),
+        None => target.starts_with("mailto:"),
+    }
+}
+
+#[cfg(not(windows))]
+fn absolutize_path(target: &str) -> String {
+    let path = std::path::Path::new(target);
+    if path.is_absolute() {
+        return target.to_string();
+    }
+    match std::env::current_dir() {
+        Ok(current_dir) => current_dir.join(path).to_string_lossy().to_string(),
+        Err(_) => target.to_string(),
+    }
+}
+
+/// Look up the desktop entry registered for the file's MIME type
+#[cfg(not(windows))]
+fn query_default_handler(file: &str) -> Option<String> {
+    if cfg!(target_os = "macos") {
+        // LaunchServices resolves handlers inside `open`; assume one exists
+        return Some("LaunchServices".to_string());
+    }
+
+    let mime = Command::new("xdg-mime").args(["query", "filetype", file]).output().ok()?;
+    let mime = String::from_utf8_lossy(&mime.stdout).trim().to_string();
+    if mime.is_empty() {
+        return None;
+    }
+
+    let handler = Command::new("xdg-mime").args(["query", "default", &mime]).output().ok()?;
+    let handler = String::from_utf8_lossy(&handler.stdout).trim().to_string();
+    debug!("📄 MIME type {} -> {:?}", mime, handler);
+    if handler.is_empty() { None } else { Some(handler) }
+}
+
+/// First installed graphical text editor
+#[cfg(not(windows))]
+fn default_fallback_editor() -> Option<String> {
+    if cfg!(target_os = "macos") {
+        return Some("open -t".to_string());
+    }
+
+    ["gnome-text-editor", "gedit", "kate", "kwrite", "mousepad", "xed", "pluma", "code"]
+        .iter()
+        .find(|editor| find_executable(editor).is_some())
+        .map(|editor| editor.to_string())
+}
+
+/// Ordered list of commands to try for opening `target` with `verb`
+#[cfg(not(windows))]
+fn open_candidates(
+    target: &str,
+    verb: &str,
+    is_dir: bool,
+    is_url: bool,
+    has_default_handler: bool,
+    editor: Option<&str>,
+) -> Vec<(String, Vec<String>)> {
+    let mut candidates = Vec::new();
+
+    let editor_command = editor.map(|editor| {
+        let mut parts = editor.split_whitespace().map(|s| s.to_string());
+        let program = parts.next().unwrap_or_default();
+        let mut args: Vec<String> = parts.collect();
+        args.push(target.to_string());
+        (program, args)
+    });
+
+    let default_openers: Vec<(String, Vec<String>)> = if cfg!(target_os = "macos") {
+        vec![("open".to_string(), vec![target.to_string()])]
+    } else {
+        vec![
+            ("xdg-open".to_string(), vec![target.to_string()]),
+            ("gio".to_string(), vec!["open".to_string(), target.to_string()]),
+        ]
+    };
+
+    match verb {
+        "edit" if !is_dir && !is_url => {
+            candidates.extend(editor_command);
+            candidates.extend(default_openers);
+        }
+        "print" if !is_dir && !is_url => {
+            candidates.push(("lp".to_string(), vec![target.to_string()]));
+        }
+        _ => {
+            if verb != "open" && verb != "explore" {
+                debug!("🔧 No mapping for verb '{}', using default handler", verb);
+            }
+            if is_dir || is_url || has_default_handler {
+                candidates.extend(default_openers);
+                if !is_dir && !is_url {
+                    candidates.extend(editor_command);
+                }
+            } else {
+                // No registered handler: go straight to the editor, then let the opener prompt
+                candidates.extend(editor_command);
+                candidates.extend(default_openers);
+            }
+        }
+    }
+
+    candidates
+}
+
+/// Run an opener; openers that are still running after a short grace period are considered launched
+#[cfg(not(windows))]
+async fn run_opener(program: &str, args: &[String]) -> Result<()> {
+    let mut child = tokio::process::Command::new(program)
+        .args(args)
+        .stdin(Stdio::null())
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .spawn()?;
+
+    match tokio::time::timeout(Duration::from_secs(3), child.wait()).await {
+        Ok(Ok(status)) if status.success() => Ok(()),
+        Ok(Ok(status)) => Err(anyhow::anyhow!("exited with code {:?}", status.code())),
+        Ok(Err(e)) => Err(e.into()),
+        Err(_) => Ok(()),
+    }
+}
+
 // Terminal Action Executor
 pub struct TerminalActionExecutor;
 
 #[async_trait::async_trait]
 impl ActionExecutor for TerminalActionExecutor {
-    async fn execute(&self, config: &ActionConfig) -> Result<ActionResult> {
+    async fn execute(&self, config: &ActionConfig, ctx: &ActionContext) -> Result<ActionResult> {
         let start_time = std::time::Instant::now();
         
-        if let ActionConfig::Terminal { terminal, profile, workdir, command, env, args } = config {
+        if let ActionConfig::Terminal { terminal, profile, workdir, command, env, args, timeout_ms } = config {
             info!("💻 Opening terminal: {}", terminal);
             
-            let mut cmd = match terminal.as_str() {
+            let resolved_terminal = match resolve_terminal_name(terminal) {
+                Some(resolved) => resolved,
+                None => {
+                    error!("❌ No supported terminal found for '{}'", terminal);
+                    return Ok(ActionResult {
+                        success: false,
+                        message: format!("No installed terminal found for '{}'", terminal),
+                        execution_time_ms: start_time.elapsed().as_millis() as u64,
+                        output: None,
+                        error_code: None,
+                        invocation_id: None,
+                    });
+                }
+            };
+            if resolved_terminal != *terminal {
+                debug!("🔎 Resolved terminal '{}' to '{}'", terminal, resolved_terminal);
+            }
+            
+            let mut extra_args_applied = false;
+            let mut cmd = match resolved_terminal.as_str() {
                 "WindowsTerminal" | "wt" => {
                     let mut cmd_builder = Command::new("wt");
                     
@@ -490,6 +862,35 @@ impl ActionExecutor for TerminalActionExecutor {
                     
                     cmd_builder
                 }
+                // A $TERMINAL outside the table (e.g. foot) is launched the generic way
+                name if UnixTerminal::from_name(name).is_some()
+                    || terminal.eq_ignore_ascii_case("auto")
+                    || terminal == "$TERMINAL" => {
+                    let kind = UnixTerminal::from_name(name).unwrap_or(UnixTerminal::Generic);
+                    let shell = std::env::var("SHELL").unwrap_or_else(|_| "/bin/sh".to_string());
+                    let inner = unix_shell_invocation(command.as_deref(), env.as_ref(), &shell);
+                    let expanded_workdir = workdir.as_deref().map(expand_environment_variables);
+                    let extra_args = args.clone().unwrap_or_default();
+                    
+                    let launch = build_unix_terminal_launch(
+                        kind,
+                        name,
+                        profile.as_deref(),
+                        expanded_workdir.as_deref(),
+                        &extra_args,
+                        inner,
+                    );
+                    debug!("🐧 {:?} launch: {} {:?}", kind, launch.program, launch.args);
+                    
+                    let mut cmd_builder = Command::new(&launch.program);
+                    cmd_builder.args(&launch.args);
+                    if let Some(dir) = &launch.current_dir {
+                        cmd_builder.current_dir(dir);
+                    }
+                    extra_args_applied = true;
+                    
+                    cmd_builder
+                }
                 _ => {
                     return Ok(ActionResult {
                         success: false,
@@ -497,12 +898,13 @@ impl ActionExecutor for TerminalActionExecutor {
                         execution_time_ms: start_time.elapsed().as_millis() as u64,
                         output: None,
                         error_code: None,
+                        invocation_id: None,
                     });
                 }
             };
             
             // Add custom arguments if specified
-            if let Some(args) = args {
+            if let Some(args) = args.as_ref().filter(|_| !extra_args_applied) {
                 cmd.args(args);
                 debug!("📝 Additional arguments: {:?}", args);
             }
@@ -519,6 +921,10 @@ impl ActionExecutor for TerminalActionExecutor {
             // Configure stdio
             cmd.stdout(Stdio::null()).stderr(Stdio::null());
             
+            // Spawn through tokio so the registry can watch the terminal
+            let mut cmd = tokio::process::Command::from(cmd);
+            own_process_group(&mut cmd);
+            
             match cmd.spawn() {
                 Ok(mut child) => {
                     let execution_time = start_time.elapsed().as_millis() as u64;
@@ -534,6 +940,7 @@ impl ActionExecutor for TerminalActionExecutor {
                                     execution_time_ms: execution_time,
                                     output: None,
                                     error_code: None,
+                                    invocation_id: None,
                                 })
                             } else {
                                 let error_code = status.code();
@@ -544,11 +951,13 @@ impl ActionExecutor for TerminalActionExecutor {
                                     execution_time_ms: execution_time,
                                     output: None,
                                     error_code,
+                                    invocation_id: None,
                                 })
                             }
                         }
                         Ok(None) => {
                             // Terminal is still running, which is expected
+                            ctx.track(child, &resolved_terminal, *timeout_ms);
                             info!("✅ Terminal opened successfully in {}ms", execution_time);
                             Ok(ActionResult {
                                 success: true,
@@ -556,6 +965,7 @@ impl ActionExecutor for TerminalActionExecutor {
                                 execution_time_ms: execution_time,
                                 output: None,
                                 error_code: None,
+                                invocation_id: None,
                             })
                         }
                         Err(e) => {
@@ -566,6 +976,7 @@ impl ActionExecutor for TerminalActionExecutor {
                                 execution_time_ms: start_time.elapsed().as_millis() as u64,
                                 output: None,
                                 error_code: None,
+                                invocation_id: None,
                             })
                         }
                     }
@@ -579,6 +990,7 @@ impl ActionExecutor for TerminalActionExecutor {
                         execution_time_ms: execution_time,
                         output: None,
                         error_code: None,
+                        invocation_id: None,
                     })
                 }
             }
@@ -592,6 +1004,235 @@ impl ActionExecutor for TerminalActionExecutor {
     }
 }
 
+// SendKeys Action Executor
+pub struct SendKeysActionExecutor {
+    backend: std::sync::Arc<dyn InputBackend>,
+}
+
+impl SendKeysActionExecutor {
+    pub fn new(backend: std::sync::Arc<dyn InputBackend>) -> Self {
+        Self { backend }
+    }
+}
+
+#[async_trait::async_trait]
+impl ActionExecutor for SendKeysActionExecutor {
+    async fn execute(&self, config: &ActionConfig, _ctx: &ActionContext) -> Result<ActionResult> {
+        let start_time = std::time::Instant::now();
+
+        if let ActionConfig::SendKeys { keys, delay_ms } = config {
+            info!("⌨️ Sending keys: {}", keys);
+
+            let strokes = match parse_key_sequence(keys) {
+                Ok(strokes) => strokes,
+                Err(e) => {
+                    error!("❌ Invalid key sequence '{}': {}", keys, e);
+                    return Ok(ActionResult {
+                        success: false,
+                        message: format!("Invalid key sequence '{}': {}", keys, e),
+                        execution_time_ms: start_time.elapsed().as_millis() as u64,
+                        output: None,
+                        error_code: None,
+                        invocation_id: None,
+                    });
+                }
+            };
+
+            let delay_ms = delay_ms.unwrap_or(0);
+            let mut first = true;
+
+            for stroke in &strokes {
+                // With a delay, text is typed one character at a time so the delay applies per key
+                let steps: Vec<KeyStroke> = match stroke {
+                    KeyStroke::Text(text) if delay_ms > 0 => {
+                        text.chars().map(|c| KeyStroke::Text(c.to_string())).collect()
+                    }
+                    KeyStroke::Chord { chord, repeat } => {
+                        vec![KeyStroke::Chord { chord: *chord, repeat: 1 }; *repeat as usize]
+                    }
+                    other => vec![other.clone()],
+                };
+
+                for step in steps {
+                    if !first && delay_ms > 0 {
+                        sleep(Duration::from_millis(delay_ms)).await;
+                    }
+                    first = false;
+
+                    let result = match &step {
+                        KeyStroke::Text(text) => self.backend.type_text(text),
+                        KeyStroke::Chord { chord, .. } => self.backend.press_chord(chord),
+                    };
+
+                    if let Err(e) = result {
+                        error!("❌ Failed to send keys: {}", e);
+                        return Ok(ActionResult {
+                            success: false,
+                            message: format!("Failed to send keys '{}': {}", keys, e),
+                            execution_time_ms: start_time.elapsed().as_millis() as u64,
+                            output: None,
+                            error_code: None,
+                            invocation_id: None,
+                        });
+                    }
+                }
+            }
+
+            let execution_time = start_time.elapsed().as_millis() as u64;
+            info!("✅ Keys sent successfully in {}ms", execution_time);
+            Ok(ActionResult {
+                success: true,
+                message: format!("Sent keys '{}'", keys),
+                execution_time_ms: execution_time,
+                output: None,
+                error_code: None,
+                invocation_id: None,
+            })
+        } else {
+            Err(anyhow::anyhow!("Invalid action config for SendKeys executor"))
+        }
+    }
+
+    fn supports_action_type(&self, config: &ActionConfig) -> bool {
+        matches!(config, ActionConfig::SendKeys { .. })
+    }
+}
+
+// PowerShell / shell script Action Executor
+pub struct PowerShellActionExecutor;
+
+impl PowerShellActionExecutor {
+    /// Resolve the interpreter and arguments for an inline script
+    fn build_command(script: &str, execution_policy: Option<&str>, shell: Option<&str>) -> (String, Vec<String>) {
+        let program = match shell {
+            Some(shell) => shell.to_string(),
+            None if cfg!(windows) => "powershell".to_string(),
+            None => {
+                // Prefer PowerShell 7 on Linux/macOS, otherwise fall back to the POSIX shell
+                if find_executable("pwsh").is_some() {
+                    "pwsh".to_string()
+                } else {
+                    "/bin/sh".to_string()
+                }
+            }
+        };
+
+        let program_name = std::path::Path::new(&program)
+            .file_stem()
+            .and_then(|s| s.to_str())
+            .unwrap_or(&program)
+            .to_lowercase();
+
+        let args = if program_name == "powershell" || program_name == "pwsh" {
+            let mut args = vec!["-NoProfile".to_string(), "-NonInteractive".to_string()];
+            if let Some(policy) = execution_policy {
+                if cfg!(windows) {
+                    args.push("-ExecutionPolicy".to_string());
+                    args.push(policy.to_string());
+                } else {
+                    debug!("🔒 Execution policy '{}' ignored on this platform", policy);
+                }
+            }
+            args.push("-Command".to_string());
+            args.push(script.to_string());
+            args
+        } else if program_name == "cmd" {
+            vec!["/C".to_string(), script.to_string()]
+        } else {
+            vec!["-c".to_string(), script.to_string()]
+        };
+
+        (program, args)
+    }
+}
+
+#[async_trait::async_trait]
+impl ActionExecutor for PowerShellActionExecutor {
+    async fn execute(&self, config: &ActionConfig, ctx: &ActionContext) -> Result<ActionResult> {
+        let start_time = std::time::Instant::now();
+
+        if let ActionConfig::PowerShell { script, workdir, execution_policy, shell, wait, timeout_ms, capture } = config {
+            let (program, args) = Self::build_command(script, execution_policy.as_deref(), shell.as_deref());
+            info!("📜 Running script with {}", program);
+            let capture = capture.unwrap_or(false);
+            let wait = wait.unwrap_or(false) || capture;
+            debug!("📝 Arguments: {:?}", args);
+
+            let mut command = tokio::process::Command::new(&program);
+            command.args(&args);
+
+            // Set working directory
+            if let Some(workdir) = workdir {
+                let expanded_workdir = expand_environment_variables(workdir);
+                command.current_dir(&expanded_workdir);
+                debug!("📁 Working directory: {}", expanded_workdir);
+            }
+            own_process_group(&mut command);
+
+            if !wait {
+                command.stdin(Stdio::null()).stdout(Stdio::null()).stderr(Stdio::null());
+
+                return match command.spawn() {
+                    Ok(child) => {
+                        ctx.track(child, &program, *timeout_ms);
+                        let execution_time = start_time.elapsed().as_millis() as u64;
+                        info!("✅ Script started in {}ms", execution_time);
+                        Ok(ActionResult {
+                            success: true,
+                            message: format!("Script started with '{}'", program),
+                            execution_time_ms: execution_time,
+                            output: None,
+                            error_code: None,
+                            invocation_id: None,
+                        })
+                    }
+                    Err(e) => {
+                        error!("❌ Failed to start script with '{}': {}", program, e);
+                        Ok(ActionResult {
+                            success: false,
+                            message: format!("Failed to start script with '{}': {}", program, e),
+                            execution_time_ms: start_time.elapsed().as_millis() as u64,
+                            output: None,
+                            error_code: None,
+                            invocation_id: None,
+                        })
+                    }
+                };
+            }
+
+            if capture {
+                command.stdin(Stdio::null()).stdout(Stdio::piped()).stderr(Stdio::piped());
+            } else {
+                command.stdin(Stdio::null()).stdout(Stdio::null()).stderr(Stdio::null());
+            }
+            let child = match command.spawn() {
+                Ok(child) => child,
+                Err(e) => {
+                    error!("❌ Failed to run script with '{}': {}", program, e);
+                    return Ok(ActionResult {
+                        success: false,
+                        message: format!("Failed to run script with '{}': {}", program, e),
+                        execution_time_ms: start_time.elapsed().as_millis() as u64,
+                        output: None,
+                        error_code: None,
+                        invocation_id: None,
+                    });
+                }
+            };
+
+            let (end, output) = wait_for_process(ctx, child, &program, *timeout_ms).await;
+            let execution_time = start_time.elapsed().as_millis() as u64;
+            Ok(waited_result("Script", end, *timeout_ms, output, execution_time))
+        } else {
+            Err(anyhow::anyhow!("Invalid action config for PowerShell executor"))
+        }
+    }
+
+    fn supports_action_type(&self, config: &ActionConfig) -> bool {
+        matches!(config, ActionConfig::PowerShell { .. })
+    }
+}
+
 // Multi Action Executor
 pub struct MultiActionExecutor {
     action_runner: std::sync::Arc<ActionRunner>,
@@ -605,7 +1246,7 @@ impl MultiActionExecutor {
 
 #[async_trait::async_trait]
 impl ActionExecutor for MultiActionExecutor {
-    async fn execute(&self, config: &ActionConfig) -> Result<ActionResult> {
+    async fn execute(&self, config: &ActionConfig, ctx: &ActionContext) -> Result<ActionResult> {
         let start_time = std::time::Instant::now();
         
         if let ActionConfig::MultiAction { actions, delay_between_ms, stop_on_error } = config {
@@ -617,9 +1258,14 @@ impl ActionExecutor for MultiActionExecutor {
             let mut total_success = true;
             
             for (index, action) in actions.iter().enumerate() {
+                if ctx.is_cancelled() {
+                    total_success = false;
+                    warn!("🛑 Multi-action cancelled before step {}", index + 1);
+                    break;
+                }
                 info!("▶️ Executing step {} of {}", index + 1, actions.len());
                 
-                match self.action_runner.execute_action_config(action).await {
+                match self.action_runner.execute_action(action, ctx).await {
                     Ok(result) => {
                         let success = result.success;
                         results.push(result);
@@ -646,6 +1292,7 @@ impl ActionExecutor for MultiActionExecutor {
                             execution_time_ms: 0,
                             output: None,
                             error_code: None,
+                            invocation_id: None,
                         });
                         
                         if stop_on_error {
@@ -677,6 +1324,7 @@ impl ActionExecutor for MultiActionExecutor {
                 execution_time_ms: execution_time,
                 output: Some(serde_json::to_string(&results)?),
                 error_code: None,
+                invocation_id: None,
             })
         } else {
             Err(anyhow::anyhow!("Invalid action config for MultiAction executor"))
@@ -703,33 +1351,348 @@ impl ActionRunner {
         runner.executors.push(Box::new(LaunchAppActionExecutor));
         runner.executors.push(Box::new(OpenActionExecutor));
         runner.executors.push(Box::new(TerminalActionExecutor));
-        
+        runner.executors.push(Box::new(SendKeysActionExecutor::new(default_input_backend())));
+        runner.executors.push(Box::new(PowerShellActionExecutor));
+
         info!("🎯 ActionRunner initialized with {} executors", runner.executors.len());
         Ok(runner)
     }
     
-    pub async fn execute_action_config(&self, config: &ActionConfig) -> Result<ActionResult> {
-        debug!("🎯 Executing action: {:?}", config);
+    /// Run an action as the invocation `ctx`; processes it starts are tracked under it
+    pub async fn execute_action(&self, config: &ActionConfig, ctx: &ActionContext) -> Result<ActionResult> {
+        debug!("🎯 Executing action {}: {:?}", ctx.invocation_id, config);
         
         // Find appropriate executor
-        for executor in &self.executors {
-            if executor.supports_action_type(config) {
-                return executor.execute(config).await;
+        let result = match self.executors.iter().find(|executor| executor.supports_action_type(config)) {
+            Some(executor) => executor.execute(config, ctx).await,
+            // Handle MultiAction separately since it needs a reference to the runner
+            None if matches!(config, ActionConfig::MultiAction { .. }) => {
+                let multi_executor = MultiActionExecutor::new(std::sync::Arc::new(Self::new()?));
+                multi_executor.execute(config, ctx).await
             }
-        }
-        
-        // Handle MultiAction separately since it needs a reference to the runner
-        if let ActionConfig::MultiAction { .. } = config {
-            let multi_executor = MultiActionExecutor::new(std::sync::Arc::new(Self::new()?));
-            return multi_executor.execute(config).await;
-        }
+            None => Err(anyhow::anyhow!("No executor found for action type {:?}", config.action_type())),
+        };
         
-        Err(anyhow::anyhow!("No executor found for action type"))
+        result.map(|result| ActionResult { invocation_id: Some(ctx.invocation_id.clone()), ..result })
     }
     
 
 }
 
+// Wait for a spawned child under the registry. Piped stdout and stderr are streamed line
+// by line while it runs and returned together; None when nothing was piped.
+async fn wait_for_process(
+    ctx: &ActionContext,
+    mut child: tokio::process::Child,
+    command: &str,
+    timeout_ms: Option<u64>,
+) -> (ProcessEnd, Option<String>) {
+    let stdout = child.stdout.take().map(|pipe| tokio::spawn(stream_lines(pipe, OutputStream::Stdout, ctx.clone())));
+    let stderr = child.stderr.take().map(|pipe| tokio::spawn(stream_lines(pipe, OutputStream::Stderr, ctx.clone())));
+    let end = ctx.track(child, command, timeout_ms).await.unwrap_or(ProcessEnd::Exited(None));
+    if stdout.is_none() && stderr.is_none() {
+        return (end, None);
+    }
+
+    let mut combined = collected_text(stdout).await;
+    let stderr = collected_text(stderr).await;
+    if !stderr.trim().is_empty() {
+        if !combined.is_empty() && !combined.ends_with('\n') {
+            combined.push('\n');
+        }
+        combined.push_str("[stderr]\n");
+        combined.push_str(&stderr);
+    }
+    (end, Some(combined))
+}
+
+// Read a pipe to the end, handing each line to the context's output sink as it arrives.
+// Reading while the process runs also keeps a chatty process from stalling on a full pipe.
+async fn stream_lines(pipe: impl AsyncRead + Unpin, stream: OutputStream, ctx: ActionContext) -> Vec<u8> {
+    let mut reader = BufReader::new(pipe);
+    let mut bytes = Vec::new();
+    loop {
+        let line_start = bytes.len();
+        match reader.read_until(b'\n', &mut bytes).await {
+            Ok(0) => break,
+            Ok(_) => ctx.emit_output(stream, &bytes[line_start..]),
+            Err(e) => {
+                debug!("Failed to read process output: {}", e);
+                break;
+            }
+        }
+    }
+    bytes
+}
+
+async fn collected_text(reader: Option<tokio::task::JoinHandle<Vec<u8>>>) -> String {
+    match reader {
+        Some(reader) => String::from_utf8_lossy(&reader.await.unwrap_or_default()).to_string(),
+        None => String::new(),
+    }
+}
+
+// Result for a process that was waited on; `subject` names it in the message
+fn waited_result(subject: &str, end: ProcessEnd, timeout_ms: Option<u64>, output: Option<String>, execution_time: u64) -> ActionResult {
+    let (success, message, error_code) = match end {
+        ProcessEnd::Exited(Some(0)) => (true, format!("{} completed successfully", subject), Some(0)),
+        ProcessEnd::Exited(error_code) => (false, format!("{} exited with code {:?}", subject, error_code), error_code),
+        ProcessEnd::Cancelled => (false, format!("{} was cancelled", subject), None),
+        ProcessEnd::TimedOut => (false, format!("{} timed out after {}ms", subject, timeout_ms.unwrap_or_default()), None),
+    };
+    if success {
+        info!("✅ {} in {}ms", message, execution_time);
+    } else {
+        error!("❌ {}", message);
+    }
+    ActionResult {
+        success,
+        message,
+        execution_time_ms: execution_time,
+        output,
+        error_code,
+        invocation_id: None,
+    }
+}
+
+// Linux terminal emulators supported by the Terminal executor
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum UnixTerminal {
+    GnomeTerminal,
+    Konsole,
+    Alacritty,
+    Kitty,
+    WezTerm,
+    Xterm,
+    /// Unknown `$TERMINAL`, launched with the xterm-compatible `-e` convention
+    Generic,
+}
+
+impl UnixTerminal {
+    /// Detection order used by the "auto" terminal value
+    const AUTO_ORDER: [UnixTerminal; 6] = [
+        UnixTerminal::GnomeTerminal,
+        UnixTerminal::Konsole,
+        UnixTerminal::WezTerm,
+        UnixTerminal::Kitty,
+        UnixTerminal::Alacritty,
+        UnixTerminal::Xterm,
+    ];
+
+    fn from_name(name: &str) -> Option<Self> {
+        let base = std::path::Path::new(name)
+            .file_name()
+            .map(|n| n.to_string_lossy().to_lowercase())
+            .unwrap_or_default();
+        match base.as_str() {
+            "gnome-terminal" => Some(UnixTerminal::GnomeTerminal),
+            "konsole" => Some(UnixTerminal::Konsole),
+            "alacritty" => Some(UnixTerminal::Alacritty),
+            "kitty" => Some(UnixTerminal::Kitty),
+            "wezterm" => Some(UnixTerminal::WezTerm),
+            "xterm" => Some(UnixTerminal::Xterm),
+            _ => None,
+        }
+    }
+
+    fn program(&self) -> &'static str {
+        match self {
+            UnixTerminal::GnomeTerminal => "gnome-terminal",
+            UnixTerminal::Konsole => "konsole",
+            UnixTerminal::Alacritty => "alacritty",
+            UnixTerminal::Kitty => "kitty",
+            UnixTerminal::WezTerm => "wezterm",
+            UnixTerminal::Xterm | UnixTerminal::Generic => "xterm",
+        }
+    }
+}
+
+struct TerminalLaunch {
+    program: String,
+    args: Vec<String>,
+    current_dir: Option<String>,
+}
+
+/// Resolve "auto" and "$TERMINAL" to a concrete terminal name
+fn resolve_terminal_name(terminal: &str) -> Option<String> {
+    if terminal == "$TERMINAL" {
+        return std::env::var("TERMINAL")
+            .ok()
+            .and_then(|t| t.split_whitespace().next().map(|t| t.to_string()))
+            .filter(|t| find_executable(t).is_some());
+    }
+    if !terminal.eq_ignore_ascii_case("auto") {
+        return Some(terminal.to_string());
+    }
+
+    if cfg!(windows) {
+        return Some(if find_executable("wt").is_some() { "WindowsTerminal" } else { "PowerShell" }.to_string());
+    }
+
+    if let Some(from_env) = resolve_terminal_name("$TERMINAL") {
+        return Some(from_env);
+    }
+    UnixTerminal::AUTO_ORDER
+        .iter()
+        .map(|kind| kind.program())
+        .find(|program| find_executable(program).is_some())
+        .map(|program| program.to_string())
+}
+
+/// Shell command run inside the terminal: applies `env` and keeps the shell open after `command`
+fn unix_shell_invocation(
+    command: Option<&str>,
+    env: Option<&HashMap<String, String>>,
+    shell: &str,
+) -> Option<Vec<String>> {
+    let env = env.filter(|env| !env.is_empty());
+    if command.is_none() && env.is_none() {
+        return None;
+    }
+
+    let mut invocation = Vec::new();
+    if let Some(env) = env {
+        // Terminal servers (gnome-terminal, kitty, wezterm) don't inherit the client's environment
+        let mut vars: Vec<_> = env.iter().collect();
+        vars.sort();
+        invocation.push("env".to_string());
+        for (key, value) in vars {
+            invocation.push(format!("{}={}", key, expand_environment_variables(value)));
+        }
+    }
+    invocation.push(shell.to_string());
+    if let Some(command) = command {
+        invocation.push("-c".to_string());
+        invocation.push(format!("{}; exec {}", command, shell));
+    }
+    Some(invocation)
+}
+
+/// Build the command line for a Linux terminal emulator
+fn build_unix_terminal_launch(
+    kind: UnixTerminal,
+    program: &str,
+    profile: Option<&str>,
+    workdir: Option<&str>,
+    extra_args: &[String],
+    inner: Option<Vec<String>>,
+) -> TerminalLaunch {
+    let mut args: Vec<String> = Vec::new();
+    let mut current_dir = None;
+
+    match kind {
+        UnixTerminal::GnomeTerminal => {
+            if let Some(profile) = profile {
+                args.push(format!("--profile={}", profile));
+            }
+            if let Some(workdir) = workdir {
+                args.push(format!("--working-directory={}", workdir));
+            }
+            args.extend_from_slice(extra_args);
+            if let Some(inner) = inner {
+                args.push("--".to_string());
+                args.extend(inner);
+            }
+        }
+        UnixTerminal::Konsole => {
+            if let Some(profile) = profile {
+                args.extend(["--profile".to_string(), profile.to_string()]);
+            }
+            if let Some(workdir) = workdir {
+                args.extend(["--workdir".to_string(), workdir.to_string()]);
+            }
+            args.extend_from_slice(extra_args);
+            if let Some(inner) = inner {
+                args.push("-e".to_string());
+                args.extend(inner);
+            }
+        }
+        UnixTerminal::Alacritty => {
+            // Alacritty has no profiles; treat the profile as a config file
+            if let Some(profile) = profile {
+                args.extend(["--config-file".to_string(), profile.to_string()]);
+            }
+            if let Some(workdir) = workdir {
+                args.extend(["--working-directory".to_string(), workdir.to_string()]);
+            }
+            args.extend_from_slice(extra_args);
+            if let Some(inner) = inner {
+                args.push("-e".to_string());
+                args.extend(inner);
+            }
+        }
+        UnixTerminal::Kitty => {
+            if let Some(profile) = profile {
+                args.extend(["--config".to_string(), profile.to_string()]);
+            }
+            if let Some(workdir) = workdir {
+                args.extend(["--directory".to_string(), workdir.to_string()]);
+            }
+            args.extend_from_slice(extra_args);
+            if let Some(inner) = inner {
+                args.extend(inner);
+            }
+        }
+        UnixTerminal::WezTerm => {
+            if let Some(profile) = profile {
+                args.extend(["--config-file".to_string(), profile.to_string()]);
+            }
+            args.push("start".to_string());
+            if let Some(workdir) = workdir {
+                args.extend(["--cwd".to_string(), workdir.to_string()]);
+            }
+            args.extend_from_slice(extra_args);
+            if let Some(inner) = inner {
+                args.push("--".to_string());
+                args.extend(inner);
+            }
+        }
+        UnixTerminal::Xterm | UnixTerminal::Generic => {
+            // xterm profiles are X resource instance names
+            if let (UnixTerminal::Xterm, Some(profile)) = (kind, profile) {
+                args.extend(["-name".to_string(), profile.to_string()]);
+            } else if profile.is_some() {
+                warn!("⚠️ Terminal '{}' does not support profiles, ignoring", program);
+            }
+            current_dir = workdir.map(|w| w.to_string());
+            args.extend_from_slice(extra_args);
+            if let Some(inner) = inner {
+                args.push("-e".to_string());
+                args.extend(inner);
+            }
+        }
+    }
+
+    TerminalLaunch {
+        program: program.to_string(),
+        args,
+        current_dir,
+    }
+}
+
+// Helper function to locate an executable on PATH
+fn find_executable(name: &str) -> Option<std::path::PathBuf> {
+    let candidate = std::path::Path::new(name);
+    if candidate.components().count() > 1 {
+        return candidate.is_file().then(|| candidate.to_path_buf());
+    }
+
+    let path_var = std::env::var_os("PATH")?;
+    for dir in std::env::split_paths(&path_var) {
+        let full_path = dir.join(name);
+        if full_path.is_file() {
+            return Some(full_path);
+        }
+        if cfg!(windows) {
+            let exe_path = dir.join(format!("{}.exe", name));
+            if exe_path.is_file() {
+                return Some(exe_path);
+            }
+        }
+    }
+    None
+}
+
 // Helper function to expand environment variables
 fn expand_environment_variables(input: &str) -> String {
     let mut result = input.to_string();
@@ -787,9 +1750,10 @@ mod tests {
         let config = ActionConfig::Open {
             target: test_file.to_string_lossy().to_string(),
             verb: Some("open".to_string()),
+            fallback_editor: None,
         };
 
-        let result = executor.execute(&config).await;
+        let result = executor.execute(&config, &ActionContext::detached(&config)).await;
         assert!(result.is_ok());
         
         let action_result = result.unwrap();
@@ -808,9 +1772,10 @@ mod tests {
         let config = ActionConfig::Open {
             target: test_dir.to_string_lossy().to_string(),
             verb: Some("open".to_string()),
+            fallback_editor: None,
         };
 
-        let result = executor.execute(&config).await;
+        let result = executor.execute(&config, &ActionContext::detached(&config)).await;
         assert!(result.is_ok());
         
         let action_result = result.unwrap();
@@ -833,9 +1798,10 @@ mod tests {
         let config = ActionConfig::Open {
             target: nonexistent_path.to_string(),
             verb: Some("open".to_string()),
+            fallback_editor: None,
         };
 
-        let result = executor.execute(&config).await;
+        let result = executor.execute(&config, &ActionContext::detached(&config)).await;
         assert!(result.is_ok());
         
         let action_result = result.unwrap();
@@ -862,9 +1828,10 @@ mod tests {
         let config = ActionConfig::Open {
             target: "relative_test.txt".to_string(),
             verb: Some("open".to_string()),
+            fallback_editor: None,
         };
 
-        let result = executor.execute(&config).await;
+        let result = executor.execute(&config, &ActionContext::detached(&config)).await;
         
         // Restore original directory
         std::env::set_current_dir(original_dir).expect("Failed to restore dir");
@@ -884,9 +1851,10 @@ mod tests {
         let config = ActionConfig::Open {
             target: test_file.to_string_lossy().to_string(),
             verb: Some("open".to_string()),
+            fallback_editor: None,
         };
 
-        let result = executor.execute(&config).await;
+        let result = executor.execute(&config, &ActionContext::detached(&config)).await;
         assert!(result.is_ok());
         
         let action_result = result.unwrap();
@@ -920,11 +1888,15 @@ mod tests {
             args: None,
             workdir: None,
             env: None,
+            timeout_ms: None,
+            wait: None,
+            capture: None,
         };
         
         let open_config = ActionConfig::Open {
             target: "test.txt".to_string(),
             verb: None,
+            fallback_editor: None,
         };
         
         assert!(executor.supports_action_type(&launch_config));
@@ -938,6 +1910,7 @@ mod tests {
         let open_config = ActionConfig::Open {
             target: "test.txt".to_string(),
             verb: None,
+            fallback_editor: None,
         };
         
         let launch_config = ActionConfig::LaunchApp {
@@ -945,14 +1918,324 @@ mod tests {
             args: None,
             workdir: None,
             env: None,
+            timeout_ms: None,
+            wait: None,
+            capture: None,
         };
         
         assert!(executor.supports_action_type(&open_config));
         assert!(!executor.supports_action_type(&launch_config));
     }
 
+    #[tokio::test]
+    async fn test_send_keys_executor_with_recording_backend() {
+        use crate::modules::input::{RecordedInput, RecordingInputBackend};
+        use crate::modules::keys::parse_chord;
+
+        let backend = std::sync::Arc::new(RecordingInputBackend::default());
+        let executor = SendKeysActionExecutor::new(backend.clone());
+        let config = ActionConfig::SendKeys {
+            keys: "git status{ENTER}{TAB 2}{Ctrl+Shift+T}".to_string(),
+            delay_ms: None,
+        };
+
+        let result = executor.execute(&config, &ActionContext::detached(&config)).await.unwrap();
+        assert!(result.success, "{}", result.message);
+        assert_eq!(backend.events(), vec![
+            RecordedInput::Text("git status".to_string()),
+            RecordedInput::Chord(parse_chord("Enter").unwrap()),
+            RecordedInput::Chord(parse_chord("Tab").unwrap()),
+            RecordedInput::Chord(parse_chord("Tab").unwrap()),
+            RecordedInput::Chord(parse_chord("Ctrl+Shift+T").unwrap()),
+        ]);
+    }
+
+    #[tokio::test]
+    async fn test_send_keys_executor_delay_types_per_character() {
+        use crate::modules::input::{RecordedInput, RecordingInputBackend};
+
+        let backend = std::sync::Arc::new(RecordingInputBackend::default());
+        let executor = SendKeysActionExecutor::new(backend.clone());
+        let config = ActionConfig::SendKeys {
+            keys: "ab".to_string(),
+            delay_ms: Some(1),
+        };
+
+        let result = executor.execute(&config, &ActionContext::detached(&config)).await.unwrap();
+        assert!(result.success);
+        assert_eq!(backend.events(), vec![
+            RecordedInput::Text("a".to_string()),
+            RecordedInput::Text("b".to_string()),
+        ]);
+    }
+
+    #[tokio::test]
+    async fn test_send_keys_executor_rejects_invalid_sequence() {
+        use crate::modules::input::RecordingInputBackend;
+
+        let backend = std::sync::Arc::new(RecordingInputBackend::default());
+        let executor = SendKeysActionExecutor::new(backend.clone());
+        let config = ActionConfig::SendKeys {
+            keys: "{NOPE}".to_string(),
+            delay_ms: None,
+        };
+
+        let result = executor.execute(&config, &ActionContext::detached(&config)).await.unwrap();
+        assert!(!result.success);
+        assert!(backend.events().is_empty());
+    }
+
     #[test]
-    fn test_expand_environment_variables() {
+    fn test_powershell_build_command() {
+        let (program, args) = PowerShellActionExecutor::build_command("echo hi", None, Some("/bin/bash"));
+        assert_eq!(program, "/bin/bash");
+        assert_eq!(args, vec!["-c", "echo hi"]);
+
+        let (program, args) = PowerShellActionExecutor::build_command("Get-Date", Some("Bypass"), Some("pwsh"));
+        assert_eq!(program, "pwsh");
+        assert_eq!(args.first().map(|s| s.as_str()), Some("-NoProfile"));
+        assert_eq!(args[args.len() - 2..], ["-Command".to_string(), "Get-Date".to_string()]);
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn test_powershell_executor_captures_output() {
+        let executor = PowerShellActionExecutor;
+        let config = ActionConfig::PowerShell {
+            script: "echo hello; echo oops 1>&2".to_string(),
+            workdir: None,
+            execution_policy: None,
+            shell: Some("/bin/sh".to_string()),
+            wait: None,
+            timeout_ms: None,
+            capture: Some(true),
+        };
+
+        let result = executor.execute(&config, &ActionContext::detached(&config)).await.unwrap();
+        assert!(result.success, "{}", result.message);
+        assert_eq!(result.error_code, Some(0));
+        let output = result.output.unwrap();
+        assert!(output.starts_with("hello\n"));
+        assert!(output.contains("[stderr]\noops"));
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn test_powershell_executor_reports_exit_code() {
+        let executor = PowerShellActionExecutor;
+        let config = ActionConfig::PowerShell {
+            script: "exit 3".to_string(),
+            workdir: None,
+            execution_policy: None,
+            shell: Some("/bin/sh".to_string()),
+            wait: Some(true),
+            timeout_ms: None,
+            capture: None,
+        };
+
+        let result = executor.execute(&config, &ActionContext::detached(&config)).await.unwrap();
+        assert!(!result.success);
+        assert_eq!(result.error_code, Some(3));
+        assert_eq!(result.output, None);
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn test_launch_app_capture_streams_output() {
+        let config = ActionConfig::LaunchApp {
+            path: "/bin/sh".to_string(),
+            args: Some(vec!["-c".to_string(), "echo one; echo oops 1>&2; echo two; exit 2".to_string()]),
+            workdir: None,
+            env: None,
+            timeout_ms: None,
+            wait: None,
+            capture: Some(true),
+        };
+        let lines = std::sync::Arc::new(std::sync::Mutex::new(Vec::new()));
+        let sink_lines = lines.clone();
+        let ctx = ActionContext::detached(&config)
+            .with_output_sink(std::sync::Arc::new(move |line: &ActionOutputLine| sink_lines.lock().unwrap().push(line.clone())));
+
+        let result = LaunchAppActionExecutor.execute(&config, &ctx).await.unwrap();
+        assert!(!result.success);
+        assert_eq!(result.error_code, Some(2));
+        assert_eq!(result.output.as_deref(), Some("one\ntwo\n[stderr]\noops\n"));
+
+        let lines = lines.lock().unwrap();
+        let streamed = |stream| lines.iter().filter(|line| line.stream == stream).map(|line| line.line.as_str()).collect::<Vec<_>>();
+        assert_eq!(streamed(OutputStream::Stdout), vec!["one", "two"]);
+        assert_eq!(streamed(OutputStream::Stderr), vec!["oops"]);
+        assert!(lines.iter().all(|line| line.invocation_id == ctx.invocation_id));
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn test_powershell_capture_streams_output() {
+        let config = ActionConfig::PowerShell {
+            script: "echo one; echo oops 1>&2; echo two; exit 2".to_string(),
+            workdir: None,
+            execution_policy: None,
+            shell: Some("/bin/sh".to_string()),
+            wait: None,
+            timeout_ms: None,
+            capture: Some(true),
+        };
+        let lines = std::sync::Arc::new(std::sync::Mutex::new(Vec::new()));
+        let sink_lines = lines.clone();
+        let ctx = ActionContext::detached(&config)
+            .with_output_sink(std::sync::Arc::new(move |line: &ActionOutputLine| sink_lines.lock().unwrap().push(line.clone())));
+
+        let result = PowerShellActionExecutor.execute(&config, &ctx).await.unwrap();
+        assert!(!result.success);
+        assert_eq!(result.error_code, Some(2));
+        assert_eq!(result.output.as_deref(), Some("one\ntwo\n[stderr]\noops\n"));
+
+        let lines = lines.lock().unwrap();
+        let streamed = |stream| lines.iter().filter(|line| line.stream == stream).map(|line| line.line.as_str()).collect::<Vec<_>>();
+        assert_eq!(streamed(OutputStream::Stdout), vec!["one", "two"]);
+        assert_eq!(streamed(OutputStream::Stderr), vec!["oops"]);
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn test_launch_app_wait_and_timeout() {
+        let launch = |script: &str, timeout_ms| ActionConfig::LaunchApp {
+            path: "/bin/sh".to_string(),
+            args: Some(vec!["-c".to_string(), script.to_string()]),
+            workdir: None,
+            env: None,
+            timeout_ms,
+            wait: Some(true),
+            capture: None,
+        };
+
+        let config = launch("exit 0", None);
+        let result = LaunchAppActionExecutor.execute(&config, &ActionContext::detached(&config)).await.unwrap();
+        assert!(result.success, "{}", result.message);
+        assert_eq!(result.error_code, Some(0));
+        assert_eq!(result.output, None);
+
+        let config = launch("sleep 30", Some(100));
+        let result = LaunchAppActionExecutor.execute(&config, &ActionContext::detached(&config)).await.unwrap();
+        assert!(!result.success);
+        assert!(result.message.contains("timed out"), "{}", result.message);
+    }
+
+    #[test]
+    fn test_open_candidates_mapping() {
+        let editor = Some("gedit");
+
+        // Default handler registered: xdg-open first, editor as last resort
+        let candidates = open_candidates("/tmp/a.txt", "open", false, false, true, editor);
+        assert_eq!(candidates[0].0, "xdg-open");
+        assert_eq!(candidates.last().unwrap(), &("gedit".to_string(), vec!["/tmp/a.txt".to_string()]));
+
+        // No default handler: fallback editor first
+        let candidates = open_candidates("/tmp/a.unknown", "open", false, false, false, editor);
+        assert_eq!(candidates[0].0, "gedit");
+
+        // Edit verb uses the editor
+        let candidates = open_candidates("/tmp/a.txt", "edit", false, false, true, Some("code --wait"));
+        assert_eq!(candidates[0], ("code".to_string(), vec!["--wait".to_string(), "/tmp/a.txt".to_string()]));
+
+        // Print verb maps to lp
+        let candidates = open_candidates("/tmp/a.txt", "print", false, false, true, editor);
+        assert_eq!(candidates, vec![("lp".to_string(), vec!["/tmp/a.txt".to_string()])]);
+
+        // Directories and URLs never go to the editor
+        let candidates = open_candidates("/tmp", "open", true, false, false, editor);
+        assert!(candidates.iter().all(|(program, _)| program != "gedit"));
+        let candidates = open_candidates("https://example.com", "edit", false, true, false, editor);
+        assert!(candidates.iter().all(|(program, _)| program != "gedit"));
+    }
+
+    #[test]
+    fn test_is_url_target() {
+        assert!(is_url_target("https://example.com"));
+        assert!(is_url_target("mailto:someone@example.com"));
+        assert!(!is_url_target("/home/user/file.txt"));
+        assert!(!is_url_target("relative/path"));
+    }
+
+    #[test]
+    fn test_unix_terminal_from_name() {
+        assert_eq!(UnixTerminal::from_name("gnome-terminal"), Some(UnixTerminal::GnomeTerminal));
+        assert_eq!(UnixTerminal::from_name("/usr/bin/kitty"), Some(UnixTerminal::Kitty));
+        assert_eq!(UnixTerminal::from_name("WezTerm"), Some(UnixTerminal::WezTerm));
+        assert_eq!(UnixTerminal::from_name("foot"), None);
+        assert_eq!(resolve_terminal_name("konsole"), Some("konsole".to_string()));
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn test_auto_terminal_launches_unknown_terminal_env() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let dir = TempDir::new().unwrap();
+        let foot = dir.path().join("foot");
+        fs::write(&foot, "#!/bin/sh\nexit 0\n").unwrap();
+        fs::set_permissions(&foot, fs::Permissions::from_mode(0o755)).unwrap();
+        std::env::set_var("TERMINAL", &foot);
+
+        let config = ActionConfig::Terminal {
+            terminal: "auto".to_string(),
+            profile: None,
+            workdir: None,
+            command: None,
+            env: None,
+            args: None,
+            timeout_ms: None,
+        };
+        let result = TerminalActionExecutor.execute(&config, &ActionContext::detached(&config)).await.unwrap();
+        std::env::remove_var("TERMINAL");
+        assert!(result.success, "{}", result.message);
+    }
+
+    #[test]
+    fn test_unix_shell_invocation() {
+        assert_eq!(unix_shell_invocation(None, None, "/bin/bash"), None);
+
+        let invocation = unix_shell_invocation(Some("make test"), None, "/bin/bash").unwrap();
+        assert_eq!(invocation, vec!["/bin/bash", "-c", "make test; exec /bin/bash"]);
+
+        let mut env = HashMap::new();
+        env.insert("B".to_string(), "2".to_string());
+        env.insert("A".to_string(), "1".to_string());
+        let invocation = unix_shell_invocation(None, Some(&env), "/bin/zsh").unwrap();
+        assert_eq!(invocation, vec!["env", "A=1", "B=2", "/bin/zsh"]);
+    }
+
+    #[test]
+    fn test_build_unix_terminal_launch() {
+        let inner = || Some(vec!["/bin/sh".to_string(), "-c".to_string(), "ls; exec /bin/sh".to_string()]);
+
+        let launch = build_unix_terminal_launch(UnixTerminal::GnomeTerminal, "gnome-terminal", Some("Dev"), Some("/tmp"), &[], inner());
+        assert_eq!(launch.args[..4], ["--profile=Dev", "--working-directory=/tmp", "--", "/bin/sh"]);
+        assert!(launch.current_dir.is_none());
+
+        let launch = build_unix_terminal_launch(UnixTerminal::Konsole, "konsole", Some("Dev"), Some("/tmp"), &[], inner());
+        assert_eq!(launch.args[..6], ["--profile", "Dev", "--workdir", "/tmp", "-e", "/bin/sh"]);
+
+        let launch = build_unix_terminal_launch(UnixTerminal::Alacritty, "alacritty", None, Some("/tmp"), &[], None);
+        assert_eq!(launch.args, vec!["--working-directory", "/tmp"]);
+
+        let launch = build_unix_terminal_launch(UnixTerminal::Kitty, "kitty", None, Some("/tmp"), &[], inner());
+        assert_eq!(launch.args[..3], ["--directory", "/tmp", "/bin/sh"]);
+
+        let launch = build_unix_terminal_launch(UnixTerminal::WezTerm, "wezterm", None, Some("/tmp"), &["--new-tab".to_string()], inner());
+        assert_eq!(launch.args[..5], ["start", "--cwd", "/tmp", "--new-tab", "--"]);
+
+        let launch = build_unix_terminal_launch(UnixTerminal::Xterm, "xterm", Some("dev"), Some("/tmp"), &[], inner());
+        assert_eq!(launch.args[..3], ["-name", "dev", "-e"]);
+        assert_eq!(launch.current_dir.as_deref(), Some("/tmp"));
+
+        let launch = build_unix_terminal_launch(UnixTerminal::Generic, "/usr/bin/foot", Some("dev"), None, &[], inner());
+        assert_eq!(launch.program, "/usr/bin/foot");
+        assert_eq!(launch.args[0], "-e");
+    }
+
+    #[test]
+    fn test_expand_environment_variables_basic() {
         // Test basic environment variable expansion
         std::env::set_var("TEST_VAR", "test_value");
         let result = expand_environment_variables("%TEST_VAR%/path");
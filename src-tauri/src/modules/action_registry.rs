@@ -0,0 +1,394 @@
+// Running actions by invocation ID: the processes they started, cancellation and timeouts
+use anyhow::{anyhow, Result};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+use tokio::process::Child;
+use tokio::sync::oneshot;
+use tracing::{debug, info, warn};
+
+use crate::modules::config::ActionType;
+
+/// How often tracked processes are checked for exit and timeouts
+const REAP_INTERVAL: Duration = Duration::from_millis(100);
+
+/// A process started by a running action
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RunningProcess {
+    pub pid: Option<u32>,
+    pub command: String,
+    pub timeout_ms: Option<u64>,
+}
+
+/// An action invocation that is still executing or still has processes alive
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RunningAction {
+    pub invocation_id: String,
+    pub button_id: Option<String>,
+    pub action_type: ActionType,
+    pub started_at: DateTime<Utc>,
+    pub elapsed_ms: u64,
+    pub processes: Vec<RunningProcess>,
+}
+
+/// How a tracked process ended
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ProcessEnd {
+    Exited(Option<i32>), // Exit code, None when killed by a signal
+    Cancelled,
+    TimedOut,
+}
+
+/// Result of `ActionRegistry::cancel`
+#[derive(Debug, Clone)]
+pub struct CancelledAction {
+    pub action: RunningAction,
+    /// The action had already returned its result (a launched app that kept running),
+    /// so nothing else will report the cancellation
+    pub returned: bool,
+}
+
+struct TrackedProcess {
+    key: u64,
+    info: RunningProcess,
+    child: Child,
+    started: Instant,
+    timeout: Option<Duration>,
+    waiter: Option<oneshot::Sender<ProcessEnd>>,
+}
+
+struct Invocation {
+    button_id: Option<String>,
+    action_type: ActionType,
+    started_at: DateTime<Utc>,
+    started: Instant,
+    processes: Vec<TrackedProcess>,
+    returned: bool,  // The action call finished; the entry goes once its processes are gone too
+    cancelled: bool,
+}
+
+impl Invocation {
+    fn snapshot(&self, invocation_id: &str) -> RunningAction {
+        RunningAction {
+            invocation_id: invocation_id.to_string(),
+            button_id: self.button_id.clone(),
+            action_type: self.action_type.clone(),
+            started_at: self.started_at,
+            elapsed_ms: self.started.elapsed().as_millis() as u64,
+            processes: self.processes.iter().map(|process| process.info.clone()).collect(),
+        }
+    }
+}
+
+/// Shared table of running actions. Cloning shares the same table.
+#[derive(Clone, Default)]
+pub struct ActionRegistry {
+    invocations: Arc<Mutex<HashMap<String, Invocation>>>,
+    next_key: Arc<AtomicU64>,
+}
+
+impl ActionRegistry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// An action call started
+    pub fn begin(&self, invocation_id: &str, button_id: Option<&str>, action_type: ActionType) {
+        let mut invocations = self.invocations.lock().unwrap();
+        invocations.insert(invocation_id.to_string(), Invocation {
+            button_id: button_id.map(str::to_string),
+            action_type,
+            started_at: Utc::now(),
+            started: Instant::now(),
+            processes: Vec::new(),
+            returned: false,
+            cancelled: false,
+        });
+    }
+
+    /// The action call returned. Processes it left running stay listed until they exit.
+    /// Returns true if the invocation was cancelled while the call was running.
+    pub fn finish(&self, invocation_id: &str) -> bool {
+        let mut invocations = self.invocations.lock().unwrap();
+        let invocation = match invocations.get_mut(invocation_id) {
+            Some(invocation) => invocation,
+            None => return false,
+        };
+        invocation.returned = true;
+        let cancelled = invocation.cancelled;
+        if invocation.processes.is_empty() {
+            invocations.remove(invocation_id);
+        }
+        cancelled
+    }
+
+    pub fn is_cancelled(&self, invocation_id: &str) -> bool {
+        let invocations = self.invocations.lock().unwrap();
+        invocations.get(invocation_id).is_some_and(|invocation| invocation.cancelled)
+    }
+
+    /// Take over a spawned process: it is listed under the invocation, killed with it on cancel
+    /// and killed after `timeout`. The receiver reports how it ended.
+    ///
+    /// Spawn it with `own_process_group` so the whole tree can be killed.
+    pub fn track(&self, invocation_id: &str, child: Child, command: &str, timeout: Option<Duration>) -> oneshot::Receiver<ProcessEnd> {
+        let (sender, receiver) = oneshot::channel();
+        let key = self.next_key.fetch_add(1, Ordering::Relaxed);
+        let mut process = TrackedProcess {
+            key,
+            info: RunningProcess {
+                pid: child.id(),
+                command: command.to_string(),
+                timeout_ms: timeout.map(|timeout| timeout.as_millis() as u64),
+            },
+            child,
+            started: Instant::now(),
+            timeout,
+            waiter: Some(sender),
+        };
+
+        {
+            let mut invocations = self.invocations.lock().unwrap();
+            match invocations.get_mut(invocation_id) {
+                Some(invocation) if invocation.cancelled => {
+                    // Cancelled between spawning and tracking: don't let it get away
+                    kill_tracked(&mut process);
+                    if let Some(waiter) = process.waiter.take() {
+                        let _ = waiter.send(ProcessEnd::Cancelled);
+                    }
+                    return receiver;
+                }
+                Some(invocation) => invocation.processes.push(process),
+                None => {
+                    warn!("Tracking process of unknown action invocation {}", invocation_id);
+                    invocations.insert(invocation_id.to_string(), Invocation {
+                        button_id: None,
+                        action_type: ActionType::LaunchApp,
+                        started_at: Utc::now(),
+                        started: Instant::now(),
+                        processes: vec![process],
+                        returned: true,
+                        cancelled: false,
+                    });
+                }
+            }
+        }
+
+        let registry = self.clone();
+        let invocation_id = invocation_id.to_string();
+        tokio::spawn(async move {
+            loop {
+                tokio::time::sleep(REAP_INTERVAL).await;
+                if registry.reap(&invocation_id, key) {
+                    break;
+                }
+            }
+        });
+
+        receiver
+    }
+
+    // Check one tracked process for exit or timeout; true once it is no longer tracked
+    fn reap(&self, invocation_id: &str, key: u64) -> bool {
+        let mut invocations = self.invocations.lock().unwrap();
+        let invocation = match invocations.get_mut(invocation_id) {
+            Some(invocation) => invocation,
+            None => return true,
+        };
+        let index = match invocation.processes.iter().position(|process| process.key == key) {
+            Some(index) => index,
+            None => return true,
+        };
+
+        let process = &mut invocation.processes[index];
+        let end = match process.child.try_wait() {
+            Ok(Some(status)) => ProcessEnd::Exited(status.code()),
+            Ok(None) => match process.timeout {
+                Some(timeout) if process.started.elapsed() >= timeout => {
+                    warn!("⏱️ '{}' of action {} timed out after {}ms, killing it", process.info.command, invocation_id, timeout.as_millis());
+                    kill_tracked(process);
+                    ProcessEnd::TimedOut
+                }
+                _ => return false,
+            },
+            Err(e) => {
+                debug!("Failed to check process status: {}", e);
+                ProcessEnd::Exited(None)
+            }
+        };
+
+        let mut process = invocation.processes.remove(index);
+        debug!("Process {:?} of action {} ended: {:?}", process.info.pid, invocation_id, end);
+        if let Some(waiter) = process.waiter.take() {
+            let _ = waiter.send(end);
+        }
+        if invocation.returned && invocation.processes.is_empty() {
+            invocations.remove(invocation_id);
+        }
+        true
+    }
+
+    /// Kill every process of an invocation, with their child processes, and mark it cancelled
+    pub fn cancel(&self, invocation_id: &str) -> Result<CancelledAction> {
+        let mut invocations = self.invocations.lock().unwrap();
+        let invocation = invocations.get_mut(invocation_id)
+            .ok_or_else(|| anyhow!("No running action with invocation ID '{}'", invocation_id))?;
+
+        let action = invocation.snapshot(invocation_id);
+        invocation.cancelled = true;
+        for mut process in invocation.processes.drain(..) {
+            kill_tracked(&mut process);
+            if let Some(waiter) = process.waiter.take() {
+                let _ = waiter.send(ProcessEnd::Cancelled);
+            }
+        }
+        let returned = invocation.returned;
+        if returned {
+            invocations.remove(invocation_id);
+        }
+
+        info!("🛑 Cancelled action {} ({} process(es))", invocation_id, action.processes.len());
+        Ok(CancelledAction { action, returned })
+    }
+
+    /// Running actions, oldest first
+    pub fn list(&self) -> Vec<RunningAction> {
+        let invocations = self.invocations.lock().unwrap();
+        let mut running: Vec<RunningAction> = invocations.iter()
+            .map(|(invocation_id, invocation)| invocation.snapshot(invocation_id))
+            .collect();
+        running.sort_by_key(|action| action.started_at);
+        running
+    }
+}
+
+fn kill_tracked(process: &mut TrackedProcess) {
+    if let Some(pid) = process.info.pid {
+        if let Err(e) = kill_process_tree(pid) {
+            debug!("Failed to kill process tree of {}: {:#}", pid, e);
+        }
+    }
+    // The tree kill covers this too, unless the process already left its group
+    let _ = process.child.start_kill();
+}
+
+/// Start the process in its own process group so `kill_process_tree` reaches its children
+pub fn own_process_group(command: &mut tokio::process::Command) {
+    #[cfg(unix)]
+    command.process_group(0);
+    #[cfg(not(unix))]
+    let _ = command;
+}
+
+/// Kill a process and every process it started
+pub fn kill_process_tree(pid: u32) -> Result<()> {
+    #[cfg(windows)]
+    let status = {
+        use std::os::windows::process::CommandExt;
+        const CREATE_NO_WINDOW: u32 = 0x0800_0000;
+        std::process::Command::new("taskkill")
+            .args(["/PID", &pid.to_string(), "/T", "/F"])
+            .creation_flags(CREATE_NO_WINDOW)
+            .stdout(std::process::Stdio::null())
+            .stderr(std::process::Stdio::null())
+            .status()?
+    };
+    // Tracked processes lead their own process group: kill the whole group
+    #[cfg(not(windows))]
+    let status = std::process::Command::new("kill")
+        .args(["-KILL", "--", &format!("-{}", pid)])
+        .stderr(std::process::Stdio::null())
+        .status()?;
+
+    if status.success() {
+        Ok(())
+    } else {
+        Err(anyhow!("kill exited with code {:?}", status.code()))
+    }
+}
+
+#[cfg(all(test, unix))]
+mod tests {
+    use super::*;
+    use std::process::Stdio;
+    use tokio::io::{AsyncBufReadExt, BufReader};
+
+    fn spawn_shell(script: &str) -> Child {
+        let mut command = tokio::process::Command::new("/bin/sh");
+        command.args(["-c", script]).stdin(Stdio::null()).stdout(Stdio::piped()).stderr(Stdio::null());
+        own_process_group(&mut command);
+        command.spawn().unwrap()
+    }
+
+    fn is_alive(pid: &str) -> bool {
+        std::process::Command::new("kill").args(["-0", pid]).stderr(Stdio::null()).status().unwrap().success()
+    }
+
+    #[tokio::test]
+    async fn test_process_exit_is_reported() {
+        let registry = ActionRegistry::new();
+        registry.begin("run-1", Some("build"), ActionType::PowerShell);
+        let ended = registry.track("run-1", spawn_shell("exit 4"), "sh", None);
+
+        assert_eq!(ended.await.unwrap(), ProcessEnd::Exited(Some(4)));
+        assert_eq!(registry.list().len(), 1, "the call has not returned yet");
+        assert!(!registry.finish("run-1"));
+        assert!(registry.list().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_cancel_kills_process_tree() {
+        let registry = ActionRegistry::new();
+        registry.begin("run-1", Some("deploy"), ActionType::LaunchApp);
+        let mut child = spawn_shell("sleep 30 & echo $!; wait");
+        let mut stdout = BufReader::new(child.stdout.take().unwrap()).lines();
+        let ended = registry.track("run-1", child, "sh", None);
+        let grandchild = stdout.next_line().await.unwrap().unwrap();
+        assert!(is_alive(&grandchild));
+
+        // The launch returned while the process kept running
+        assert!(!registry.finish("run-1"));
+        let running = registry.list();
+        assert_eq!(running.len(), 1);
+        assert_eq!(running[0].button_id.as_deref(), Some("deploy"));
+        assert_eq!(running[0].processes.len(), 1);
+
+        let cancelled = registry.cancel("run-1").unwrap();
+        assert!(cancelled.returned);
+        assert_eq!(ended.await.unwrap(), ProcessEnd::Cancelled);
+        assert!(registry.list().is_empty());
+        assert!(registry.cancel("run-1").is_err());
+
+        // The child the shell started died with it
+        let deadline = Instant::now() + Duration::from_secs(2);
+        while is_alive(&grandchild) && Instant::now() < deadline {
+            tokio::time::sleep(Duration::from_millis(20)).await;
+        }
+        assert!(!is_alive(&grandchild));
+    }
+
+    #[tokio::test]
+    async fn test_timeout_and_cancel_while_running() {
+        let registry = ActionRegistry::new();
+        registry.begin("run-1", None, ActionType::PowerShell);
+        let ended = registry.track("run-1", spawn_shell("sleep 30"), "sh", Some(Duration::from_millis(150)));
+        let end = tokio::time::timeout(Duration::from_secs(5), ended).await.unwrap().unwrap();
+        assert_eq!(end, ProcessEnd::TimedOut);
+        assert!(!registry.finish("run-1"));
+
+        // Cancelled while the call is still waiting: the call reports it when it returns
+        registry.begin("run-2", None, ActionType::MultiAction);
+        let ended = registry.track("run-2", spawn_shell("sleep 30"), "sh", None);
+        assert!(!registry.cancel("run-2").unwrap().returned);
+        assert_eq!(ended.await.unwrap(), ProcessEnd::Cancelled);
+        assert!(registry.is_cancelled("run-2"));
+        // Anything started after the cancel is killed straight away
+        let late = registry.track("run-2", spawn_shell("sleep 30"), "sh", None);
+        assert_eq!(late.await.unwrap(), ProcessEnd::Cancelled);
+        assert!(registry.finish("run-2"));
+        assert!(registry.list().is_empty());
+    }
+}
@@ -3,6 +3,10 @@ use std::collections::HashMap;
 use std::path::{Path, PathBuf};
 use anyhow::{Context, Result};
 
+use crate::modules::action::ActionConfig;
+use crate::modules::migration::{self, ConfigVersion, MigrationOutcome, CURRENT_CONFIG_VERSION};
+use crate::modules::validation::{self, ValidationReport};
+
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct QDeckConfig {
@@ -21,6 +25,49 @@ pub struct UIConfig {
 pub struct SummonConfig {
     pub hotkeys: Vec<String>,
     pub edge_trigger: Option<EdgeTriggerConfig>,
+    #[serde(default = "default_sequence_timeout_ms")]
+    pub sequence_timeout_ms: u64, // Time allowed between the keys of a hotkey like "Ctrl+Space, G, P"
+    #[serde(default = "default_fallback_hotkeys")]
+    pub fallback_hotkeys: Vec<String>, // Tried in order when none of `hotkeys` can be registered
+    #[serde(default)]
+    pub mode: SummonMode,
+    #[serde(default = "default_hold_threshold_ms")]
+    pub hold_threshold_ms: u64, // Hold mode: shorter presses leave the overlay open
+    #[serde(default)]
+    pub double_tap: Option<String>, // Modifier that toggles the overlay when tapped twice, e.g. "Ctrl"
+    #[serde(default = "default_double_tap_interval_ms")]
+    pub double_tap_interval_ms: u64, // Longest tap, and longest gap between the two taps
+}
+
+/// How the summon hotkeys behave
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SummonMode {
+    /// Each press shows or hides the overlay
+    #[default]
+    Toggle,
+    /// Holding the key shows the overlay; letting go hides it and runs the hovered button
+    Hold,
+}
+
+fn default_sequence_timeout_ms() -> u64 {
+    crate::modules::hotkey_sequence::DEFAULT_SEQUENCE_TIMEOUT_MS
+}
+
+fn default_hold_threshold_ms() -> u64 {
+    crate::modules::hotkey_gesture::DEFAULT_HOLD_THRESHOLD_MS
+}
+
+fn default_double_tap_interval_ms() -> u64 {
+    crate::modules::hotkey_gesture::DEFAULT_DOUBLE_TAP_INTERVAL_MS
+}
+
+fn default_monitor() -> String {
+    "primary".to_string()
+}
+
+fn default_fallback_hotkeys() -> Vec<String> {
+    vec!["Ctrl+Alt+Q".to_string(), "Ctrl+F11".to_string(), "Alt+F12".to_string()]
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -33,7 +80,9 @@ pub struct EdgeTriggerConfig {
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct WindowConfig {
-    pub placement: String,
+    pub placement: String, // dropdown-top, dropdown-bottom, slide-left, slide-right, center, at-cursor or remember-last
+    #[serde(default = "default_monitor")]
+    pub monitor: String, // "primary", "cursor" (the monitor under the pointer) or a monitor name
     pub width_px: u32,
     pub height_px: u32,
     pub cell_size_px: u32,
@@ -53,34 +102,165 @@ pub struct AnimationConfig {
 pub struct Profile {
     pub name: String,
     pub hotkey: Option<String>,
+    #[serde(default, rename = "match")]
+    pub match_rule: Option<ProfileMatch>, // Switch to this profile when a matching window is focused
     pub pages: Vec<Page>,
 }
 
+/// Foreground-window rule for automatic profile switching; every field that is set must match
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ProfileMatch {
+    pub exe: Option<String>,          // Executable name, case-insensitive, ".exe" optional
+    pub window_class: Option<String>, // X11 WM_CLASS (instance or class) / Win32 class name, case-insensitive
+    pub title: Option<String>,        // Regex searched in the window title
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Page {
     pub name: String,
     pub rows: u32,
     pub cols: u32,
+    pub hotkey: Option<String>, // Jumps to this page
     pub buttons: Vec<ActionButton>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(try_from = "ActionButtonRepr")]
 pub struct ActionButton {
+    /// Persistent identifier; generated on load when missing
+    pub id: String,
     pub position: Position,
-    pub action_type: ActionType,
     pub label: String,
     pub icon: Option<String>,
-    pub config: HashMap<String, serde_json::Value>,
     pub style: Option<ButtonStyle>,
-    pub action: Option<ActionConfig>,
+    pub hotkey: Option<String>, // Runs the button without opening the overlay
+    pub action: ActionConfig,
+}
+
+impl ActionButton {
+    pub fn folder(&self) -> Option<&FolderConfig> {
+        match &self.action {
+            ActionConfig::Folder(folder) => Some(folder),
+            _ => None,
+        }
+    }
+
+    pub fn folder_mut(&mut self) -> Option<&mut FolderConfig> {
+        match &mut self.action {
+            ActionConfig::Folder(folder) => Some(folder),
+            _ => None,
+        }
+    }
 }
 
+/// On-disk shape of a button. Accepts the typed `action` as well as the
+/// older `action_type` + untyped `config` map (and `folder`) layout, whose
+/// `action: { action_type: system, system_action }` marks a System button.
+#[derive(Deserialize)]
+struct ActionButtonRepr {
+    #[serde(default)]
+    id: String,
+    position: Position,
+    label: String,
+    icon: Option<String>,
+    style: Option<ButtonStyle>,
+    hotkey: Option<String>,
+    action: Option<serde_json::Value>,
+    action_type: Option<ActionType>,
+    #[serde(default)]
+    config: HashMap<String, serde_json::Value>,
+    folder: Option<FolderConfig>,
+}
+
+impl TryFrom<ActionButtonRepr> for ActionButton {
+    type Error = String;
+
+    fn try_from(repr: ActionButtonRepr) -> std::result::Result<Self, Self::Error> {
+        let context = format!("button '{}' at ({}, {})", repr.label, repr.position.row, repr.position.col);
+
+        // The legacy `action` field held a struct without a `type` tag; only its system action is kept
+        let (typed_action, legacy_action) = match repr.action {
+            Some(action) if action.get("type").is_some() => (Some(action), None),
+            other => (None, other),
+        };
+        let system_action = legacy_action.as_ref()
+            .filter(|action| action.get("action_type").and_then(|t| t.as_str()) == Some("system"))
+            .and_then(|action| action.get("system_action"))
+            .filter(|system_action| !system_action.is_null())
+            .cloned();
+        let action_type = repr.action_type.or(system_action.as_ref().map(|_| ActionType::System));
+
+        let action = match (typed_action, action_type) {
+            (Some(action), _) => serde_json::from_value(action)
+                .map_err(|e| format!("{}: invalid action: {}", context, e))?,
+            (None, Some(action_type)) => {
+                let mut config = repr.config;
+                if let Some(folder) = repr.folder {
+                    if let Ok(serde_json::Value::Object(fields)) = serde_json::to_value(folder) {
+                        config.extend(fields);
+                    }
+                }
+                if let (ActionType::System, Some(system_action)) = (&action_type, system_action) {
+                    config.entry("system_action".to_string()).or_insert(system_action);
+                }
+                legacy_action_config(&action_type, config)
+                    .map_err(|e| format!("{}: {}", context, e))?
+            }
+            (None, None) => return Err(format!("{}: missing 'action'", context)),
+        };
+
+        Ok(ActionButton {
+            id: repr.id,
+            position: repr.position,
+            label: repr.label,
+            icon: repr.icon,
+            style: repr.style,
+            hotkey: repr.hotkey,
+            action,
+        })
+    }
+}
+
+/// Convert the legacy `action_type` + `config` map of a button into a typed action
+pub fn legacy_action_config(
+    action_type: &ActionType,
+    config: HashMap<String, serde_json::Value>,
+) -> std::result::Result<ActionConfig, String> {
+    let tag = serde_json::to_value(action_type).map_err(|e| e.to_string())?;
+    let tagged = legacy_to_tagged(tag, config.into_iter().collect());
+
+    serde_json::from_value(tagged).map_err(|e| format!("invalid {:?} action: {}", action_type, e))
+}
+
+/// Rewrite a legacy `{ action_type, config }` pair (recursively for MultiAction) into `{ type, ... }`
+pub fn legacy_to_tagged(
+    tag: serde_json::Value,
+    mut fields: serde_json::Map<String, serde_json::Value>,
+) -> serde_json::Value {
+    if let Some(serde_json::Value::Array(actions)) = fields.get_mut("actions") {
+        for action in actions.iter_mut() {
+            let nested = match action {
+                serde_json::Value::Object(nested) if !nested.contains_key("type") => nested,
+                _ => continue,
+            };
+            let nested_tag = nested.remove("action_type").unwrap_or(serde_json::Value::Null);
+            let nested_fields = match nested.remove("config") {
+                Some(serde_json::Value::Object(nested_fields)) => nested_fields,
+                _ => serde_json::Map::new(),
+            };
+            *action = legacy_to_tagged(nested_tag, nested_fields);
+        }
+    }
+
+    fields.insert("type".to_string(), tag);
+    serde_json::Value::Object(fields)
+}
+
+/// Target of a Folder button: an inline child page, or the name of another page in the same profile
 #[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct ActionConfig {
-    pub action_type: String,
-    pub system_action: Option<String>,
-    pub app_config: Option<HashMap<String, serde_json::Value>>,
-    pub command_config: Option<HashMap<String, serde_json::Value>>,
+pub struct FolderConfig {
+    pub page: Option<Box<Page>>,
+    pub page_ref: Option<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, Copy)]
@@ -98,6 +278,33 @@ pub enum ActionType {
     PowerShell,
     Folder,
     MultiAction,
+    #[serde(alias = "system")]
+    System,
+}
+
+/// Where a button lives in the config tree
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ButtonLocation {
+    pub profile_index: usize,
+    pub page_index: usize,
+    /// Button indices of the inline Folder buttons leading to the button's page
+    pub folder_path: Vec<usize>,
+    pub button_index: usize,
+}
+
+impl ButtonLocation {
+    /// Page that directly contains the button
+    pub fn page<'a>(&self, config: &'a QDeckConfig) -> Option<&'a Page> {
+        let mut page = config.profiles.get(self.profile_index)?.pages.get(self.page_index)?;
+        for &folder_index in &self.folder_path {
+            page = page.buttons.get(folder_index)?.folder()?.page.as_deref()?;
+        }
+        Some(page)
+    }
+
+    pub fn resolve<'a>(&self, config: &'a QDeckConfig) -> Option<&'a ActionButton> {
+        self.page(config)?.buttons.get(self.button_index)
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -111,7 +318,7 @@ pub struct ButtonStyle {
 impl Default for QDeckConfig {
     fn default() -> Self {
         Self {
-            version: "1.0".to_string(),
+            version: CURRENT_CONFIG_VERSION.to_string(),
             ui: UIConfig::default(),
             profiles: vec![Profile::default()],
         }
@@ -132,6 +339,12 @@ impl Default for SummonConfig {
         Self {
             hotkeys: vec!["F11".to_string()],
             edge_trigger: Some(EdgeTriggerConfig::default()),
+            sequence_timeout_ms: default_sequence_timeout_ms(),
+            fallback_hotkeys: default_fallback_hotkeys(),
+            mode: SummonMode::default(),
+            hold_threshold_ms: default_hold_threshold_ms(),
+            double_tap: None,
+            double_tap_interval_ms: default_double_tap_interval_ms(),
         }
     }
 }
@@ -151,6 +364,7 @@ impl Default for WindowConfig {
     fn default() -> Self {
         Self {
             placement: "dropdown-top".to_string(),
+            monitor: default_monitor(),
             width_px: 1000,
             height_px: 600,
             cell_size_px: 96,
@@ -176,6 +390,7 @@ impl Default for Profile {
         Self {
             name: "Default".to_string(),
             hotkey: None,
+            match_rule: None,
             pages: vec![Page::default()],
         }
     }
@@ -187,24 +402,136 @@ impl Default for Page {
             name: "Main".to_string(),
             rows: 3,
             cols: 6,
+            hotkey: None,
             buttons: vec![],
         }
     }
 }
 
+/// Number of timestamped backups kept next to the config file
+pub const MAX_CONFIG_BACKUPS: usize = 10;
+
+/// A backup copy of the config file
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ConfigBackup {
+    pub file_name: String,
+    pub path: String,
+    pub created_at: chrono::DateTime<chrono::Local>,
+    pub size_bytes: u64,
+    /// Set for backups taken before a schema migration
+    pub migrated_from: Option<String>,
+}
+
+/// Write via a temp file in the same directory, fsync, then rename over the target
+pub fn write_atomic(path: &Path, contents: &str) -> Result<()> {
+    use std::io::Write;
+
+    let parent = path.parent()
+        .filter(|p| !p.as_os_str().is_empty())
+        .unwrap_or_else(|| Path::new("."));
+    let file_name = path.file_name()
+        .ok_or_else(|| anyhow::anyhow!("Invalid config path: {}", path.display()))?
+        .to_string_lossy();
+    let temp_path = parent.join(format!(".{}.tmp-{}", file_name, std::process::id()));
+
+    let result = (|| -> Result<()> {
+        let mut file = std::fs::File::create(&temp_path)
+            .with_context(|| format!("Failed to create {}", temp_path.display()))?;
+        file.write_all(contents.as_bytes())
+            .with_context(|| format!("Failed to write {}", temp_path.display()))?;
+        file.sync_all()
+            .with_context(|| format!("Failed to flush {}", temp_path.display()))?;
+        drop(file);
+
+        std::fs::rename(&temp_path, path)
+            .with_context(|| format!("Failed to move config into place at {}", path.display()))?;
+
+        // Persist the rename itself
+        #[cfg(unix)]
+        if let Ok(dir) = std::fs::File::open(parent) {
+            let _ = dir.sync_all();
+        }
+        Ok(())
+    })();
+
+    if result.is_err() {
+        let _ = std::fs::remove_file(&temp_path);
+    }
+    result
+}
+
 pub struct ConfigManager {
     config_path: PathBuf,
     config: QDeckConfig,
+    button_index: HashMap<String, ButtonLocation>,
+    load_error: Option<String>, // Why config.yaml couldn't be loaded at startup, until it loads again
 }
 
 impl ConfigManager {
+    /// Load config.yaml. If it can't be read, parsed or migrated, the newest valid backup
+    /// (or the defaults) is used instead and the file is left alone; see `load_error`.
     pub fn new() -> Result<Self> {
-        let config_path = Self::get_config_path()?;
-        let config = Self::load_or_create_config(&config_path)?;
-        
-        Ok(Self {
+        Self::open(Self::get_config_path()?)
+    }
+
+    fn open(config_path: PathBuf) -> Result<Self> {
+        let mut manager = Self {
             config_path,
-            config,
+            config: QDeckConfig::default(),
+            button_index: HashMap::new(),
+            load_error: None,
+        };
+        
+        match Self::load_or_create_config(&manager.config_path) {
+            Ok(config) => manager.config = config,
+            Err(e) => {
+                let fallback = match manager.newest_valid_backup() {
+                    Some((file_name, config)) => {
+                        manager.config = config;
+                        format!("using backup '{}'", file_name)
+                    }
+                    None => "using the default config".to_string(),
+                };
+                let message = format!("Failed to load {}, {}: {:#}", manager.config_path.display(), fallback, e);
+                tracing::error!("❌ {}", message);
+                manager.load_error = Some(message);
+            }
+        }
+        
+        let assigned = Self::assign_button_ids(&mut manager.config);
+        manager.button_index = Self::build_button_index(&manager.config);
+        
+        // Persist newly generated IDs so external references stay stable (never over a file that failed to load)
+        if assigned > 0 && manager.load_error.is_none() {
+            tracing::info!("🆔 Assigned IDs to {} buttons", assigned);
+            if let Err(e) = manager.save_config() {
+                tracing::warn!("⚠️ Failed to save generated button IDs: {:#}", e);
+            }
+        }
+        
+        Ok(manager)
+    }
+
+    /// Startup load failure, kept until the config is successfully saved or reloaded
+    pub fn load_error(&self) -> Option<&str> {
+        self.load_error.as_deref()
+    }
+
+    // Newest backup that parses and validates, for when config.yaml itself can't be loaded
+    fn newest_valid_backup(&self) -> Option<(String, QDeckConfig)> {
+        let backups = match self.list_backups() {
+            Ok(backups) => backups,
+            Err(e) => {
+                tracing::warn!("⚠️ Failed to list config backups: {:#}", e);
+                return None;
+            }
+        };
+        
+        backups.into_iter().find_map(|backup| {
+            let content = std::fs::read_to_string(&backup.path).ok()?;
+            let (config, _) = Self::parse_config(&content).ok()?;
+            self.validate_config(&config).ok()?;
+            Some((backup.file_name, config))
         })
     }
 
@@ -212,10 +539,112 @@ impl ConfigManager {
         &self.config
     }
 
-    pub fn update_config(&mut self, config: QDeckConfig) -> Result<()> {
+    pub fn update_config(&mut self, mut config: QDeckConfig) -> Result<()> {
+        Self::assign_button_ids(&mut config);
+        self.validate_config(&config)?;
+        self.button_index = Self::build_button_index(&config);
+        self.config = config;
+        self.save_config()?;
+        self.load_error = None;
+        Ok(())
+    }
+
+    pub fn config_path(&self) -> &Path {
+        &self.config_path
+    }
+
+    /// Re-read the config file after an external edit. Returns `Ok(false)` when the file
+    /// matches the in-memory config (e.g. our own save); on error the current config is kept.
+    pub fn reload(&mut self) -> Result<bool> {
+        let content = std::fs::read_to_string(&self.config_path)
+            .context("Failed to read config file")?;
+        let (mut config, outcome) = Self::parse_config(&content)?;
+        let assigned = Self::assign_button_ids(&mut config);
         self.validate_config(&config)?;
+        self.load_error = None;
+        
+        let unchanged = serde_yaml::to_string(&config).ok() == serde_yaml::to_string(&self.config).ok();
+        if unchanged && assigned == 0 && !outcome.migrated() {
+            return Ok(false);
+        }
+        
+        if outcome.migrated() {
+            Self::write_migrated_config(&self.config_path, &config, &outcome)?;
+        }
+        
+        self.button_index = Self::build_button_index(&config);
         self.config = config;
-        self.save_config()
+        
+        if assigned > 0 {
+            tracing::info!("🆔 Assigned IDs to {} buttons", assigned);
+            self.save_config()?;
+        }
+        
+        tracing::info!("🔄 Config reloaded from: {}", self.config_path.display());
+        Ok(!unchanged)
+    }
+
+    /// Look up a button by its persistent ID
+    pub fn find_button(&self, id: &str) -> Option<(&ButtonLocation, &ActionButton)> {
+        let location = self.button_index.get(id)?;
+        location.resolve(&self.config).map(|button| (location, button))
+    }
+
+    /// Give every button without an ID (or with a duplicated one) a fresh UUID.
+    /// Returns the number of IDs generated.
+    pub fn assign_button_ids(config: &mut QDeckConfig) -> usize {
+        fn assign(page: &mut Page, seen: &mut std::collections::HashSet<String>, assigned: &mut usize) {
+            for button in &mut page.buttons {
+                if button.id.is_empty() || !seen.insert(button.id.clone()) {
+                    if !button.id.is_empty() {
+                        tracing::warn!("⚠️ Duplicate button ID '{}' on '{}', generating a new one", button.id, button.label);
+                    }
+                    button.id = uuid::Uuid::new_v4().to_string();
+                    seen.insert(button.id.clone());
+                    *assigned += 1;
+                }
+                if let Some(child) = button.folder_mut().and_then(|f| f.page.as_deref_mut()) {
+                    assign(child, seen, assigned);
+                }
+            }
+        }
+
+        let mut seen = std::collections::HashSet::new();
+        let mut assigned = 0;
+        for profile in &mut config.profiles {
+            for page in &mut profile.pages {
+                assign(page, &mut seen, &mut assigned);
+            }
+        }
+        assigned
+    }
+
+    fn build_button_index(config: &QDeckConfig) -> HashMap<String, ButtonLocation> {
+        fn index_page(page: &Page, base: &ButtonLocation, index: &mut HashMap<String, ButtonLocation>) {
+            for (button_index, button) in page.buttons.iter().enumerate() {
+                let location = ButtonLocation { button_index, ..base.clone() };
+                if let Some(child) = button.folder().and_then(|f| f.page.as_deref()) {
+                    let mut child_base = base.clone();
+                    child_base.folder_path.push(button_index);
+                    index_page(child, &child_base, index);
+                }
+                index.insert(button.id.clone(), location);
+            }
+        }
+
+        let mut index = HashMap::new();
+        for (profile_index, profile) in config.profiles.iter().enumerate() {
+            for (page_index, page) in profile.pages.iter().enumerate() {
+                let base = ButtonLocation {
+                    profile_index,
+                    page_index,
+                    folder_path: Vec::new(),
+                    button_index: 0,
+                };
+                index_page(page, &base, &mut index);
+            }
+        }
+        index
     }
 
     pub fn save_config(&self) -> Result<()> {
@@ -227,11 +656,18 @@ impl ConfigManager {
                 .context("Failed to create config directory")?;
         }
         
-        std::fs::write(&self.config_path, yaml_content.clone())
+        // Keep the previous version unless nothing changed
+        match std::fs::read_to_string(&self.config_path) {
+            Ok(existing) if existing == yaml_content => return Ok(()),
+            Ok(_) => self.create_backup()?,
+            Err(_) => {}
+        }
+        
+        write_atomic(&self.config_path, &yaml_content)
             .context("Failed to write config file")?;
         
         // In development mode, also sync to common locations to prevent confusion
-        if cfg!(debug_assertions) {
+        if cfg!(debug_assertions) && !cfg!(test) {
             self.sync_config_files(&yaml_content)?;
         }
         
@@ -252,7 +688,7 @@ impl ConfigManager {
             if location != self.config_path {
                 if let Some(parent) = location.parent() {
                     if parent.exists() {
-                        match std::fs::write(&location, yaml_content) {
+                        match write_atomic(&location, yaml_content) {
                             Ok(_) => tracing::info!("🔄 Synced config to: {}", location.display()),
                             Err(e) => tracing::warn!("⚠️ Failed to sync config to {}: {}", location.display(), e),
                         }
@@ -264,6 +700,103 @@ impl ConfigManager {
         Ok(())
     }
 
+    // e.g. config.yaml -> config.yaml.20240101T120000123.bak
+    fn create_backup(&self) -> Result<()> {
+        let file_name = self.config_file_name();
+        let timestamp = chrono::Local::now().format("%Y%m%dT%H%M%S%3f");
+        let mut backup_path = self.config_path.with_file_name(format!("{}.{}.bak", file_name, timestamp));
+        let mut suffix = 1;
+        while backup_path.exists() {
+            backup_path = self.config_path.with_file_name(format!("{}.{}-{}.bak", file_name, timestamp, suffix));
+            suffix += 1;
+        }
+        
+        std::fs::copy(&self.config_path, &backup_path)
+            .with_context(|| format!("Failed to back up config to {}", backup_path.display()))?;
+        tracing::debug!("💾 Backed up config to: {}", backup_path.display());
+        
+        // Rotate regular backups; pre-migration backups are kept
+        let mut rotating: Vec<ConfigBackup> = self.list_backups()?
+            .into_iter()
+            .filter(|b| b.migrated_from.is_none())
+            .collect();
+        if rotating.len() > MAX_CONFIG_BACKUPS {
+            for old in rotating.drain(MAX_CONFIG_BACKUPS..) {
+                match std::fs::remove_file(&old.path) {
+                    Ok(_) => tracing::debug!("🗑️ Removed old config backup: {}", old.file_name),
+                    Err(e) => tracing::warn!("⚠️ Failed to remove old backup {}: {}", old.file_name, e),
+                }
+            }
+        }
+        
+        Ok(())
+    }
+    
+    fn config_file_name(&self) -> String {
+        self.config_path.file_name()
+            .map(|n| n.to_string_lossy().to_string())
+            .unwrap_or_else(|| "config.yaml".to_string())
+    }
+    
+    /// Backups next to the config file, newest first
+    pub fn list_backups(&self) -> Result<Vec<ConfigBackup>> {
+        let prefix = format!("{}.", self.config_file_name());
+        let dir = match self.config_path.parent().filter(|p| !p.as_os_str().is_empty()) {
+            Some(dir) => dir.to_path_buf(),
+            None => PathBuf::from("."),
+        };
+        if !dir.exists() {
+            return Ok(Vec::new());
+        }
+        
+        let mut backups = Vec::new();
+        for entry in std::fs::read_dir(&dir).context("Failed to read config directory")? {
+            let entry = entry?;
+            let file_name = entry.file_name().to_string_lossy().to_string();
+            let stamp = match file_name.strip_prefix(&prefix).and_then(|rest| rest.strip_suffix(".bak")) {
+                Some(stamp) => stamp,
+                None => continue,
+            };
+            
+            let metadata = entry.metadata()?;
+            let migrated_from = stamp.strip_prefix('v')
+                .and_then(|rest| rest.split('-').next())
+                .map(|version| version.to_string());
+            let created_at = metadata.modified()
+                .map(chrono::DateTime::<chrono::Local>::from)
+                .unwrap_or_else(|_| chrono::Local::now());
+            
+            backups.push(ConfigBackup {
+                file_name: file_name.clone(),
+                path: entry.path().to_string_lossy().to_string(),
+                created_at,
+                size_bytes: metadata.len(),
+                migrated_from,
+            });
+        }
+        
+        // Timestamps in the names sort chronologically; mtime breaks ties across naming schemes
+        backups.sort_by(|a, b| b.created_at.cmp(&a.created_at).then_with(|| b.file_name.cmp(&a.file_name)));
+        Ok(backups)
+    }
+    
+    /// Replace the current config with a backup (the current file is backed up first)
+    pub fn restore_backup(&mut self, file_name: &str) -> Result<()> {
+        let backup = self.list_backups()?
+            .into_iter()
+            .find(|b| b.file_name == file_name)
+            .ok_or_else(|| anyhow::anyhow!("Config backup '{}' not found", file_name))?;
+        
+        let content = std::fs::read_to_string(&backup.path)
+            .with_context(|| format!("Failed to read backup {}", backup.path))?;
+        let (config, _) = Self::parse_config(&content)
+            .with_context(|| format!("Backup '{}' is not a valid config", file_name))?;
+        
+        self.update_config(config)?;
+        tracing::info!("♻️ Restored config from backup: {}", file_name);
+        Ok(())
+    }
+
     fn get_config_path() -> Result<PathBuf> {
         // Development mode: Use project root config.yaml
         if cfg!(debug_assertions) {
@@ -321,8 +854,14 @@ impl ConfigManager {
             tracing::info!("📄 Loading config from: {}", config_path.display());
             tracing::debug!("📄 Config content:\n{}", content);
             
-            let config: QDeckConfig = serde_yaml::from_str(&content)
-                .context("Failed to parse config YAML")?;
+            let (config, outcome) = Self::parse_config(&content)?;
+            
+            // The upgraded config is already in memory; failing to persist it isn't fatal
+            if outcome.migrated() {
+                if let Err(e) = Self::write_migrated_config(config_path, &config, &outcome) {
+                    tracing::warn!("⚠️ Failed to write migrated config: {:#}", e);
+                }
+            }
             
             tracing::info!("✅ Config loaded successfully");
             tracing::debug!("📋 Config structure: {:#?}", config);
@@ -350,69 +889,59 @@ impl ConfigManager {
                     .context("Failed to create config directory")?;
             }
             
-            std::fs::write(config_path, yaml_content)
+            write_atomic(config_path, &yaml_content)
                 .context("Failed to write default config file")?;
             
             Ok(default_config)
         }
     }
 
-    fn validate_config(&self, config: &QDeckConfig) -> Result<()> {
-        // Validate version
-        if config.version.is_empty() {
-            return Err(anyhow::anyhow!("Config version cannot be empty"));
-        }
-
-        // Validate UI config
-        if config.ui.window.width_px == 0 || config.ui.window.height_px == 0 {
-            return Err(anyhow::anyhow!("Window dimensions must be greater than 0"));
-        }
-
-        if config.ui.window.opacity < 0.0 || config.ui.window.opacity > 1.0 {
-            return Err(anyhow::anyhow!("Window opacity must be between 0.0 and 1.0"));
-        }
-
-        // Validate profiles
-        if config.profiles.is_empty() {
-            return Err(anyhow::anyhow!("At least one profile must be defined"));
+    /// Parse config YAML, upgrading older schema versions on the way
+    fn parse_config(content: &str) -> Result<(QDeckConfig, MigrationOutcome)> {
+        let mut raw: serde_yaml::Value = serde_yaml::from_str(content)
+            .context("Failed to parse config YAML")?;
+        
+        let outcome = migration::migrate(&mut raw)?;
+        for step in &outcome.applied {
+            tracing::info!("🔄 Applied config migration {}", step);
         }
+        
+        let config: QDeckConfig = serde_yaml::from_value(raw)
+            .context("Failed to parse config YAML")?;
+        Ok((config, outcome))
+    }
 
-        for profile in &config.profiles {
-            if profile.name.is_empty() {
-                return Err(anyhow::anyhow!("Profile name cannot be empty"));
-            }
-
-            if profile.pages.is_empty() {
-                return Err(anyhow::anyhow!("Profile must have at least one page"));
-            }
-
-            for page in &profile.pages {
-                if page.name.is_empty() {
-                    return Err(anyhow::anyhow!("Page name cannot be empty"));
-                }
-
-                if page.rows == 0 || page.cols == 0 {
-                    return Err(anyhow::anyhow!("Page dimensions must be greater than 0"));
-                }
+    // Back up the pre-migration file, then replace it with the upgraded config
+    fn write_migrated_config(config_path: &Path, config: &QDeckConfig, outcome: &MigrationOutcome) -> Result<()> {
+        let backup_path = Self::migration_backup_path(config_path, outcome.from);
+        std::fs::copy(config_path, &backup_path)
+            .with_context(|| format!("Failed to back up config to {}", backup_path.display()))?;
+        tracing::info!("💾 Backed up version {} config to: {}", outcome.from, backup_path.display());
+        
+        let yaml_content = serde_yaml::to_string(config)
+            .context("Failed to serialize migrated config")?;
+        write_atomic(config_path, &yaml_content)
+            .context("Failed to write migrated config file")?;
+        tracing::info!("✅ Migrated config from {} to {}", outcome.from, outcome.to);
+        Ok(())
+    }
 
-                // Validate button positions
-                for button in &page.buttons {
-                    if button.position.row > page.rows || button.position.col > page.cols {
-                        return Err(anyhow::anyhow!(
-                            "Button position ({}, {}) exceeds page dimensions ({}, {})",
-                            button.position.row, button.position.col,
-                            page.rows, page.cols
-                        ));
-                    }
+    // e.g. config.yaml -> config.yaml.v1.0-20240101T120000.bak
+    fn migration_backup_path(config_path: &Path, from: ConfigVersion) -> PathBuf {
+        let file_name = config_path.file_name()
+            .map(|n| n.to_string_lossy().to_string())
+            .unwrap_or_else(|| "config.yaml".to_string());
+        let timestamp = chrono::Local::now().format("%Y%m%dT%H%M%S");
+        config_path.with_file_name(format!("{}.v{}-{}.bak", file_name, from, timestamp))
+    }
 
-                    if button.label.is_empty() {
-                        return Err(anyhow::anyhow!("Button label cannot be empty"));
-                    }
-                }
-            }
-        }
+    fn validate_config(&self, config: &QDeckConfig) -> Result<()> {
+        validation::validate(config).into_result()
+    }
 
-        Ok(())
+    /// Full validation report for a config (the loaded one when `None`)
+    pub fn validation_report(&self, config: Option<&QDeckConfig>) -> ValidationReport {
+        validation::validate(config.unwrap_or(&self.config))
     }
 
     pub fn export_config(&self, export_path: &Path) -> Result<()> {
@@ -429,28 +958,43 @@ impl ConfigManager {
         let content = std::fs::read_to_string(import_path)
             .context("Failed to read import config file")?;
         
-        let imported_config: QDeckConfig = serde_yaml::from_str(&content)
+        let (imported_config, _) = Self::parse_config(&content)
             .context("Failed to parse imported config YAML")?;
         
-        self.validate_config(&imported_config)?;
-        self.config = imported_config;
-        self.save_config()?;
-        
-        Ok(())
+        self.update_config(imported_config)
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::modules::action::SystemAction;
     use tempfile::TempDir;
 
+    fn open_button(label: &str, row: u32) -> ActionButton {
+        ActionButton {
+            id: String::new(),
+            position: Position { row, col: 1 },
+            label: label.to_string(),
+            icon: None,
+            style: None,
+            hotkey: None,
+            action: ActionConfig::Open {
+                target: "C:\\".to_string(),
+                verb: None,
+                fallback_editor: None,
+            },
+        }
+    }
+
     #[test]
     fn test_default_config_validation() {
         let config = QDeckConfig::default();
         let manager = ConfigManager {
             config_path: PathBuf::from("test.yaml"),
             config: config.clone(),
+            button_index: HashMap::new(),
+            load_error: None,
         };
         
         assert!(manager.validate_config(&config).is_ok());
@@ -464,11 +1008,407 @@ mod tests {
         let manager = ConfigManager {
             config_path: PathBuf::from("test.yaml"),
             config: QDeckConfig::default(),
+            button_index: HashMap::new(),
+            load_error: None,
         };
         
         assert!(manager.validate_config(&config).is_err());
     }
 
+    #[test]
+    fn test_folder_validation() {
+        let manager = ConfigManager {
+            config_path: PathBuf::from("test.yaml"),
+            config: QDeckConfig::default(),
+            button_index: HashMap::new(),
+            load_error: None,
+        };
+
+        let mut config = QDeckConfig::default();
+        config.profiles[0].pages.push(Page {
+            name: "Tools".to_string(),
+            ..Page::default()
+        });
+        let mut folder_button = open_button("Tools", 1);
+        folder_button.action = ActionConfig::Folder(FolderConfig {
+            page: None,
+            page_ref: Some("Tools".to_string()),
+        });
+        config.profiles[0].pages[0].buttons.push(folder_button.clone());
+        assert!(manager.validate_config(&config).is_ok());
+
+        // Unknown page reference
+        folder_button.action = ActionConfig::Folder(FolderConfig { page: None, page_ref: Some("Missing".to_string()) });
+        config.profiles[0].pages[0].buttons[0] = folder_button.clone();
+        assert!(manager.validate_config(&config).is_err());
+
+        // Both inline page and reference
+        folder_button.action = ActionConfig::Folder(FolderConfig {
+            page: Some(Box::new(Page::default())),
+            page_ref: Some("Tools".to_string()),
+        });
+        config.profiles[0].pages[0].buttons[0] = folder_button;
+        assert!(manager.validate_config(&config).is_err());
+    }
+
+    #[test]
+    fn test_button_ids_and_index() {
+        let dir = TempDir::new().unwrap();
+        let mut manager = ConfigManager {
+            config_path: dir.path().join("config.yaml"),
+            config: QDeckConfig::default(),
+            button_index: HashMap::new(),
+            load_error: None,
+        };
+
+        let mut config = QDeckConfig::default();
+        let mut nested = Page { name: "Nested".to_string(), ..Page::default() };
+        nested.buttons.push(open_button("Same", 1));
+        let mut folder = open_button("Folder", 2);
+        folder.action = ActionConfig::Folder(FolderConfig { page: Some(Box::new(nested)), page_ref: None });
+        config.profiles[0].pages[0].buttons = vec![open_button("Same", 1), folder];
+
+        manager.update_config(config).unwrap();
+
+        // Duplicate labels get distinct IDs and each resolves to its own location
+        let top_id = manager.get_config().profiles[0].pages[0].buttons[0].id.clone();
+        let nested_id = manager.get_config().profiles[0].pages[0].buttons[1]
+            .folder().unwrap().page.as_ref().unwrap().buttons[0].id.clone();
+        assert!(!top_id.is_empty());
+        assert_ne!(top_id, nested_id);
+
+        let (location, found) = manager.find_button(&nested_id).unwrap();
+        assert_eq!(found.label, "Same");
+        assert_eq!(location.folder_path, vec![1]);
+        assert_eq!(manager.find_button(&top_id).unwrap().0.folder_path, Vec::<usize>::new());
+
+        // IDs survive renaming the page and round-trip through YAML
+        let mut renamed = manager.get_config().clone();
+        renamed.profiles[0].pages[0].name = "Renamed".to_string();
+        renamed.profiles[0].pages[0].buttons.swap(0, 1);
+        manager.update_config(renamed).unwrap();
+        assert_eq!(manager.find_button(&top_id).unwrap().0.button_index, 1);
+
+        let reloaded: QDeckConfig = serde_yaml::from_str(&std::fs::read_to_string(dir.path().join("config.yaml")).unwrap()).unwrap();
+        assert_eq!(reloaded.profiles[0].pages[0].buttons[1].id, top_id);
+        assert!(manager.find_button("missing").is_none());
+    }
+
+    #[test]
+    fn test_duplicate_button_ids_are_regenerated() {
+        let mut config = QDeckConfig::default();
+        let mut button = open_button("A", 1);
+        button.id = "fixed".to_string();
+        config.profiles[0].pages[0].buttons = vec![button.clone(), button];
+
+        assert_eq!(ConfigManager::assign_button_ids(&mut config), 1);
+        let buttons = &config.profiles[0].pages[0].buttons;
+        assert_eq!(buttons[0].id, "fixed");
+        assert_ne!(buttons[1].id, "fixed");
+    }
+
+    #[test]
+    fn test_typed_button_action_yaml() {
+        let yaml = r#"
+id: launch-1
+position: { row: 1, col: 2 }
+label: Editor
+icon: null
+style: null
+action:
+  type: LaunchApp
+  path: code
+  args: ["--new-window"]
+"#;
+        let button: ActionButton = serde_yaml::from_str(yaml).unwrap();
+        assert_eq!(button.action.action_type(), ActionType::LaunchApp);
+        match &button.action {
+            ActionConfig::LaunchApp { path, args, .. } => {
+                assert_eq!(path, "code");
+                assert_eq!(args.as_deref(), Some(&["--new-window".to_string()][..]));
+            }
+            other => panic!("unexpected action: {:?}", other),
+        }
+
+        // Round-trips in the typed format
+        let serialized = serde_yaml::to_string(&button).unwrap();
+        assert!(serialized.contains("type: LaunchApp"));
+        assert!(!serialized.contains("action_type"));
+        let reparsed: ActionButton = serde_yaml::from_str(&serialized).unwrap();
+        assert_eq!(reparsed.id, "launch-1");
+    }
+
+    #[test]
+    fn test_legacy_button_format_is_converted() {
+        let yaml = r#"
+position: { row: 1, col: 1 }
+action_type: MultiAction
+label: Both
+icon: null
+style: null
+action: null
+config:
+  delay_between_ms: 100
+  actions:
+    - action_type: Open
+      config: { target: 'C:\Users' }
+    - action_type: SendKeys
+      config: { keys: "Ctrl+C" }
+"#;
+        let button: ActionButton = serde_yaml::from_str(yaml).unwrap();
+        match &button.action {
+            ActionConfig::MultiAction { actions, delay_between_ms, .. } => {
+                assert_eq!(*delay_between_ms, Some(100));
+                assert_eq!(actions.len(), 2);
+                assert_eq!(actions[0].action_type(), ActionType::Open);
+                assert_eq!(actions[1].action_type(), ActionType::SendKeys);
+            }
+            other => panic!("unexpected action: {:?}", other),
+        }
+
+        let folder_yaml = r#"
+position: { row: 2, col: 1 }
+action_type: Folder
+label: Tools
+icon: null
+config: {}
+style: null
+action: { action_type: system, system_action: null, app_config: null, command_config: null }
+folder: { page: null, page_ref: Tools }
+"#;
+        let folder: ActionButton = serde_yaml::from_str(folder_yaml).unwrap();
+        assert_eq!(folder.folder().unwrap().page_ref.as_deref(), Some("Tools"));
+
+        // The Settings button the Electron app writes into config.yaml
+        let system_yaml = r#"
+position: { row: 2, col: 1 }
+action_type: system
+label: Settings
+icon: "⚙️"
+config: {}
+action: { action_type: system, system_action: config }
+"#;
+        let system: ActionButton = serde_yaml::from_str(system_yaml).unwrap();
+        assert_eq!(system.action.action_type(), ActionType::System);
+        assert!(matches!(system.action, ActionConfig::System { system_action: SystemAction::Config }));
+
+        let serialized = serde_yaml::to_string(&system).unwrap();
+        assert!(serialized.contains("system_action: config"), "{}", serialized);
+        let reparsed: ActionButton = serde_yaml::from_str(&serialized).unwrap();
+        assert!(matches!(reparsed.action, ActionConfig::System { system_action: SystemAction::Config }));
+    }
+
+    #[test]
+    fn test_invalid_button_action_errors() {
+        let missing_field = r#"
+position: { row: 3, col: 4 }
+label: Broken
+action: { type: LaunchApp, args: [] }
+"#;
+        let err = serde_yaml::from_str::<ActionButton>(missing_field).unwrap_err().to_string();
+        assert!(err.contains("button 'Broken' at (3, 4)"), "{}", err);
+        assert!(err.contains("missing field `path`"), "{}", err);
+
+        let unknown_type = r#"
+position: { row: 1, col: 1 }
+label: Typo
+action_type: Opn
+config: { target: x }
+"#;
+        assert!(serde_yaml::from_str::<ActionButton>(unknown_type).is_err());
+
+        let legacy_missing = r#"
+position: { row: 1, col: 1 }
+label: Legacy
+action_type: Open
+config: { tragte: x }
+"#;
+        let err = serde_yaml::from_str::<ActionButton>(legacy_missing).unwrap_err().to_string();
+        assert!(err.contains("invalid Open action: missing field `target`"), "{}", err);
+
+        let no_action = "position: { row: 1, col: 1 }\nlabel: Empty\n";
+        let err = serde_yaml::from_str::<ActionButton>(no_action).unwrap_err().to_string();
+        assert!(err.contains("missing 'action'"), "{}", err);
+    }
+
+    #[test]
+    fn test_load_migrates_and_backs_up_legacy_config() {
+        let dir = TempDir::new().unwrap();
+        let config_path = dir.path().join("config.yaml");
+        let legacy = r#"
+version: "1.0"
+ui:
+  summon: { hotkeys: ["F11"], edge_trigger: null }
+  window:
+    placement: dropdown-top
+    width_px: 1000
+    height_px: 600
+    cell_size_px: 96
+    gap_px: 8
+    opacity: 0.9
+    theme: dark
+    animation: { enabled: true, duration_ms: 150 }
+profiles:
+  - name: Default
+    hotkey: null
+    pages:
+      - name: Main
+        rows: 3
+        cols: 6
+        buttons:
+          - position: { row: 1, col: 1 }
+            action_type: SendKeys
+            label: Copy
+            icon: null
+            config: { keys: "Ctrl+C" }
+            style: null
+"#;
+        std::fs::write(&config_path, legacy).unwrap();
+
+        let config = ConfigManager::load_or_create_config(&config_path).unwrap();
+        assert_eq!(config.version, CURRENT_CONFIG_VERSION.to_string());
+        assert_eq!(config.profiles[0].pages[0].buttons[0].action.action_type(), ActionType::SendKeys);
+
+        // Original kept as a backup, rewritten file is in the new format
+        let backups: Vec<_> = std::fs::read_dir(dir.path()).unwrap()
+            .filter_map(|e| e.ok())
+            .map(|e| e.file_name().to_string_lossy().to_string())
+            .filter(|name| name.starts_with("config.yaml.v1.0-") && name.ends_with(".bak"))
+            .collect();
+        assert_eq!(backups.len(), 1);
+        assert_eq!(std::fs::read_to_string(dir.path().join(&backups[0])).unwrap(), legacy);
+        let rewritten = std::fs::read_to_string(&config_path).unwrap();
+        assert!(rewritten.contains("type: SendKeys"));
+        assert!(!rewritten.contains("action_type"));
+    }
+
+    #[test]
+    fn test_open_falls_back_to_backup_without_touching_broken_file() {
+        let dir = TempDir::new().unwrap();
+        let config_path = dir.path().join("config.yaml");
+
+        // Nothing to fall back to: defaults
+        let broken = "version: \"1.0\"\nprofiles:\n  - name: Default\n    pages:\n      - name: Main\n        buttons:\n          - position: { row: 2, col: 3 }\n            action_type: LaunchApp\n            label: Broken\n            config: {}\n";
+        std::fs::write(&config_path, broken).unwrap();
+        let manager = ConfigManager::open(config_path.clone()).unwrap();
+        let error = manager.load_error().unwrap();
+        assert!(error.contains("using the default config"), "{}", error);
+        assert_eq!(manager.get_config().profiles[0].name, "Default");
+        assert_eq!(std::fs::read_to_string(&config_path).unwrap(), broken);
+
+        // With a valid backup, the newest one is used
+        let mut backed_up = QDeckConfig::default();
+        backed_up.profiles[0].name = "Backed up".to_string();
+        std::fs::write(dir.path().join("config.yaml.20240101T120000000.bak"), serde_yaml::to_string(&backed_up).unwrap()).unwrap();
+        let mut manager = ConfigManager::open(config_path.clone()).unwrap();
+        assert!(manager.load_error().unwrap().contains("config.yaml.20240101T120000000.bak"));
+        assert_eq!(manager.get_config().profiles[0].name, "Backed up");
+        assert_eq!(std::fs::read_to_string(&config_path).unwrap(), broken);
+
+        // Fixing the file clears the error
+        std::fs::write(&config_path, serde_yaml::to_string(&QDeckConfig::default()).unwrap()).unwrap();
+        assert!(manager.reload().unwrap());
+        assert!(manager.load_error().is_none());
+    }
+
+    #[test]
+    fn test_load_refuses_newer_config() {
+        let dir = TempDir::new().unwrap();
+        let config_path = dir.path().join("config.yaml");
+        let mut future = serde_yaml::to_value(QDeckConfig::default()).unwrap();
+        future["version"] = serde_yaml::Value::from("99.0");
+        let content = serde_yaml::to_string(&future).unwrap();
+        std::fs::write(&config_path, &content).unwrap();
+
+        assert!(ConfigManager::load_or_create_config(&config_path).is_err());
+        // The file is left untouched
+        assert_eq!(std::fs::read_to_string(&config_path).unwrap(), content);
+    }
+
+    #[test]
+    fn test_reload_applies_valid_edits_and_rejects_invalid_ones() {
+        let dir = TempDir::new().unwrap();
+        let config_path = dir.path().join("config.yaml");
+        let mut manager = ConfigManager {
+            config_path: config_path.clone(),
+            config: QDeckConfig::default(),
+            button_index: HashMap::new(),
+            load_error: None,
+        };
+        manager.update_config(QDeckConfig::default()).unwrap();
+
+        // Our own write is not a change
+        assert!(!manager.reload().unwrap());
+
+        // A hand edit is picked up, and new buttons get IDs written back
+        let mut edited = QDeckConfig::default();
+        edited.profiles[0].name = "Edited".to_string();
+        edited.profiles[0].pages[0].buttons.push(open_button("New", 1));
+        std::fs::write(&config_path, serde_yaml::to_string(&edited).unwrap()).unwrap();
+        assert!(manager.reload().unwrap());
+        assert_eq!(manager.get_config().profiles[0].name, "Edited");
+        let id = manager.get_config().profiles[0].pages[0].buttons[0].id.clone();
+        assert!(manager.find_button(&id).is_some());
+        assert!(std::fs::read_to_string(&config_path).unwrap().contains(&id));
+
+        // Invalid YAML and invalid values are reported and the current config is kept
+        std::fs::write(&config_path, "profiles: [").unwrap();
+        assert!(manager.reload().is_err());
+        edited.ui.window.opacity = 3.0;
+        std::fs::write(&config_path, serde_yaml::to_string(&edited).unwrap()).unwrap();
+        assert!(manager.reload().is_err());
+        assert_eq!(manager.get_config().profiles[0].name, "Edited");
+        assert!(manager.find_button(&id).is_some());
+    }
+
+    #[test]
+    fn test_write_atomic_replaces_file_without_leftovers() {
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join("config.yaml");
+        write_atomic(&path, "first").unwrap();
+        write_atomic(&path, "second").unwrap();
+
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), "second");
+        let entries: Vec<_> = std::fs::read_dir(dir.path()).unwrap().collect();
+        assert_eq!(entries.len(), 1);
+    }
+
+    #[test]
+    fn test_backups_rotate_and_restore() {
+        let dir = TempDir::new().unwrap();
+        let mut manager = ConfigManager {
+            config_path: dir.path().join("config.yaml"),
+            config: QDeckConfig::default(),
+            button_index: HashMap::new(),
+            load_error: None,
+        };
+
+        // First save has nothing to back up; unchanged saves don't create backups
+        manager.update_config(QDeckConfig::default()).unwrap();
+        manager.save_config().unwrap();
+        assert!(manager.list_backups().unwrap().is_empty());
+
+        for i in 0..MAX_CONFIG_BACKUPS + 3 {
+            let mut config = QDeckConfig::default();
+            config.profiles[0].name = format!("Profile {}", i);
+            manager.update_config(config).unwrap();
+        }
+
+        let backups = manager.list_backups().unwrap();
+        assert_eq!(backups.len(), MAX_CONFIG_BACKUPS);
+        assert!(backups.iter().all(|b| b.file_name.starts_with("config.yaml.") && b.migrated_from.is_none()));
+
+        // Newest backup holds the previous version
+        let newest = backups[0].file_name.clone();
+        manager.restore_backup(&newest).unwrap();
+        let last = MAX_CONFIG_BACKUPS + 1;
+        assert_eq!(manager.get_config().profiles[0].name, format!("Profile {}", last));
+
+        // Only listed backups can be restored
+        assert!(manager.restore_backup("../config.yaml").is_err());
+        assert!(manager.restore_backup("missing.bak").is_err());
+    }
+
     #[test]
     fn test_config_serialization() {
         let config = QDeckConfig::default();
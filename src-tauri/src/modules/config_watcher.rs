@@ -0,0 +1,110 @@
+// Config file watching for hot-reload
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::thread;
+use std::time::{Duration, SystemTime};
+use tracing::{debug, info};
+
+/// Polls the config file and calls `on_change` once an edit has settled
+pub struct ConfigWatcher {
+    stop: Arc<AtomicBool>,
+    handle: Option<thread::JoinHandle<()>>,
+}
+
+// Modification time and size; `None` while the file is missing
+type Fingerprint = Option<(SystemTime, u64)>;
+
+fn fingerprint(path: &Path) -> Fingerprint {
+    let metadata = std::fs::metadata(path).ok()?;
+    Some((metadata.modified().ok()?, metadata.len()))
+}
+
+impl ConfigWatcher {
+    pub fn spawn<F>(path: PathBuf, poll_interval: Duration, on_change: F) -> Self
+    where
+        F: Fn() + Send + 'static,
+    {
+        let stop = Arc::new(AtomicBool::new(false));
+        let stop_flag = Arc::clone(&stop);
+
+        let handle = thread::spawn(move || {
+            info!("👀 Watching config file: {}", path.display());
+            let mut last_seen = fingerprint(&path);
+
+            while !stop_flag.load(Ordering::Relaxed) {
+                thread::sleep(poll_interval);
+
+                let current = fingerprint(&path);
+                if current == last_seen || current.is_none() {
+                    // Unchanged, or mid-save by an editor that deletes and recreates the file
+                    continue;
+                }
+
+                // Wait for the write to settle before reading a half-written file
+                thread::sleep(poll_interval);
+                let settled = fingerprint(&path);
+                if settled != current {
+                    debug!("📝 Config file still changing, waiting");
+                    continue;
+                }
+
+                last_seen = settled;
+                if stop_flag.load(Ordering::Relaxed) {
+                    break;
+                }
+                info!("📝 Config file changed on disk");
+                on_change();
+            }
+
+            debug!("👀 Stopped watching config file: {}", path.display());
+        });
+
+        Self {
+            stop,
+            handle: Some(handle),
+        }
+    }
+
+    pub fn stop(&mut self) {
+        self.stop.store(true, Ordering::Relaxed);
+        if let Some(handle) = self.handle.take() {
+            let _ = handle.join();
+        }
+    }
+}
+
+impl Drop for ConfigWatcher {
+    fn drop(&mut self) {
+        self.stop();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::mpsc;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_watcher_reports_settled_changes() {
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join("config.yaml");
+        std::fs::write(&path, "version: \"1.0\"\n").unwrap();
+
+        let (tx, rx) = mpsc::channel();
+        let mut watcher = ConfigWatcher::spawn(path.clone(), Duration::from_millis(20), move || {
+            let _ = tx.send(());
+        });
+
+        // No spurious notification without an edit
+        assert!(rx.recv_timeout(Duration::from_millis(100)).is_err());
+
+        std::fs::write(&path, "version: \"1.1\"\nprofiles: []\n").unwrap();
+        assert!(rx.recv_timeout(Duration::from_secs(2)).is_ok());
+
+        watcher.stop();
+        std::fs::write(&path, "version: \"1.1\"\nprofiles: [] # edited\n").unwrap();
+        assert!(rx.recv_timeout(Duration::from_millis(100)).is_err());
+    }
+}
@@ -6,7 +6,7 @@ use std::collections::HashMap;
 use tracing::{info, warn, debug, error};
 
 use crate::modules::{
-    config::{ActionButton, ActionType, Position, ButtonStyle},
+    config::{legacy_action_config, ActionButton, ActionType, Position, ButtonStyle},
     icon::IconService,
 };
 
@@ -483,14 +483,17 @@ impl DragDropService {
         // Create button style based on file type
         let style = self.create_button_style(&file.file_type);
 
+        let action = legacy_action_config(&action_type, config)
+            .map_err(|e| anyhow::anyhow!("Failed to build action for '{}': {}", file.name, e))?;
+
         Ok(ActionButton {
+            id: uuid::Uuid::new_v4().to_string(),
             position,
-            action_type,
             label,
             icon,
-            config,
             style,
-            action: None,
+            hotkey: None,
+            action,
         })
     }
 
@@ -976,10 +979,12 @@ mod tests {
         assert_eq!(result.errors.len(), 0);
         
         let button = &result.generated_buttons[0];
-        assert_eq!(button.action_type, ActionType::Open);
+        assert_eq!(button.action.action_type(), ActionType::Open);
         
-        let target = button.config.get("target").expect("Target not found");
-        let target_str = target.as_str().expect("Target is not a string");
+        let target_str = match &button.action {
+            crate::modules::action::ActionConfig::Open { target, .. } => target.as_str(),
+            other => panic!("Expected Open action, got {:?}", other),
+        };
         
         assert!(Path::new(target_str).is_absolute());
         assert_eq!(target_str, test_file_path);
@@ -0,0 +1,368 @@
+// Screen-edge summon: show the overlay when the pointer rests against a screen edge
+use anyhow::{anyhow, Result};
+use serde::{Deserialize, Serialize};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::thread;
+use std::time::{Duration, Instant};
+use tracing::{debug, info, warn};
+
+use crate::modules::config::EdgeTriggerConfig;
+
+/// How often the monitor layout is re-read while watching the pointer
+const MONITOR_REFRESH_INTERVAL: Duration = Duration::from_secs(1);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ScreenEdge {
+    Top,
+    Bottom,
+    Left,
+    Right,
+}
+
+impl ScreenEdge {
+    pub fn from_name(name: &str) -> Result<Self> {
+        match name.trim().to_lowercase().as_str() {
+            "top" => Ok(ScreenEdge::Top),
+            "bottom" => Ok(ScreenEdge::Bottom),
+            "left" => Ok(ScreenEdge::Left),
+            "right" => Ok(ScreenEdge::Right),
+            _ => Err(anyhow!("Unknown screen edge '{}'; use top, bottom, left or right", name)),
+        }
+    }
+}
+
+/// A monitor's area in physical pixels, in desktop coordinates shared by all monitors
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MonitorRect {
+    pub x: i32,
+    pub y: i32,
+    pub width: u32,
+    pub height: u32,
+}
+
+impl MonitorRect {
+    pub fn right(&self) -> i32 {
+        self.x + self.width as i32
+    }
+
+    pub fn bottom(&self) -> i32 {
+        self.y + self.height as i32
+    }
+
+    pub fn contains(&self, x: i32, y: i32) -> bool {
+        x >= self.x && x < self.right() && y >= self.y && y < self.bottom()
+    }
+}
+
+/// The pointer is against `edge` of the monitor at this index
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct EdgeHit {
+    pub monitor: usize,
+    pub edge: ScreenEdge,
+}
+
+/// Where the pointer is and which monitors it can move across
+pub trait CursorSource: Send {
+    /// Pointer position in desktop coordinates, None if it can't be seen right now
+    fn cursor_position(&mut self) -> Result<Option<(i32, i32)>>;
+    fn monitors(&mut self) -> Result<Vec<MonitorRect>>;
+}
+
+/// The configured edge the pointer is resting against, if any.
+///
+/// Only outer edges of the desktop count: where two monitors meet, the pointer just moves
+/// on to the next monitor, so the shared border is not an edge you can push against.
+pub fn edge_at(x: i32, y: i32, monitors: &[MonitorRect], edges: &[ScreenEdge], margin_px: u32) -> Option<EdgeHit> {
+    let margin = margin_px.max(1) as i32;
+    let (index, monitor) = monitors.iter().enumerate().find(|(_, monitor)| monitor.contains(x, y))?;
+    let on_desktop = |x: i32, y: i32| monitors.iter().any(|monitor| monitor.contains(x, y));
+
+    edges.iter().find_map(|&edge| {
+        let (distance, beyond) = match edge {
+            ScreenEdge::Top => (y - monitor.y, (x, monitor.y - 1)),
+            ScreenEdge::Bottom => (monitor.bottom() - 1 - y, (x, monitor.bottom())),
+            ScreenEdge::Left => (x - monitor.x, (monitor.x - 1, y)),
+            ScreenEdge::Right => (monitor.right() - 1 - x, (monitor.right(), y)),
+        };
+        (distance < margin && !on_desktop(beyond.0, beyond.1)).then_some(EdgeHit { monitor: index, edge })
+    })
+}
+
+/// Fires once the pointer has stayed on the same edge for the dwell time.
+///
+/// It fires once per visit: the pointer has to leave the edge before it can fire again.
+/// Time is passed in by the caller.
+#[derive(Debug, Default)]
+pub struct EdgeDwell {
+    current: Option<(EdgeHit, Instant)>, // Edge under the pointer and when it got there
+    fired: bool,
+}
+
+impl EdgeDwell {
+    pub fn update(&mut self, hit: Option<EdgeHit>, dwell: Duration, now: Instant) -> Option<EdgeHit> {
+        let hit = match hit {
+            Some(hit) => hit,
+            None => {
+                self.current = None;
+                self.fired = false;
+                return None;
+            }
+        };
+
+        let since = match self.current {
+            Some((current, since)) if current == hit => since,
+            _ => {
+                self.current = Some((hit, now));
+                self.fired = false;
+                now
+            }
+        };
+        if self.fired || now.duration_since(since) < dwell {
+            return None;
+        }
+        self.fired = true;
+        Some(hit)
+    }
+}
+
+/// Parse the configured edge names, leaving out unknown ones
+pub fn configured_edges(config: &EdgeTriggerConfig) -> Vec<ScreenEdge> {
+    let mut edges = Vec::new();
+    for name in &config.edges {
+        match ScreenEdge::from_name(name) {
+            Ok(edge) if !edges.contains(&edge) => edges.push(edge),
+            Ok(_) => {}
+            Err(e) => warn!("Ignoring edge trigger edge: {}", e),
+        }
+    }
+    edges
+}
+
+/// Polls a `CursorSource` and reports when the pointer dwells on a configured edge
+pub struct EdgeTriggerWatcher {
+    stop: Arc<AtomicBool>,
+    handle: Option<thread::JoinHandle<()>>,
+}
+
+impl EdgeTriggerWatcher {
+    /// Start watching, or None when the edge trigger is disabled or has no usable edges
+    pub fn spawn<F>(mut source: Box<dyn CursorSource>, config: &EdgeTriggerConfig, poll_interval: Duration, mut on_trigger: F) -> Option<Self>
+    where
+        F: FnMut(EdgeHit) + Send + 'static,
+    {
+        if !config.enabled {
+            return None;
+        }
+        let edges = configured_edges(config);
+        if edges.is_empty() {
+            warn!("Edge trigger is enabled but has no valid edges");
+            return None;
+        }
+
+        let dwell = Duration::from_millis(config.dwell_ms as u64);
+        let margin_px = config.margin_px;
+        let stop = Arc::new(AtomicBool::new(false));
+        let stop_flag = Arc::clone(&stop);
+
+        let handle = thread::spawn(move || {
+            info!("🖱️ Watching screen edges {:?} (dwell {:?}, margin {}px)", edges, dwell, margin_px);
+            let mut monitors: Vec<MonitorRect> = Vec::new();
+            let mut monitors_read_at: Option<Instant> = None;
+            let mut dwell_state = EdgeDwell::default();
+
+            while !stop_flag.load(Ordering::Relaxed) {
+                let now = Instant::now();
+                match source.cursor_position() {
+                    Ok(Some((x, y))) => {
+                        // Re-read the layout now and then, and at once if the pointer left every known monitor
+                        let stale = monitors_read_at.is_none_or(|at| now.duration_since(at) >= MONITOR_REFRESH_INTERVAL);
+                        if stale || !monitors.iter().any(|monitor| monitor.contains(x, y)) {
+                            match source.monitors() {
+                                Ok(layout) => monitors = layout,
+                                Err(e) => debug!("Failed to query monitors: {:#}", e),
+                            }
+                            monitors_read_at = Some(now);
+                        }
+
+                        let hit = edge_at(x, y, &monitors, &edges, margin_px);
+                        if let Some(hit) = dwell_state.update(hit, dwell, now) {
+                            debug!("🖱️ Pointer rested on the {:?} edge of monitor {}", hit.edge, hit.monitor);
+                            on_trigger(hit);
+                        }
+                    }
+                    Ok(None) => {
+                        dwell_state.update(None, dwell, now);
+                    }
+                    Err(e) => debug!("Failed to query cursor position: {:#}", e),
+                }
+
+                thread::sleep(poll_interval);
+            }
+
+            debug!("🖱️ Stopped watching screen edges");
+        });
+
+        Some(Self {
+            stop,
+            handle: Some(handle),
+        })
+    }
+
+    pub fn stop(&mut self) {
+        self.stop.store(true, Ordering::Relaxed);
+        if let Some(handle) = self.handle.take() {
+            let _ = handle.join();
+        }
+    }
+}
+
+impl Drop for EdgeTriggerWatcher {
+    fn drop(&mut self) {
+        self.stop();
+    }
+}
+
+/// Scripted pointer and monitor layout for tests
+#[cfg(test)]
+#[derive(Clone, Default)]
+pub struct FakeCursorSource {
+    position: Arc<std::sync::Mutex<Option<(i32, i32)>>>,
+    monitors: Arc<std::sync::Mutex<Vec<MonitorRect>>>,
+}
+
+#[cfg(test)]
+impl FakeCursorSource {
+    pub fn set_position(&self, position: Option<(i32, i32)>) {
+        *self.position.lock().unwrap() = position;
+    }
+
+    pub fn set_monitors(&self, monitors: Vec<MonitorRect>) {
+        *self.monitors.lock().unwrap() = monitors;
+    }
+}
+
+#[cfg(test)]
+impl CursorSource for FakeCursorSource {
+    fn cursor_position(&mut self) -> Result<Option<(i32, i32)>> {
+        Ok(*self.position.lock().unwrap())
+    }
+
+    fn monitors(&mut self) -> Result<Vec<MonitorRect>> {
+        Ok(self.monitors.lock().unwrap().clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::mpsc;
+
+    const MS: Duration = Duration::from_millis(1);
+
+    fn monitor(x: i32, y: i32, width: u32, height: u32) -> MonitorRect {
+        MonitorRect { x, y, width, height }
+    }
+
+    // A 1920x1080 primary with a taller 1440x2560 monitor to its left, sitting higher up
+    fn two_monitors() -> Vec<MonitorRect> {
+        vec![monitor(0, 0, 1920, 1080), monitor(-1440, -800, 1440, 2560)]
+    }
+
+    fn hit(monitor: usize, edge: ScreenEdge) -> Option<EdgeHit> {
+        Some(EdgeHit { monitor, edge })
+    }
+
+    #[test]
+    fn test_edge_at_single_monitor() {
+        let monitors = vec![monitor(0, 0, 1920, 1080)];
+        let all = [ScreenEdge::Top, ScreenEdge::Bottom, ScreenEdge::Left, ScreenEdge::Right];
+
+        assert_eq!(edge_at(900, 0, &monitors, &all, 5), hit(0, ScreenEdge::Top));
+        assert_eq!(edge_at(900, 4, &monitors, &all, 5), hit(0, ScreenEdge::Top));
+        assert_eq!(edge_at(900, 5, &monitors, &all, 5), None);
+        assert_eq!(edge_at(900, 1079, &monitors, &all, 5), hit(0, ScreenEdge::Bottom));
+        assert_eq!(edge_at(1919, 500, &monitors, &all, 5), hit(0, ScreenEdge::Right));
+        // Only configured edges count, and a zero margin still means touching the edge
+        assert_eq!(edge_at(0, 500, &monitors, &[ScreenEdge::Top], 5), None);
+        assert_eq!(edge_at(900, 0, &monitors, &[ScreenEdge::Top], 0), hit(0, ScreenEdge::Top));
+        assert_eq!(edge_at(900, 1, &monitors, &[ScreenEdge::Top], 0), None);
+        // Off every monitor
+        assert_eq!(edge_at(-10, 500, &monitors, &all, 5), None);
+    }
+
+    #[test]
+    fn test_edge_at_skips_shared_monitor_borders() {
+        let monitors = two_monitors();
+        let all = [ScreenEdge::Top, ScreenEdge::Bottom, ScreenEdge::Left, ScreenEdge::Right];
+
+        // The primary's left border runs into the other monitor: not an edge
+        assert_eq!(edge_at(0, 500, &monitors, &all, 5), None);
+        assert_eq!(edge_at(-1, 500, &monitors, &[ScreenEdge::Right], 5), None);
+        // The primary's top is an outer edge even though the left monitor reaches higher
+        assert_eq!(edge_at(500, 0, &monitors, &all, 5), hit(0, ScreenEdge::Top));
+        assert_eq!(edge_at(-700, -800, &monitors, &all, 5), hit(1, ScreenEdge::Top));
+        assert_eq!(edge_at(-1440, 100, &monitors, &all, 5), hit(1, ScreenEdge::Left));
+        // Above the primary, the left monitor's right border is an outer edge
+        assert_eq!(edge_at(-1, -100, &monitors, &[ScreenEdge::Right], 5), hit(1, ScreenEdge::Right));
+    }
+
+    #[test]
+    fn test_dwell_fires_once_per_visit() {
+        let start = Instant::now();
+        let dwell = 300 * MS;
+        let top = EdgeHit { monitor: 0, edge: ScreenEdge::Top };
+        let left = EdgeHit { monitor: 1, edge: ScreenEdge::Left };
+        let mut state = EdgeDwell::default();
+
+        assert_eq!(state.update(Some(top), dwell, start), None);
+        assert_eq!(state.update(Some(top), dwell, start + 299 * MS), None);
+        assert_eq!(state.update(Some(top), dwell, start + 300 * MS), Some(top));
+        assert_eq!(state.update(Some(top), dwell, start + 900 * MS), None);
+
+        // Leaving the edge re-arms it
+        state.update(None, dwell, start + 1000 * MS);
+        assert_eq!(state.update(Some(top), dwell, start + 1100 * MS), None);
+        assert_eq!(state.update(Some(top), dwell, start + 1400 * MS), Some(top));
+
+        // Sliding onto another edge restarts the dwell
+        state.update(None, dwell, start);
+        state.update(Some(top), dwell, start);
+        assert_eq!(state.update(Some(left), dwell, start + 200 * MS), None);
+        assert_eq!(state.update(Some(left), dwell, start + 400 * MS), None);
+        assert_eq!(state.update(Some(left), dwell, start + 500 * MS), Some(left));
+    }
+
+    #[test]
+    fn test_watcher_triggers_after_dwell() {
+        let source = FakeCursorSource::default();
+        source.set_monitors(two_monitors());
+        source.set_position(Some((500, 500)));
+
+        let config = EdgeTriggerConfig {
+            enabled: true,
+            edges: vec!["top".to_string(), "sideways".to_string()],
+            dwell_ms: 50,
+            margin_px: 5,
+        };
+        let (tx, rx) = mpsc::channel();
+        let mut watcher = EdgeTriggerWatcher::spawn(Box::new(source.clone()), &config, 5 * MS, move |hit| {
+            let _ = tx.send(hit);
+        })
+        .unwrap();
+        assert!(rx.recv_timeout(100 * MS).is_err());
+
+        source.set_position(Some((-700, -799)));
+        assert_eq!(rx.recv_timeout(Duration::from_secs(2)).ok(), hit(1, ScreenEdge::Top));
+        // Staying on the edge does not fire again
+        assert!(rx.recv_timeout(150 * MS).is_err());
+
+        watcher.stop();
+
+        let disabled = EdgeTriggerConfig { enabled: false, ..config.clone() };
+        assert!(EdgeTriggerWatcher::spawn(Box::new(source.clone()), &disabled, 5 * MS, |_| {}).is_none());
+        let no_edges = EdgeTriggerConfig { edges: vec!["sideways".to_string()], ..config };
+        assert!(EdgeTriggerWatcher::spawn(Box::new(source), &no_edges, 5 * MS, |_| {}).is_none());
+    }
+}
@@ -0,0 +1,404 @@
+// Foreground window tracking for automatic profile switching
+use anyhow::Result;
+use regex::{Regex, RegexBuilder};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::thread;
+use std::time::Duration;
+use tracing::{debug, info, warn};
+
+use crate::modules::config::{ProfileMatch, QDeckConfig};
+
+/// The application window that currently has keyboard focus
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ForegroundWindow {
+    pub pid: Option<u32>,
+    pub exe: Option<String>,            // Executable file name, e.g. "code" or "Code.exe"
+    pub window_class: Option<String>,   // X11 WM_CLASS class / Win32 class name
+    pub window_instance: Option<String>, // X11 WM_CLASS instance name
+    pub title: String,
+}
+
+/// Platform hook that reports the focused window
+pub trait ForegroundWindowSource: Send {
+    fn foreground_window(&mut self) -> Result<Option<ForegroundWindow>>;
+}
+
+/// Foreground window source for this platform, if one is available
+pub fn platform_source() -> Option<Box<dyn ForegroundWindowSource>> {
+    #[cfg(target_os = "linux")]
+    {
+        match x11_source::X11ForegroundSource::new() {
+            Ok(source) => Some(Box::new(source)),
+            Err(e) => {
+                warn!("⚠️ Automatic profile switching unavailable: {:#}", e);
+                None
+            }
+        }
+    }
+
+    #[cfg(not(target_os = "linux"))]
+    {
+        None
+    }
+}
+
+fn name_matches(expected: &str, actual: Option<&str>) -> bool {
+    let normalize = |name: &str| {
+        let name = name.trim().to_lowercase();
+        name.strip_suffix(".exe").map(str::to_string).unwrap_or(name)
+    };
+    actual.map(|actual| normalize(actual) == normalize(expected)).unwrap_or(false)
+}
+
+/// Whether `window` satisfies every field set on `rule`, given `rule.title` compiled as `title`.
+/// A rule with no fields never matches, nor does one whose title pattern failed to compile.
+pub fn rule_matches(rule: &ProfileMatch, title: Option<&Regex>, window: &ForegroundWindow) -> bool {
+    if rule.exe.is_none() && rule.window_class.is_none() && rule.title.is_none() {
+        return false;
+    }
+
+    if let Some(exe) = &rule.exe {
+        if !name_matches(exe, window.exe.as_deref()) {
+            return false;
+        }
+    }
+
+    if let Some(class) = &rule.window_class {
+        if !name_matches(class, window.window_class.as_deref()) && !name_matches(class, window.window_instance.as_deref()) {
+            return false;
+        }
+    }
+
+    if rule.title.is_some() {
+        match title {
+            Some(regex) if regex.is_match(&window.title) => {}
+            _ => return false,
+        }
+    }
+
+    true
+}
+
+/// Case-insensitive title regex of every profile `match` rule, keyed by profile name.
+/// Invalid patterns are reported here once and left out.
+pub fn compile_title_patterns(config: &QDeckConfig) -> HashMap<String, Regex> {
+    let mut patterns = HashMap::new();
+    for profile in &config.profiles {
+        let pattern = match profile.match_rule.as_ref().and_then(|rule| rule.title.as_ref()) {
+            Some(pattern) => pattern,
+            None => continue,
+        };
+        match RegexBuilder::new(pattern).case_insensitive(true).build() {
+            Ok(regex) => {
+                patterns.insert(profile.name.clone(), regex);
+            }
+            Err(e) => warn!("⚠️ Invalid title pattern '{}' in profile '{}': {}", pattern, profile.name, e),
+        }
+    }
+    patterns
+}
+
+/// Index of the first profile whose `match` rule accepts `window`
+pub fn find_matching_profile(
+    config: &QDeckConfig,
+    title_patterns: &HashMap<String, Regex>,
+    window: &ForegroundWindow,
+) -> Option<usize> {
+    config.profiles.iter().position(|profile| {
+        profile.match_rule.as_ref()
+            .map(|rule| rule_matches(rule, title_patterns.get(&profile.name), window))
+            .unwrap_or(false)
+    })
+}
+
+/// Decides which focus changes should switch profiles. A switch happens only when the
+/// matching profile changes, so a manual switch sticks while the same app keeps focus.
+#[derive(Debug, Default)]
+pub struct ProfileAutoSwitcher {
+    last_match: Option<String>,
+    title_patterns: Option<HashMap<String, Regex>>, // Compiled on first use after each config load
+}
+
+impl ProfileAutoSwitcher {
+    /// Drop the compiled title patterns so the next focus change compiles them from the new config
+    pub fn config_reloaded(&mut self) {
+        self.title_patterns = None;
+    }
+
+    pub fn on_focus_changed(&mut self, config: &QDeckConfig, window: &ForegroundWindow) -> Option<usize> {
+        let title_patterns = self.title_patterns.get_or_insert_with(|| compile_title_patterns(config));
+        let matched = find_matching_profile(config, title_patterns, window);
+        let matched_name = matched.map(|index| config.profiles[index].name.clone());
+        if matched_name == self.last_match {
+            return None;
+        }
+
+        self.last_match = matched_name;
+        matched
+    }
+}
+
+/// Polls a `ForegroundWindowSource` and reports focus changes to other applications
+pub struct ForegroundWatcher {
+    stop: Arc<AtomicBool>,
+    handle: Option<thread::JoinHandle<()>>,
+}
+
+impl ForegroundWatcher {
+    pub fn spawn<F>(mut source: Box<dyn ForegroundWindowSource>, poll_interval: Duration, mut on_change: F) -> Self
+    where
+        F: FnMut(&ForegroundWindow) + Send + 'static,
+    {
+        let stop = Arc::new(AtomicBool::new(false));
+        let stop_flag = Arc::clone(&stop);
+        let own_pid = std::process::id();
+
+        let handle = thread::spawn(move || {
+            info!("👀 Watching the foreground window");
+            let mut last_seen: Option<ForegroundWindow> = None;
+
+            while !stop_flag.load(Ordering::Relaxed) {
+                match source.foreground_window() {
+                    // Focusing the overlay itself must not switch profiles
+                    Ok(Some(window)) if window.pid != Some(own_pid) => {
+                        if last_seen.as_ref() != Some(&window) {
+                            debug!("🪟 Foreground window: {:?} ({:?}) '{}'", window.exe, window.window_class, window.title);
+                            on_change(&window);
+                            last_seen = Some(window);
+                        }
+                    }
+                    Ok(_) => {}
+                    Err(e) => debug!("Failed to query foreground window: {:#}", e),
+                }
+
+                thread::sleep(poll_interval);
+            }
+
+            debug!("👀 Stopped watching the foreground window");
+        });
+
+        Self {
+            stop,
+            handle: Some(handle),
+        }
+    }
+
+    pub fn stop(&mut self) {
+        self.stop.store(true, Ordering::Relaxed);
+        if let Some(handle) = self.handle.take() {
+            let _ = handle.join();
+        }
+    }
+}
+
+impl Drop for ForegroundWatcher {
+    fn drop(&mut self) {
+        self.stop();
+    }
+}
+
+#[cfg(target_os = "linux")]
+mod x11_source {
+    use super::{ForegroundWindow, ForegroundWindowSource};
+    use crate::modules::x11::XConnection;
+    use anyhow::Result;
+    use std::os::raw::c_ulong;
+    use std::ptr;
+    use x11_dl::xlib;
+
+    /// Reads `_NET_ACTIVE_WINDOW` from an EWMH-compliant window manager
+    pub struct X11ForegroundSource {
+        connection: XConnection,
+        net_active_window: c_ulong,
+        net_wm_pid: c_ulong,
+        net_wm_name: c_ulong,
+    }
+
+    impl X11ForegroundSource {
+        pub fn new() -> Result<Self> {
+            let connection = XConnection::open()?;
+            Ok(Self {
+                net_active_window: connection.intern_atom("_NET_ACTIVE_WINDOW"),
+                net_wm_pid: connection.intern_atom("_NET_WM_PID"),
+                net_wm_name: connection.intern_atom("_NET_WM_NAME"),
+                connection,
+            })
+        }
+
+        fn describe(&self, window: c_ulong) -> ForegroundWindow {
+            let connection = &self.connection;
+            let pid = connection.property_longs(window, self.net_wm_pid).first().map(|pid| *pid as u32);
+
+            let mut hint = xlib::XClassHint {
+                res_name: ptr::null_mut(),
+                res_class: ptr::null_mut(),
+            };
+            let (window_instance, window_class) = if unsafe { (connection.xlib.XGetClassHint)(connection.display, window, &mut hint) } != 0 {
+                unsafe { (connection.take_string(hint.res_name), connection.take_string(hint.res_class)) }
+            } else {
+                (None, None)
+            };
+
+            let title = connection.property_text(window, self.net_wm_name)
+                .or_else(|| connection.property_text(window, xlib::XA_WM_NAME))
+                .unwrap_or_default();
+
+            ForegroundWindow {
+                pid,
+                exe: pid.and_then(process_exe_name),
+                window_class,
+                window_instance,
+                title,
+            }
+        }
+    }
+
+    impl ForegroundWindowSource for X11ForegroundSource {
+        fn foreground_window(&mut self) -> Result<Option<ForegroundWindow>> {
+            let root = self.connection.root_window();
+            let active = match self.connection.property_longs(root, self.net_active_window).first() {
+                Some(&window) if window != 0 => window,
+                _ => return Ok(None),
+            };
+
+            // The window can close while we query it; that surfaces as a BadWindow error
+            self.connection.checked(|_| self.describe(active))
+                .map(Some)
+                .map_err(|code| anyhow::anyhow!("X error {} while reading window 0x{:x}", code, active))
+        }
+    }
+
+    fn process_exe_name(pid: u32) -> Option<String> {
+        std::fs::read_link(format!("/proc/{}/exe", pid)).ok()
+            .and_then(|path| path.file_name().map(|name| name.to_string_lossy().to_string()))
+            .or_else(|| std::fs::read_to_string(format!("/proc/{}/comm", pid)).ok().map(|comm| comm.trim().to_string()))
+    }
+}
+
+/// Scripted foreground window for tests
+#[cfg(test)]
+#[derive(Clone, Default)]
+pub struct FakeForegroundSource {
+    window: Arc<std::sync::Mutex<Option<ForegroundWindow>>>,
+}
+
+#[cfg(test)]
+impl FakeForegroundSource {
+    pub fn set(&self, window: Option<ForegroundWindow>) {
+        *self.window.lock().unwrap() = window;
+    }
+}
+
+#[cfg(test)]
+impl ForegroundWindowSource for FakeForegroundSource {
+    fn foreground_window(&mut self) -> Result<Option<ForegroundWindow>> {
+        Ok(self.window.lock().unwrap().clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::modules::config::Profile;
+    use std::sync::mpsc;
+
+    fn window(exe: &str, class: &str, title: &str) -> ForegroundWindow {
+        ForegroundWindow {
+            pid: Some(1),
+            exe: Some(exe.to_string()),
+            window_class: Some(class.to_string()),
+            window_instance: Some(class.to_lowercase()),
+            title: title.to_string(),
+        }
+    }
+
+    fn config_with_rules() -> QDeckConfig {
+        let mut config = QDeckConfig::default();
+        config.profiles.push(Profile {
+            name: "Code".to_string(),
+            match_rule: Some(ProfileMatch { exe: Some("code.exe".to_string()), ..Default::default() }),
+            ..Profile::default()
+        });
+        config.profiles.push(Profile {
+            name: "Video".to_string(),
+            match_rule: Some(ProfileMatch {
+                window_class: Some("firefox".to_string()),
+                title: Some(r"youtube|twitch".to_string()),
+                ..Default::default()
+            }),
+            ..Profile::default()
+        });
+        config
+    }
+
+    #[test]
+    fn test_rule_matching() {
+        let config = config_with_rules();
+        let patterns = compile_title_patterns(&config);
+        assert_eq!(find_matching_profile(&config, &patterns, &window("code", "Code", "main.rs")), Some(1));
+        assert_eq!(find_matching_profile(&config, &patterns, &window("Code.exe", "Chrome_WidgetWin_1", "")), Some(1));
+        assert_eq!(find_matching_profile(&config, &patterns, &window("firefox", "Firefox", "Cats - YouTube")), Some(2));
+        // Every field set on the rule has to match
+        assert_eq!(find_matching_profile(&config, &patterns, &window("firefox", "Firefox", "Docs")), None);
+        assert!(!rule_matches(&ProfileMatch::default(), None, &window("code", "Code", "")));
+    }
+
+    #[test]
+    fn test_invalid_title_pattern_never_matches() {
+        let mut config = config_with_rules();
+        config.profiles[2].match_rule.as_mut().unwrap().title = Some("(youtube".to_string());
+        let patterns = compile_title_patterns(&config);
+
+        assert!(!patterns.contains_key("Video"));
+        assert_eq!(find_matching_profile(&config, &patterns, &window("firefox", "Firefox", "(youtube")), None);
+    }
+
+    #[test]
+    fn test_auto_switcher_recompiles_title_patterns_after_reload() {
+        let mut config = config_with_rules();
+        let mut switcher = ProfileAutoSwitcher::default();
+        assert_eq!(switcher.on_focus_changed(&config, &window("firefox", "Firefox", "Twitch")), Some(2));
+
+        config.profiles[2].match_rule.as_mut().unwrap().title = Some("docs".to_string());
+        // Patterns stay compiled from the old config until it is reported as reloaded
+        assert_eq!(switcher.on_focus_changed(&config, &window("firefox", "Firefox", "Docs")), None);
+        switcher.config_reloaded();
+        assert_eq!(switcher.on_focus_changed(&config, &window("firefox", "Firefox", "Docs")), Some(2));
+    }
+
+    #[test]
+    fn test_auto_switcher_only_fires_on_match_changes() {
+        let config = config_with_rules();
+        let mut switcher = ProfileAutoSwitcher::default();
+
+        assert_eq!(switcher.on_focus_changed(&config, &window("code", "Code", "a.rs")), Some(1));
+        // Same profile keeps matching: leave a manual switch alone
+        assert_eq!(switcher.on_focus_changed(&config, &window("code", "Code", "b.rs")), None);
+        assert_eq!(switcher.on_focus_changed(&config, &window("nautilus", "Nautilus", "Home")), None);
+        assert_eq!(switcher.on_focus_changed(&config, &window("code", "Code", "a.rs")), Some(1));
+    }
+
+    #[test]
+    fn test_watcher_reports_focus_changes() {
+        let source = FakeForegroundSource::default();
+        source.set(Some(window("code", "Code", "a.rs")));
+
+        let (tx, rx) = mpsc::channel();
+        let mut watcher = ForegroundWatcher::spawn(Box::new(source.clone()), Duration::from_millis(10), move |w| {
+            let _ = tx.send(w.title.clone());
+        });
+        assert_eq!(rx.recv_timeout(Duration::from_secs(2)).unwrap(), "a.rs");
+
+        // Our own windows are ignored
+        source.set(Some(ForegroundWindow { pid: Some(std::process::id()), ..window("q-deck", "Q-Deck", "overlay") }));
+        assert!(rx.recv_timeout(Duration::from_millis(100)).is_err());
+
+        source.set(Some(window("firefox", "Firefox", "Docs")));
+        assert_eq!(rx.recv_timeout(Duration::from_secs(2)).unwrap(), "Docs");
+
+        watcher.stop();
+    }
+}
@@ -1,16 +1,28 @@
 use anyhow::Result;
 use std::collections::HashMap;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::mpsc;
 use std::sync::{Arc, Mutex};
 use std::thread;
+use std::time::{Duration, Instant};
 use tracing::{debug, error, info, warn};
 
-#[cfg(windows)]
-use winapi::um::{
-    winuser::{
-        RegisterHotKey, UnregisterHotKey, GetMessageW, TranslateMessage, DispatchMessageW,
-        MSG, WM_HOTKEY, MOD_ALT, MOD_CONTROL, MOD_SHIFT, MOD_WIN, VK_ESCAPE, SetTimer,
-    },
-};
+use crate::modules::config::{Page, QDeckConfig, SummonMode};
+use crate::modules::hotkey_backend::{self, HotkeyBackend, HotkeyBackendFactory, HotkeyEvent};
+use crate::modules::hotkey_gesture::{DoubleTapDetector, HoldTracker, DEFAULT_DOUBLE_TAP_INTERVAL_MS, DEFAULT_HOLD_THRESHOLD_MS};
+use crate::modules::hotkey_sequence::{SequenceStep, SequenceTracker, DEFAULT_SEQUENCE_TIMEOUT_MS};
+use crate::modules::keys::{self, Key, KeyChord, ModifierKey, Modifiers};
+
+// How long the hotkey thread waits for OS events before handling queued commands
+const POLL_INTERVAL: Duration = Duration::from_millis(50);
+
+// How long `start_message_loop` waits for the hotkey thread to come up
+const STARTUP_TIMEOUT: Duration = Duration::from_secs(2);
+
+// Backend IDs for sequence leaders and for keys grabbed while a sequence is armed.
+// They sit above the IDs handed out to hotkeys and below Windows' 0xBFFF limit.
+const LEADER_ID_BASE: u32 = 0xA000;
+const FOLLOW_UP_ID_BASE: u32 = 0xB000;
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
 pub struct HotkeyConfig {
@@ -23,30 +35,237 @@ pub struct HotkeyConfig {
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub struct ParsedHotkey {
     pub id: u32,
-    pub modifiers: u32,
-    pub vk_code: u32,
+    pub hotkey: String, // As written in the config, e.g. "Ctrl+Alt+F1" or "Ctrl+Space, G, P"
+    #[serde(default)]
+    pub display: String, // Canonical form from `to_hotkey_string`, e.g. "Ctrl+=" for "ctrl++"
+    pub modifiers: Modifiers,
+    pub key: Key,
+    #[serde(default)]
+    pub sequence: Vec<KeyChord>, // Keys typed after the leader chord; empty for a plain hotkey
+    pub action: String,
+    #[serde(default)]
+    pub release_action: Option<String>, // Fired when the key is let go after being held past the hold threshold
+}
+
+impl ParsedHotkey {
+    /// The chord registered with the OS (the leader of a sequence)
+    pub fn chord(&self) -> KeyChord {
+        KeyChord {
+            modifiers: self.modifiers,
+            key: self.key,
+        }
+    }
+
+    pub fn is_sequence(&self) -> bool {
+        !self.sequence.is_empty()
+    }
+
+    /// Canonical spelling, e.g. "ctrl+alt+f1" becomes "Ctrl+Alt+F1"; parses back to the same keys
+    pub fn to_hotkey_string(&self) -> String {
+        keys::format_chord_sequence(&self.all_keys())
+    }
+
+    // Leader followed by the sequence keys
+    fn all_keys(&self) -> Vec<KeyChord> {
+        std::iter::once(self.chord()).chain(self.sequence.iter().copied()).collect()
+    }
+}
+
+/// A modifier that fires its action when tapped twice on its own, e.g. Ctrl Ctrl
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct DoubleTapHotkey {
+    pub id: u32,
+    pub modifier: ModifierKey,
+    pub action: String,
+}
+
+/// A hotkey declared in the config and the action it dispatches
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct HotkeyBinding {
+    pub hotkey: String,
+    pub action: String, // e.g. "toggle_overlay", "run_button:<id>", "switch_page:<profile>/<page>"
+    #[serde(default)]
+    pub release_action: Option<String>, // Hold mode: fired when the key is let go after a hold
+    /// Where it is declared, e.g. `profiles[0].pages[1].hotkey`
+    pub source: String,
+}
+
+/// A binding that can't be registered because an earlier one already claims its keys
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct HotkeyConflict {
+    pub hotkey: String,
+    pub source: String,
+    pub conflicts_with: String, // Source of the binding that wins
+    pub message: String,
+}
+
+/// A binding the OS refused, usually because another application owns the keys
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct UnavailableHotkey {
+    pub hotkey: String,
+    pub action: String,
+    pub source: String,
+    pub reason: String,
+}
+
+/// A binding that is registered and live
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct EffectiveHotkey {
+    pub id: u32,
+    pub hotkey: String,
     pub action: String,
+    pub source: String, // `ui.summon.fallback_hotkeys[N]` when a fallback stands in for the summon key
+}
+
+/// Outcome of registering the hotkeys of a config
+#[derive(Debug, Clone, Default, serde::Serialize, serde::Deserialize)]
+pub struct HotkeyConflictReport {
+    /// Bindings skipped because an earlier binding in the config claims their keys
+    pub duplicates: Vec<HotkeyConflict>,
+    /// Bindings the OS refused
+    pub unavailable: Vec<UnavailableHotkey>,
+    pub effective: Vec<EffectiveHotkey>,
+}
+
+impl HotkeyConflictReport {
+    pub fn has_conflicts(&self) -> bool {
+        !self.duplicates.is_empty() || !self.unavailable.is_empty()
+    }
+}
+
+/// Every hotkey in the config in registration order: summon, profiles, pages, then buttons
+pub fn config_hotkey_bindings(config: &QDeckConfig) -> Vec<HotkeyBinding> {
+    let mut bindings: Vec<HotkeyBinding> = config.ui.summon.hotkeys.iter().enumerate()
+        .map(|(index, hotkey)| summon_binding(config, hotkey, format!("ui.summon.hotkeys[{}]", index)))
+        .collect();
+    let mut bind = |hotkey: &Option<String>, action: String, source: String| {
+        if let Some(hotkey) = hotkey.as_ref().filter(|h| !h.trim().is_empty()) {
+            bindings.push(HotkeyBinding { hotkey: hotkey.clone(), action, release_action: None, source });
+        }
+    };
+
+    for (profile_index, profile) in config.profiles.iter().enumerate() {
+        bind(&profile.hotkey, format!("switch_profile:{}", profile.name), format!("profiles[{}].hotkey", profile_index));
+    }
+    for (profile_index, profile) in config.profiles.iter().enumerate() {
+        for (page_index, page) in profile.pages.iter().enumerate() {
+            let page_path = format!("profiles[{}].pages[{}]", profile_index, page_index);
+            bind(&page.hotkey, format!("switch_page:{}/{}", profile.name, page.name), format!("{}.hotkey", page_path));
+        }
+    }
+    for (profile_index, profile) in config.profiles.iter().enumerate() {
+        for (page_index, page) in profile.pages.iter().enumerate() {
+            collect_button_bindings(page, &format!("profiles[{}].pages[{}]", profile_index, page_index), &mut bind);
+        }
+    }
+
+    bindings
+}
+
+// Summon hotkeys toggle the overlay; in hold mode, letting go after a hold hides it again
+fn summon_binding(config: &QDeckConfig, hotkey: &str, source: String) -> HotkeyBinding {
+    HotkeyBinding {
+        hotkey: hotkey.to_string(),
+        action: "toggle_overlay".to_string(),
+        release_action: match config.ui.summon.mode {
+            SummonMode::Toggle => None,
+            SummonMode::Hold => Some("release_overlay".to_string()),
+        },
+        source,
+    }
+}
+
+// Button hotkeys of a page and of the inline folder pages below it
+fn collect_button_bindings(page: &Page, page_path: &str, bind: &mut impl FnMut(&Option<String>, String, String)) {
+    for (button_index, button) in page.buttons.iter().enumerate() {
+        let button_path = format!("{}.buttons[{}]", page_path, button_index);
+        bind(&button.hotkey, format!("run_button:{}", button.id), format!("{}.hotkey", button_path));
+        if let Some(folder_page) = button.folder().and_then(|folder| folder.page.as_deref()) {
+            collect_button_bindings(folder_page, &format!("{}.action.page", button_path), bind);
+        }
+    }
+}
+
+/// Bindings that clash with an earlier one: the same keys, or a key sequence that
+/// starts with another binding's keys. The first declaration wins.
+pub fn find_hotkey_conflicts(bindings: &[HotkeyBinding]) -> Vec<HotkeyConflict> {
+    let mut winners: Vec<(&HotkeyBinding, Vec<KeyChord>)> = Vec::new();
+    let mut conflicts = Vec::new();
+
+    for binding in bindings {
+        // Unparsable hotkeys are reported by validation
+        let keys = match keys::parse_chord_sequence(&binding.hotkey) {
+            Ok(keys) => keys,
+            Err(_) => continue,
+        };
+
+        match winners.iter().find(|(_, other)| other.starts_with(&keys) || keys.starts_with(other)) {
+            Some((winner, other)) => {
+                let message = if *other == keys {
+                    format!("Hotkey '{}' is already bound at {} ({})", binding.hotkey, winner.source, winner.action)
+                } else {
+                    format!("Hotkey '{}' overlaps '{}' at {} ({})", binding.hotkey, winner.hotkey, winner.source, winner.action)
+                };
+                conflicts.push(HotkeyConflict {
+                    hotkey: binding.hotkey.clone(),
+                    source: binding.source.clone(),
+                    conflicts_with: winner.source.clone(),
+                    message,
+                });
+            }
+            None => winners.push((binding, keys)),
+        }
+    }
+
+    conflicts
 }
 
+/// Called on the hotkey thread with the action of the pressed (or, for holds, released) hotkey.
+/// It must not lock the `HotkeyService`: its callers wait on that thread.
 pub type HotkeyCallback = Arc<dyn Fn(&str) + Send + Sync>;
 
+// Requests executed on the hotkey thread, which owns the OS backend
+enum HotkeyCommand {
+    Register(ParsedHotkey, mpsc::Sender<Result<()>>),
+    Unregister(u32, mpsc::Sender<Result<()>>),
+    Replace(ParsedHotkey, mpsc::Sender<Result<()>>),
+    WatchDoubleTap(DoubleTapHotkey, mpsc::Sender<Result<()>>),
+}
+
 pub struct HotkeyService {
-    registered_hotkeys: Arc<Mutex<HashMap<u32, ParsedHotkey>>>,
+    registered_hotkeys: Arc<Mutex<HashMap<u32, ParsedHotkey>>>, // Live registrations once the loop runs
+    double_taps: Arc<Mutex<HashMap<u32, DoubleTapHotkey>>>,     // Same ID space as the hotkeys
     callback: Option<HotkeyCallback>,
     next_id: Arc<Mutex<u32>>,
-    #[cfg(windows)]
+    backend_factory: HotkeyBackendFactory,
+    stop: Arc<AtomicBool>,
+    sequence_timeout_ms: Arc<AtomicU64>,
+    hold_threshold_ms: Arc<AtomicU64>,
+    double_tap_interval_ms: Arc<AtomicU64>,
+    commands: Option<mpsc::Sender<HotkeyCommand>>,
     message_thread: Option<thread::JoinHandle<()>>,
 }
 
 impl HotkeyService {
     pub fn new() -> Result<Self> {
+        Self::with_backend(hotkey_backend::platform_backend_factory())
+    }
+
+    /// Use a specific OS backend (tests use the in-memory one)
+    pub fn with_backend(backend_factory: HotkeyBackendFactory) -> Result<Self> {
         info!("Initializing hotkey service");
         
         Ok(Self {
             registered_hotkeys: Arc::new(Mutex::new(HashMap::new())),
+            double_taps: Arc::new(Mutex::new(HashMap::new())),
             callback: None,
             next_id: Arc::new(Mutex::new(1)),
-            #[cfg(windows)]
+            backend_factory,
+            stop: Arc::new(AtomicBool::new(false)),
+            sequence_timeout_ms: Arc::new(AtomicU64::new(DEFAULT_SEQUENCE_TIMEOUT_MS)),
+            hold_threshold_ms: Arc::new(AtomicU64::new(DEFAULT_HOLD_THRESHOLD_MS)),
+            double_tap_interval_ms: Arc::new(AtomicU64::new(DEFAULT_DOUBLE_TAP_INTERVAL_MS)),
+            commands: None,
             message_thread: None,
         })
     }
@@ -55,47 +274,227 @@ impl HotkeyService {
         self.callback = Some(callback);
     }
 
+    /// Time allowed between the keys of a sequence such as "Ctrl+Space, G, P"
+    pub fn set_sequence_timeout(&self, timeout: Duration) {
+        self.sequence_timeout_ms.store(timeout.as_millis() as u64, Ordering::Relaxed);
+    }
+
+    /// How long a hold hotkey has to be held for its release action to fire
+    pub fn set_hold_threshold(&self, threshold: Duration) {
+        self.hold_threshold_ms.store(threshold.as_millis() as u64, Ordering::Relaxed);
+    }
+
+    /// Time allowed for each tap of a double tap and for the gap between the taps
+    pub fn set_double_tap_interval(&self, interval: Duration) {
+        self.double_tap_interval_ms.store(interval.as_millis() as u64, Ordering::Relaxed);
+    }
+
+    fn allocate_id(&self) -> u32 {
+        let mut next_id = self.next_id.lock().unwrap();
+        let id = *next_id;
+        *next_id += 1;
+        id
+    }
+
     pub fn register_hotkey(&mut self, hotkey_str: &str, action: String) -> Result<u32> {
+        self.add_hotkey(hotkey_str, action, None)
+    }
+
+    fn add_hotkey(&mut self, hotkey_str: &str, action: String, release_action: Option<String>) -> Result<u32> {
         let parsed = self.parse_hotkey_string(hotkey_str)?;
-        let id = {
-            let mut next_id = self.next_id.lock().unwrap();
-            let id = *next_id;
-            *next_id += 1;
-            id
-        };
+        if parsed.is_sequence() && release_action.is_some() {
+            return Err(anyhow::anyhow!("Hotkey '{}' is a key sequence and cannot be held", hotkey_str));
+        }
 
+        let id = self.allocate_id();
         let hotkey = ParsedHotkey {
             id,
-            modifiers: parsed.modifiers,
-            vk_code: parsed.vk_code,
             action,
+            release_action,
+            ..parsed
         };
 
-        // Don't register system hotkey here - it will be registered in the message loop thread
-        // self.register_system_hotkey(&hotkey)?;
-        
-        {
+        if self.commands.is_some() {
+            self.send_command(|reply| HotkeyCommand::Register(hotkey, reply))?;
+            info!("Registered hotkey: {} with ID {}", hotkey_str, id);
+        } else {
+            // Registered with the OS once the message loop starts
             let mut hotkeys = self.registered_hotkeys.lock().unwrap();
-            hotkeys.insert(id, hotkey.clone());
+            hotkeys.insert(id, hotkey);
+            info!("Queued hotkey for registration: {} with ID {}", hotkey_str, id);
+        }
+
+        Ok(id)
+    }
+
+    /// Fire `action` when `modifier` (e.g. "Ctrl") is tapped twice on its own
+    pub fn register_double_tap(&mut self, modifier: &str, action: String) -> Result<u32> {
+        let modifier = ModifierKey::from_name(modifier)?;
+        let id = self.allocate_id();
+        let double_tap = DoubleTapHotkey { id, modifier, action };
+
+        if self.commands.is_some() {
+            self.send_command(|reply| HotkeyCommand::WatchDoubleTap(double_tap, reply))?;
+            info!("Registered double tap of {} with ID {}", modifier, id);
+        } else {
+            self.double_taps.lock().unwrap().insert(id, double_tap);
+            info!("Queued double tap of {} with ID {}", modifier, id);
         }
 
-        info!("Queued hotkey for registration: {} with ID {}", hotkey_str, id);
         Ok(id)
     }
 
     pub fn unregister_hotkey(&mut self, id: u32) -> Result<()> {
-        {
-            let mut hotkeys = self.registered_hotkeys.lock().unwrap();
-            if hotkeys.remove(&id).is_none() {
-                return Err(anyhow::anyhow!("Hotkey with ID {} not found", id));
-            }
+        if !self.registered_hotkeys.lock().unwrap().contains_key(&id) && !self.double_taps.lock().unwrap().contains_key(&id) {
+            return Err(anyhow::anyhow!("Hotkey with ID {} not found", id));
+        }
+
+        if self.commands.is_some() {
+            self.send_command(|reply| HotkeyCommand::Unregister(id, reply))?;
+        } else {
+            self.registered_hotkeys.lock().unwrap().remove(&id);
+            self.double_taps.lock().unwrap().remove(&id);
         }
 
-        self.unregister_system_hotkey(id)?;
         info!("Unregistered hotkey with ID {}", id);
         Ok(())
     }
 
+    /// Point an existing hotkey ID at a new key combination. If the OS refuses the new
+    /// combination the old one stays registered.
+    pub fn replace_hotkey(&mut self, id: u32, hotkey_str: &str) -> Result<()> {
+        let (action, release_action) = self.registered_hotkeys.lock().unwrap()
+            .get(&id)
+            .map(|hotkey| (hotkey.action.clone(), hotkey.release_action.clone()))
+            .ok_or_else(|| anyhow::anyhow!("Hotkey with ID {} not found", id))?;
+        let hotkey = ParsedHotkey {
+            id,
+            action,
+            release_action,
+            ..self.parse_hotkey_string(hotkey_str)?
+        };
+        if hotkey.is_sequence() && hotkey.release_action.is_some() {
+            return Err(anyhow::anyhow!("Hotkey '{}' is a key sequence and cannot be held", hotkey_str));
+        }
+
+        if self.commands.is_some() {
+            self.send_command(|reply| HotkeyCommand::Replace(hotkey, reply))?;
+        } else {
+            self.registered_hotkeys.lock().unwrap().insert(id, hotkey);
+        }
+
+        info!("Replaced hotkey ID {} with {}", id, hotkey_str);
+        Ok(())
+    }
+
+    /// Drop every registered hotkey (used before re-registering from a reloaded config)
+    pub fn unregister_all(&mut self) {
+        let mut hotkey_ids: Vec<u32> = self.registered_hotkeys.lock().unwrap().keys().copied().collect();
+        hotkey_ids.extend(self.double_taps.lock().unwrap().keys().copied());
+        for id in &hotkey_ids {
+            if let Err(e) = self.unregister_hotkey(*id) {
+                debug!("Unregistration of hotkey {} failed: {}", id, e);
+            }
+        }
+        info!("Unregistered {} hotkeys", hotkey_ids.len());
+    }
+
+    // Run a command on the hotkey thread and wait for the OS result. There is no timeout:
+    // a queued command runs eventually, so giving up early would misreport what the OS has.
+    fn send_command(&self, command: impl FnOnce(mpsc::Sender<Result<()>>) -> HotkeyCommand) -> Result<()> {
+        let commands = self.commands.as_ref()
+            .ok_or_else(|| anyhow::anyhow!("Hotkey message loop is not running"))?;
+        let (reply, response) = mpsc::channel();
+        commands.send(command(reply))
+            .map_err(|_| anyhow::anyhow!("Global hotkeys are unavailable: the hotkey thread has stopped"))?;
+
+        response.recv()
+            .map_err(|_| anyhow::anyhow!("Global hotkeys are unavailable: the hotkey thread has stopped"))?
+    }
+
+    /// Register every hotkey of the config. Clashing bindings are skipped (the first declaration
+    /// wins), and the summon fallbacks are tried last, only if no summon hotkey could be registered.
+    pub fn register_config(&mut self, config: &QDeckConfig) -> HotkeyConflictReport {
+        let summon = &config.ui.summon;
+        self.set_sequence_timeout(Duration::from_millis(summon.sequence_timeout_ms));
+        self.set_hold_threshold(Duration::from_millis(summon.hold_threshold_ms));
+        self.set_double_tap_interval(Duration::from_millis(summon.double_tap_interval_ms));
+
+        let bindings = config_hotkey_bindings(config);
+        let mut report = HotkeyConflictReport {
+            duplicates: find_hotkey_conflicts(&bindings),
+            ..Default::default()
+        };
+
+        for binding in &bindings {
+            if let Some(conflict) = report.duplicates.iter().find(|c| c.source == binding.source) {
+                warn!("⚠️ Skipping hotkey at {}: {}", binding.source, conflict.message);
+                continue;
+            }
+            self.register_binding(binding, &mut report);
+        }
+
+        // Only `summon.hotkeys` count here: a double-tap or a button bound to the overlay is no stand-in
+        let summon_registered = report.effective.iter().any(|hotkey| hotkey.source.starts_with("ui.summon.hotkeys["));
+        if !summon_registered && !summon.hotkeys.is_empty() {
+            warn!("⚠️ No summon hotkey could be registered, trying fallbacks");
+            for (index, hotkey) in summon.fallback_hotkeys.iter().enumerate() {
+                let binding = summon_binding(config, hotkey, format!("ui.summon.fallback_hotkeys[{}]", index));
+                if self.register_binding(&binding, &mut report) {
+                    break;
+                }
+            }
+        }
+
+        if let Some(modifier) = summon.double_tap.as_deref() {
+            let hotkey = format!("Double-tap {}", modifier);
+            let source = "ui.summon.double_tap".to_string();
+            match self.register_double_tap(modifier, "toggle_overlay".to_string()) {
+                Ok(id) => {
+                    info!("✅ Registered {} for toggle_overlay with ID {}", hotkey, id);
+                    report.effective.push(EffectiveHotkey { id, hotkey, action: "toggle_overlay".to_string(), source });
+                }
+                Err(e) => {
+                    error!("❌ Failed to register {}: {}", hotkey, e);
+                    report.unavailable.push(UnavailableHotkey { hotkey, action: "toggle_overlay".to_string(), source, reason: e.to_string() });
+                }
+            }
+        }
+
+        if report.has_conflicts() {
+            warn!("⚠️ Hotkeys: {} active, {} conflicting, {} unavailable",
+                  report.effective.len(), report.duplicates.len(), report.unavailable.len());
+        } else {
+            info!("✅ Hotkeys: {} active", report.effective.len());
+        }
+        report
+    }
+
+    fn register_binding(&mut self, binding: &HotkeyBinding, report: &mut HotkeyConflictReport) -> bool {
+        match self.add_hotkey(&binding.hotkey, binding.action.clone(), binding.release_action.clone()) {
+            Ok(id) => {
+                info!("✅ Registered hotkey '{}' for {} with ID {}", binding.hotkey, binding.action, id);
+                report.effective.push(EffectiveHotkey {
+                    id,
+                    hotkey: binding.hotkey.clone(),
+                    action: binding.action.clone(),
+                    source: binding.source.clone(),
+                });
+                true
+            }
+            Err(e) => {
+                error!("❌ Failed to register hotkey '{}' for {}: {}", binding.hotkey, binding.action, e);
+                report.unavailable.push(UnavailableHotkey {
+                    hotkey: binding.hotkey.clone(),
+                    action: binding.action.clone(),
+                    source: binding.source.clone(),
+                    reason: e.to_string(),
+                });
+                false
+            }
+        }
+    }
+
     pub fn register_multiple_hotkeys(&mut self, hotkey_configs: Vec<HotkeyConfig>) -> Result<Vec<u32>> {
         let mut registered_ids = Vec::new();
         let mut failed_registrations = Vec::new();
@@ -125,425 +524,442 @@ impl HotkeyService {
         if self.callback.is_none() {
             return Err(anyhow::anyhow!("Callback not set. Call set_callback() first."));
         }
+        if self.message_thread.is_some() {
+            return Err(anyhow::anyhow!("Hotkey message loop is already running"));
+        }
 
-        #[cfg(windows)]
-        {
-            let hotkeys = Arc::clone(&self.registered_hotkeys);
-            let callback = self.callback.as_ref().unwrap().clone();
-
-            let handle = thread::spawn(move || {
-                // Re-register all hotkeys in the message loop thread
-                info!("🔄 Re-registering hotkeys in message loop thread...");
-                let thread_id = unsafe { winapi::um::processthreadsapi::GetCurrentThreadId() };
-                info!("📍 Message loop thread ID: {}", thread_id);
-                
-                // Get all hotkeys to re-register
-                let hotkeys_to_register: Vec<ParsedHotkey> = {
-                    let hotkeys_guard = hotkeys.lock().unwrap();
-                    hotkeys_guard.values().cloned().collect()
-                };
-                
-                info!("🎯 Re-registering {} hotkeys in message loop thread:", hotkeys_to_register.len());
-                
-                // Re-register each hotkey in this thread
-                for hotkey in &hotkeys_to_register {
-                    info!("🔧 Re-registering hotkey ID {} in message loop thread", hotkey.id);
-                    unsafe {
-                        let result = RegisterHotKey(
-                            std::ptr::null_mut(),
-                            hotkey.id as i32,
-                            hotkey.modifiers,
-                            hotkey.vk_code,
-                        );
-                        
-                        if result == 0 {
-                            let error_code = winapi::um::errhandlingapi::GetLastError();
-                            error!("❌ Failed to re-register hotkey ID {} in message loop thread. Error: {}", hotkey.id, error_code);
-                        } else {
-                            info!("✅ Successfully re-registered hotkey ID {} in message loop thread", hotkey.id);
-                        }
-                    }
+        let hotkeys = Arc::clone(&self.registered_hotkeys);
+        let double_taps = Arc::clone(&self.double_taps);
+        let callback = self.callback.as_ref().unwrap().clone();
+        let backend_factory = Arc::clone(&self.backend_factory);
+        let stop = Arc::clone(&self.stop);
+        let sequence_timeout_ms = Arc::clone(&self.sequence_timeout_ms);
+        let hold_threshold_ms = Arc::clone(&self.hold_threshold_ms);
+        let double_tap_interval_ms = Arc::clone(&self.double_tap_interval_ms);
+        let (command_sender, commands) = mpsc::channel();
+        let (ready_sender, ready) = mpsc::channel();
+
+        let handle = thread::spawn(move || {
+            // The backend lives on this thread for its whole life (Windows hotkeys are per-thread)
+            let backend = match backend_factory() {
+                Ok(backend) => backend,
+                Err(e) => {
+                    let _ = ready_sender.send(Err(e));
+                    return;
                 }
-                
-                Self::windows_message_loop(hotkeys, callback);
-            });
+            };
+
+            let mut message_loop = MessageLoop {
+                backend,
+                hotkeys,
+                double_taps,
+                callback,
+                sequence_timeout_ms,
+                hold_threshold_ms,
+                double_tap_interval_ms,
+                leaders: HashMap::new(),
+                follow_ups: HashMap::new(),
+                tracker: SequenceTracker::default(),
+                holds: HoldTracker::default(),
+                tap_detectors: HashMap::new(),
+            };
+            message_loop.register_queued();
+            let _ = ready_sender.send(Ok(()));
 
-            self.message_thread = Some(handle);
+            message_loop.run(&commands, &stop);
+        });
+
+        match ready.recv_timeout(STARTUP_TIMEOUT) {
+            Ok(Ok(())) => {}
+            Ok(Err(e)) => {
+                let _ = handle.join();
+                return Err(e.context("Global hotkeys unavailable"));
+            }
+            Err(_) => return Err(anyhow::anyhow!("Hotkey thread did not start")),
         }
 
+        self.commands = Some(command_sender);
+        self.message_thread = Some(handle);
         info!("✅ Started hotkey message loop thread");
         Ok(())
     }
 
-    #[cfg(windows)]
-    fn windows_message_loop(
-        hotkeys: Arc<Mutex<HashMap<u32, ParsedHotkey>>>,
-        callback: HotkeyCallback,
-    ) {
-        info!("🚀 Starting Windows message loop for hotkeys");
-        
-        // Log thread information
-        let thread_id = unsafe { winapi::um::processthreadsapi::GetCurrentThreadId() };
-        info!("📍 Message loop running on thread ID: {}", thread_id);
+    fn parse_hotkey_string(&self, hotkey_str: &str) -> Result<ParsedHotkey> {
+        debug!("🔍 Parsing hotkey string: '{}'", hotkey_str);
         
-        unsafe {
-            // Set up a timer to generate periodic messages to test the loop
-            let timer_id = SetTimer(
-                std::ptr::null_mut(),
-                1,
-                5000, // 5 seconds
-                None
-            );
-            
-            if timer_id != 0 {
-                info!("✅ Timer set up successfully (ID: {})", timer_id);
-            } else {
-                warn!("⚠️ Failed to set up timer");
+        // Shared grammar with the SendKeys action (see keys.rs)
+        let mut chords = keys::parse_chord_sequence(hotkey_str)?;
+        let chord = chords.remove(0);
+        debug!("Key: {:?}, Modifiers: {:?}, Sequence: {:?}", chord.key, chord.modifiers, chords);
+
+        let mut parsed = ParsedHotkey {
+            id: 0, // Will be set by caller
+            hotkey: hotkey_str.trim().to_string(),
+            display: String::new(),
+            modifiers: chord.modifiers,
+            key: chord.key,
+            sequence: chords,
+            action: String::new(), // Will be set by caller
+            release_action: None,
+        };
+        parsed.display = parsed.to_hotkey_string();
+        Ok(parsed)
+    }
+
+    fn parse_key_code(&self, key: &str) -> Result<u32> {
+        Ok(keys::Key::from_name(key)?.vk_code())
+    }
+
+    fn build_hotkey_string(&self, modifiers: &[String], key: &str) -> String {
+        if modifiers.is_empty() {
+            key.to_string()
+        } else {
+            format!("{}+{}", modifiers.join("+"), key)
+        }
+    }
+
+    pub fn get_registered_hotkeys(&self) -> Vec<ParsedHotkey> {
+        let hotkeys = self.registered_hotkeys.lock().unwrap();
+        hotkeys.values().cloned().collect()
+    }
+
+    pub fn is_hotkey_available(&self, hotkey_str: &str) -> bool {
+        let parsed = match self.parse_hotkey_string(hotkey_str) {
+            Ok(parsed) => parsed,
+            Err(_) => return false,
+        };
+
+        // Probe with a throwaway backend on this thread: register, then release immediately
+        let mut backend = match (self.backend_factory)() {
+            Ok(backend) => backend,
+            Err(e) => {
+                debug!("Cannot check hotkey availability: {}", e);
+                return false;
             }
-            
-            let mut msg: MSG = std::mem::zeroed();
-            let mut message_count = 0u64;
-            
-            // Test if we can receive any messages at all
-            info!("🧪 Testing message loop - waiting for first message...");
-            
-            loop {
-                let result = GetMessageW(&mut msg, std::ptr::null_mut(), 0, 0);
-                message_count += 1;
-                
-                // Periodic heartbeat to confirm message loop is alive
-                if message_count % 1000 == 0 {
-                    info!("💓 Message loop heartbeat: {} messages processed", message_count);
+        };
+
+        let probe_id = 9999; // Use a high ID for testing
+        match backend.register(probe_id, &parsed.chord()) {
+            Ok(_) => {
+                let _ = backend.unregister(probe_id);
+                true
+            }
+            Err(e) => {
+                debug!("Hotkey '{}' is not available: {}", hotkey_str, e);
+                false
+            }
+        }
+    }
+}
+
+// State owned by the hotkey thread
+struct MessageLoop {
+    backend: Box<dyn HotkeyBackend>,
+    hotkeys: Arc<Mutex<HashMap<u32, ParsedHotkey>>>,
+    double_taps: Arc<Mutex<HashMap<u32, DoubleTapHotkey>>>,
+    callback: HotkeyCallback,
+    sequence_timeout_ms: Arc<AtomicU64>,
+    hold_threshold_ms: Arc<AtomicU64>,
+    double_tap_interval_ms: Arc<AtomicU64>,
+    leaders: HashMap<KeyChord, u32>,    // Leader chord -> backend ID, shared by its sequences
+    follow_ups: HashMap<u32, KeyChord>, // Keys grabbed while a sequence is armed
+    tracker: SequenceTracker,
+    holds: HoldTracker,
+    tap_detectors: HashMap<u32, DoubleTapDetector>,
+}
+
+impl MessageLoop {
+    // Register whatever was queued before the loop started; drop what the OS refuses
+    fn register_queued(&mut self) {
+        let mut queued: Vec<ParsedHotkey> = self.hotkeys.lock().unwrap().drain().map(|(_, hotkey)| hotkey).collect();
+        queued.sort_by_key(|hotkey| hotkey.id);
+        info!("🎯 Registering {} hotkeys in message loop thread", queued.len());
+
+        for hotkey in queued {
+            match self.register(&hotkey) {
+                Ok(_) => {
+                    info!("✅ Registered hotkey '{}' (ID {})", hotkey.hotkey, hotkey.id);
+                    self.hotkeys.lock().unwrap().insert(hotkey.id, hotkey);
                 }
-                
-                // Special logging for first few messages to ensure loop is working
-                if message_count <= 10 {
-                    info!("🔍 Early message #{}: 0x{:X} (wParam: 0x{:X}, lParam: 0x{:X})", 
-                          message_count, msg.message, msg.wParam, msg.lParam);
+                Err(e) => error!("❌ Failed to register hotkey '{}' (ID {}): {}", hotkey.hotkey, hotkey.id, e),
+            }
+        }
+
+        let queued: Vec<DoubleTapHotkey> = self.double_taps.lock().unwrap().drain().map(|(_, double_tap)| double_tap).collect();
+        for double_tap in queued {
+            match self.watch_double_tap(&double_tap) {
+                Ok(_) => {
+                    info!("✅ Watching double taps of {} (ID {})", double_tap.modifier, double_tap.id);
+                    self.double_taps.lock().unwrap().insert(double_tap.id, double_tap);
                 }
-                
-                if result == 0 {
-                    // WM_QUIT received
-                    info!("WM_QUIT received, stopping message loop after {} messages", message_count);
-                    break;
-                } else if result == -1 {
-                    // Error occurred
-                    error!("Error in GetMessageW after {} messages", message_count);
+                Err(e) => error!("❌ Failed to watch double taps of {} (ID {}): {}", double_tap.modifier, double_tap.id, e),
+            }
+        }
+    }
+
+    fn run(&mut self, commands: &mpsc::Receiver<HotkeyCommand>, stop: &AtomicBool) {
+        while !stop.load(Ordering::Relaxed) {
+            loop {
+                match commands.try_recv() {
+                    Ok(command) => self.handle_command(command),
+                    Err(mpsc::TryRecvError::Empty) => break,
+                    Err(mpsc::TryRecvError::Disconnected) => return,
+                }
+            }
+
+            let events = match self.backend.poll_events(POLL_INTERVAL) {
+                Ok(events) => events,
+                Err(e) => {
+                    error!("Hotkey event loop failed: {:#}", e);
                     break;
                 }
+            };
 
-                // Log all messages for debugging (only every 1000th message to avoid spam)
-                if message_count % 1000 == 0 || msg.message == WM_HOTKEY {
-                    debug!("📨 Message #{}: 0x{:X}, wParam: 0x{:X}, lParam: 0x{:X}", 
-                           message_count, msg.message, msg.wParam, msg.lParam);
+            for event in events {
+                match event {
+                    HotkeyEvent::Pressed(backend_id) => self.dispatch(backend_id),
+                    HotkeyEvent::Released(backend_id) => self.released(backend_id),
                 }
+            }
 
-                if msg.message == WM_HOTKEY {
-                    let hotkey_id = msg.wParam as u32;
-                    let modifiers = (msg.lParam & 0xFFFF) as u32;
-                    let vk_code = ((msg.lParam >> 16) & 0xFFFF) as u32;
-                    
-                    info!("🔥 HOTKEY TRIGGERED! ID: {}, VK: 0x{:X}, Modifiers: 0x{:X}", 
-                          hotkey_id, vk_code, modifiers);
-                    
-                    if let Ok(hotkeys_guard) = hotkeys.lock() {
-                        if let Some(hotkey) = hotkeys_guard.get(&hotkey_id) {
-                            info!("Executing hotkey action: {}", hotkey.action);
-                            callback(&hotkey.action);
-                        } else {
-                            warn!("No hotkey found for ID: {}", hotkey_id);
-                        }
-                    } else {
-                        error!("Failed to lock hotkeys mutex");
+            if self.tracker.expire(Instant::now()) {
+                info!("⌛ Key sequence timed out");
+                self.release_follow_ups();
+            }
+        }
+
+        info!("Hotkey message loop ended");
+    }
+
+    fn handle_command(&mut self, command: HotkeyCommand) {
+        match command {
+            HotkeyCommand::Register(hotkey, reply) => {
+                let result = self.register(&hotkey);
+                if result.is_ok() {
+                    self.hotkeys.lock().unwrap().insert(hotkey.id, hotkey);
+                }
+                let _ = reply.send(result);
+            }
+            HotkeyCommand::Unregister(id, reply) => {
+                let _ = reply.send(self.unregister(id));
+            }
+            HotkeyCommand::Replace(hotkey, reply) => {
+                let previous = self.hotkeys.lock().unwrap().get(&hotkey.id).cloned();
+                if let Err(e) = self.unregister(hotkey.id) {
+                    debug!("Releasing hotkey {} before replacing it failed: {}", hotkey.id, e);
+                }
+
+                let result = self.register(&hotkey);
+                match &result {
+                    Ok(_) => {
+                        self.hotkeys.lock().unwrap().insert(hotkey.id, hotkey);
                     }
-                } else {
-                    // Log all key-related messages for debugging
-                    match msg.message {
-                        0x0100 => info!("⌨️  WM_KEYDOWN: VK 0x{:X} ({})", msg.wParam, msg.wParam), // WM_KEYDOWN
-                        0x0101 => info!("⌨️  WM_KEYUP: VK 0x{:X} ({})", msg.wParam, msg.wParam),   // WM_KEYUP
-                        0x0104 => info!("⌨️  WM_SYSKEYDOWN: VK 0x{:X} ({})", msg.wParam, msg.wParam), // WM_SYSKEYDOWN
-                        0x0105 => info!("⌨️  WM_SYSKEYUP: VK 0x{:X} ({})", msg.wParam, msg.wParam),   // WM_SYSKEYUP
-                        0x0312 => info!("🔥 WM_HOTKEY detected but not handled properly: wParam={}, lParam={}", msg.wParam, msg.lParam), // WM_HOTKEY
-                        0x0113 => info!("⏰ WM_TIMER: Timer ID {}", msg.wParam), // WM_TIMER
-                        _ => {
-                            // Log any other message that might be relevant
-                            if msg.message >= 0x0100 && msg.message <= 0x0109 {
-                                debug!("Other key message: 0x{:X}, wParam: 0x{:X}", msg.message, msg.wParam);
-                            } else if msg.message == 0x0113 {
-                                // Already handled WM_TIMER above
-                            } else {
-                                // Log other messages occasionally
-                                if message_count % 100 == 0 {
-                                    debug!("Other message: 0x{:X}, wParam: 0x{:X}", msg.message, msg.wParam);
+                    Err(_) => {
+                        // Put the previous combination back
+                        if let Some(previous) = previous {
+                            match self.register(&previous) {
+                                Ok(_) => {
+                                    self.hotkeys.lock().unwrap().insert(previous.id, previous);
                                 }
+                                Err(e) => error!("❌ Failed to restore hotkey '{}' (ID {}): {}", previous.hotkey, previous.id, e),
                             }
                         }
                     }
                 }
+                let _ = reply.send(result);
+            }
+            HotkeyCommand::WatchDoubleTap(double_tap, reply) => {
+                let result = self.watch_double_tap(&double_tap);
+                if result.is_ok() {
+                    self.double_taps.lock().unwrap().insert(double_tap.id, double_tap);
+                }
+                let _ = reply.send(result);
+            }
+        }
+    }
+
+    fn watch_double_tap(&mut self, double_tap: &DoubleTapHotkey) -> Result<()> {
+        self.backend.watch_modifier(double_tap.id, double_tap.modifier)?;
+        self.tap_detectors.insert(double_tap.id, DoubleTapDetector::default());
+        Ok(())
+    }
 
-                TranslateMessage(&msg);
-                DispatchMessageW(&msg);
+    // Register with the OS; sequences share one registration per leader chord
+    fn register(&mut self, hotkey: &ParsedHotkey) -> Result<()> {
+        let chord = hotkey.chord();
+        if !hotkey.is_sequence() {
+            if self.leaders.contains_key(&chord) {
+                return Err(anyhow::anyhow!("Hotkey '{}' is already the start of a key sequence", hotkey.hotkey));
             }
+            return self.backend.register(hotkey.id, &chord);
         }
-        info!("Windows message loop ended");
+
+        // A sequence that is a prefix of another could never complete
+        let keys = hotkey.all_keys();
+        if let Some(other) = self.hotkeys.lock().unwrap().values().find(|other| {
+            let other_keys = other.all_keys();
+            other.id != hotkey.id && (other_keys.starts_with(&keys) || keys.starts_with(&other_keys))
+        }) {
+            return Err(anyhow::anyhow!("Hotkey '{}' conflicts with '{}'", hotkey.hotkey, other.hotkey));
+        }
+
+        if !self.leaders.contains_key(&chord) {
+            let backend_id = (LEADER_ID_BASE..FOLLOW_UP_ID_BASE)
+                .find(|id| !self.leaders.values().any(|used| used == id))
+                .ok_or_else(|| anyhow::anyhow!("Too many key sequence leaders"))?;
+            self.backend.register(backend_id, &chord)?;
+            self.leaders.insert(chord, backend_id);
+        }
+        Ok(())
     }
 
-    fn parse_hotkey_string(&self, hotkey_str: &str) -> Result<ParsedHotkey> {
-        debug!("🔍 Parsing hotkey string: '{}'", hotkey_str);
-        
-        let parts: Vec<&str> = hotkey_str.split('+').map(|s| s.trim()).collect();
-        
-        if parts.is_empty() {
-            return Err(anyhow::anyhow!("Invalid hotkey string: {}", hotkey_str));
+    fn unregister(&mut self, id: u32) -> Result<()> {
+        if self.double_taps.lock().unwrap().remove(&id).is_some() {
+            self.tap_detectors.remove(&id);
+            return self.backend.unregister(id);
         }
 
-        let key = parts.last().unwrap();
-        let modifiers = &parts[..parts.len() - 1];
+        let hotkey = self.hotkeys.lock().unwrap().remove(&id)
+            .ok_or_else(|| anyhow::anyhow!("Hotkey with ID {} not found", id))?;
+        if !hotkey.is_sequence() {
+            self.holds.forget(id);
+            return self.backend.unregister(id);
+        }
 
-        debug!("Key: '{}', Modifiers: {:?}", key, modifiers);
+        if self.tracker.is_armed() {
+            self.tracker.cancel();
+            self.release_follow_ups();
+        }
 
-        let mut mod_flags = 0u32;
-        for modifier in modifiers {
-            match modifier.to_lowercase().as_str() {
-                "ctrl" | "control" => {
-                    mod_flags |= MOD_CONTROL as u32;
-                    debug!("Added CTRL modifier (0x{:X})", MOD_CONTROL);
-                }
-                "alt" => {
-                    mod_flags |= MOD_ALT as u32;
-                    debug!("Added ALT modifier (0x{:X})", MOD_ALT);
-                }
-                "shift" => {
-                    mod_flags |= MOD_SHIFT as u32;
-                    debug!("Added SHIFT modifier (0x{:X})", MOD_SHIFT);
-                }
-                "win" | "windows" | "cmd" => {
-                    mod_flags |= MOD_WIN as u32;
-                    debug!("Added WIN modifier (0x{:X})", MOD_WIN);
-                }
-                _ => return Err(anyhow::anyhow!("Unknown modifier: {}", modifier)),
+        // Release the leader once no sequence uses it
+        let chord = hotkey.chord();
+        let leader_in_use = self.hotkeys.lock().unwrap().values().any(|other| other.is_sequence() && other.chord() == chord);
+        if !leader_in_use {
+            if let Some(backend_id) = self.leaders.remove(&chord) {
+                return self.backend.unregister(backend_id);
             }
         }
+        Ok(())
+    }
 
-        let vk_code = self.parse_key_code(key)?;
-        debug!("Parsed VK code: 0x{:X} for key '{}'", vk_code, key);
-
-        let parsed = ParsedHotkey {
-            id: 0, // Will be set by caller
-            modifiers: mod_flags,
-            vk_code,
-            action: String::new(), // Will be set by caller
-        };
+    fn dispatch(&mut self, backend_id: u32) {
+        let now = Instant::now();
 
-        info!("📋 Parsed hotkey '{}' -> Modifiers: 0x{:X}, VK: 0x{:X}", 
-              hotkey_str, parsed.modifiers, parsed.vk_code);
+        if let Some(detector) = self.tap_detectors.get_mut(&backend_id) {
+            detector.pressed(now);
+            return;
+        }
 
-        Ok(parsed)
-    }
+        if let Some(chord) = self.follow_ups.get(&backend_id).copied() {
+            let step = self.tracker.key_pressed(chord, now);
+            self.apply_sequence_step(step);
+            return;
+        }
 
-    fn parse_key_code(&self, key: &str) -> Result<u32> {
-        match key.to_lowercase().as_str() {
-            // Function keys
-            "f1" => Ok(0x70),
-            "f2" => Ok(0x71),
-            "f3" => Ok(0x72),
-            "f4" => Ok(0x73),
-            "f5" => Ok(0x74),
-            "f6" => Ok(0x75),
-            "f7" => Ok(0x76),
-            "f8" => Ok(0x77),
-            "f9" => Ok(0x78),
-            "f10" => Ok(0x79),
-            "f11" => Ok(0x7A),
-            "f12" => Ok(0x7B),
-            
-            // Special keys
-            "escape" | "esc" => Ok(VK_ESCAPE as u32),
-            "space" => Ok(0x20),
-            "enter" | "return" => Ok(0x0D),
-            "tab" => Ok(0x09),
-            "backspace" => Ok(0x08),
-            "delete" | "del" => Ok(0x2E),
-            "insert" | "ins" => Ok(0x2D),
-            "home" => Ok(0x24),
-            "end" => Ok(0x23),
-            "pageup" | "pgup" => Ok(0x21),
-            "pagedown" | "pgdn" => Ok(0x22),
-            
-            // Arrow keys
-            "up" => Ok(0x26),
-            "down" => Ok(0x28),
-            "left" => Ok(0x25),
-            "right" => Ok(0x27),
-            
-            // Number keys
-            "0" => Ok(0x30),
-            "1" => Ok(0x31),
-            "2" => Ok(0x32),
-            "3" => Ok(0x33),
-            "4" => Ok(0x34),
-            "5" => Ok(0x35),
-            "6" => Ok(0x36),
-            "7" => Ok(0x37),
-            "8" => Ok(0x38),
-            "9" => Ok(0x39),
-            
-            // Letter keys (A-Z)
-            key if key.len() == 1 && key.chars().next().unwrap().is_ascii_alphabetic() => {
-                let ch = key.to_uppercase().chars().next().unwrap();
-                Ok(ch as u32)
-            }
-            
-            // Special characters
-            "oem3" | "`" | "~" => Ok(0xC0), // Backtick/Tilde key
-            "oem1" | ";" | ":" => Ok(0xBA), // Semicolon/Colon key
-            "oemplus" | "=" | "+" => Ok(0xBB), // Plus/Equal key
-            "oemcomma" | "," | "<" => Ok(0xBC), // Comma/Less than key
-            "oemminus" | "-" | "_" => Ok(0xBD), // Minus/Underscore key
-            "oemperiod" | "." | ">" => Ok(0xBE), // Period/Greater than key
-            "oem2" | "/" | "?" => Ok(0xBF), // Forward slash/Question mark key
-            
-            _ => Err(anyhow::anyhow!("Unknown key: {}", key)),
+        if let Some(leader) = self.leaders.iter().find(|(_, id)| **id == backend_id).map(|(chord, _)| *chord) {
+            let candidates = self.hotkeys.lock().unwrap().values()
+                .filter(|hotkey| hotkey.is_sequence() && hotkey.chord() == leader)
+                .map(|hotkey| (hotkey.id, hotkey.sequence.clone()))
+                .collect();
+            let timeout = Duration::from_millis(self.sequence_timeout_ms.load(Ordering::Relaxed));
+            debug!("🎹 Key sequence leader pressed");
+            let step = self.tracker.arm(candidates, timeout, now);
+            self.apply_sequence_step(step);
+            return;
         }
-    }
 
-    fn build_hotkey_string(&self, modifiers: &[String], key: &str) -> String {
-        if modifiers.is_empty() {
-            key.to_string()
-        } else {
-            format!("{}+{}", modifiers.join("+"), key)
+        let is_hold = self.hotkeys.lock().unwrap().get(&backend_id).map(|hotkey| hotkey.release_action.is_some());
+        if is_hold == Some(true) {
+            self.holds.pressed(backend_id, now);
         }
+        self.fire(backend_id);
     }
 
-    #[cfg(windows)]
-    fn register_system_hotkey(&self, hotkey: &ParsedHotkey) -> Result<()> {
-        info!("🔧 Registering system hotkey - ID: {}, Modifiers: 0x{:X}, VK: 0x{:X}", 
-              hotkey.id, hotkey.modifiers, hotkey.vk_code);
-        
-        // Get current thread ID for debugging
-        let thread_id = unsafe { winapi::um::processthreadsapi::GetCurrentThreadId() };
-        info!("📍 Registering hotkey on thread ID: {}", thread_id);
-        
-        unsafe {
-            let result = RegisterHotKey(
-                std::ptr::null_mut(),
-                hotkey.id as i32,
-                hotkey.modifiers,
-                hotkey.vk_code,
-            );
-            
-            if result == 0 {
-                let error_code = winapi::um::errhandlingapi::GetLastError();
-                error!("❌ Failed to register hotkey (ID: {}). Error code: {}. This hotkey may already be in use by another application.", 
-                       hotkey.id, error_code);
-                return Err(anyhow::anyhow!(
-                    "Failed to register hotkey (ID: {}). Error code: {}. This hotkey may already be in use by another application.",
-                    hotkey.id,
-                    error_code
-                ));
-            } else {
-                info!("✅ Successfully registered system hotkey ID: {} on thread {}", hotkey.id, thread_id);
-                
-                // Verify the hotkey is actually registered by trying to register it again
-                let verify_result = RegisterHotKey(
-                    std::ptr::null_mut(),
-                    (hotkey.id + 1000) as i32, // Use different ID for verification
-                    hotkey.modifiers,
-                    hotkey.vk_code,
-                );
-                
-                if verify_result == 0 {
-                    let verify_error = winapi::um::errhandlingapi::GetLastError();
-                    if verify_error == 1409 { // ERROR_HOTKEY_ALREADY_REGISTERED
-                        info!("🔍 Verification: Hotkey is properly registered (error 1409 expected)");
-                    } else {
-                        warn!("🔍 Verification: Unexpected error code: {}", verify_error);
-                    }
-                } else {
-                    warn!("🔍 Verification: Hotkey verification registered unexpectedly");
-                    // Unregister the verification hotkey
-                    UnregisterHotKey(std::ptr::null_mut(), (hotkey.id + 1000) as i32);
+    // A key came back up: completes double taps and holds; plain hotkeys ignore it
+    fn released(&mut self, backend_id: u32) {
+        let now = Instant::now();
+
+        if let Some(detector) = self.tap_detectors.get_mut(&backend_id) {
+            let interval = Duration::from_millis(self.double_tap_interval_ms.load(Ordering::Relaxed));
+            if detector.released(interval, now) {
+                let action = self.double_taps.lock().ok()
+                    .and_then(|double_taps| double_taps.get(&backend_id).map(|d| d.action.clone()));
+                if let Some(action) = action {
+                    info!("🔥 Double tap {} triggered: {}", backend_id, action);
+                    (self.callback)(&action);
                 }
             }
+            return;
         }
-        Ok(())
-    }
 
-    #[cfg(windows)]
-    fn unregister_system_hotkey(&self, id: u32) -> Result<()> {
-        unsafe {
-            let result = UnregisterHotKey(std::ptr::null_mut(), id as i32);
-            if result == 0 {
-                let error_code = winapi::um::errhandlingapi::GetLastError();
-                return Err(anyhow::anyhow!(
-                    "Failed to unregister hotkey (ID: {}). Error code: {}",
-                    id,
-                    error_code
-                ));
+        let threshold = Duration::from_millis(self.hold_threshold_ms.load(Ordering::Relaxed));
+        if self.holds.released(backend_id, threshold, now) {
+            let release_action = self.hotkeys.lock().ok()
+                .and_then(|hotkeys| hotkeys.get(&backend_id).and_then(|h| h.release_action.clone()));
+            if let Some(action) = release_action {
+                info!("🔥 Hotkey {} released after a hold: {}", backend_id, action);
+                (self.callback)(&action);
             }
         }
-        Ok(())
     }
 
-    #[cfg(not(windows))]
-    fn register_system_hotkey(&self, _hotkey: &ParsedHotkey) -> Result<()> {
-        Err(anyhow::anyhow!("Hotkey registration is only supported on Windows"))
-    }
+    fn apply_sequence_step(&mut self, step: SequenceStep) {
+        self.release_follow_ups();
+        match step {
+            SequenceStep::Waiting(next) => {
+                // Capture the possible next keys, plus Escape to back out
+                let mut grabs = next;
+                let escape = KeyChord { modifiers: Modifiers::default(), key: Key::Escape };
+                if !grabs.contains(&escape) {
+                    grabs.push(escape);
+                }
 
-    #[cfg(not(windows))]
-    fn unregister_system_hotkey(&self, _id: u32) -> Result<()> {
-        Err(anyhow::anyhow!("Hotkey unregistration is only supported on Windows"))
+                for (offset, chord) in grabs.into_iter().enumerate() {
+                    let backend_id = FOLLOW_UP_ID_BASE + offset as u32;
+                    match self.backend.register(backend_id, &chord) {
+                        Ok(_) => {
+                            self.follow_ups.insert(backend_id, chord);
+                        }
+                        Err(e) => warn!("Cannot capture {:?} for a key sequence: {}", chord, e),
+                    }
+                }
+            }
+            SequenceStep::Matched(id) => self.fire(id),
+            SequenceStep::Cancelled => debug!("🎹 Key sequence cancelled"),
+        }
     }
 
-    pub fn get_registered_hotkeys(&self) -> Vec<ParsedHotkey> {
-        let hotkeys = self.registered_hotkeys.lock().unwrap();
-        hotkeys.values().cloned().collect()
+    fn release_follow_ups(&mut self) {
+        for (backend_id, _) in self.follow_ups.drain() {
+            if let Err(e) = self.backend.unregister(backend_id) {
+                debug!("Failed to release sequence key {}: {}", backend_id, e);
+            }
+        }
     }
 
-    pub fn is_hotkey_available(&self, hotkey_str: &str) -> bool {
-        match self.parse_hotkey_string(hotkey_str) {
-            Ok(parsed) => {
-                // Try to register temporarily to check availability
-                #[cfg(windows)]
-                unsafe {
-                    let temp_id = 9999; // Use a high ID for testing
-                    let result = RegisterHotKey(
-                        std::ptr::null_mut(),
-                        temp_id,
-                        parsed.modifiers,
-                        parsed.vk_code,
-                    );
-                    
-                    if result != 0 {
-                        // Successfully registered, so unregister immediately
-                        UnregisterHotKey(std::ptr::null_mut(), temp_id);
-                        true
-                    } else {
-                        false
-                    }
-                }
-                
-                #[cfg(not(windows))]
-                false
+    fn fire(&self, hotkey_id: u32) {
+        // Look up and release the lock before running the callback
+        let action = self.hotkeys.lock().ok()
+            .and_then(|hotkeys| hotkeys.get(&hotkey_id).map(|h| h.action.clone()));
+        match action {
+            Some(action) => {
+                info!("🔥 Hotkey {} triggered: {}", hotkey_id, action);
+                (self.callback)(&action);
             }
-            Err(_) => false,
+            None => warn!("No hotkey found for ID: {}", hotkey_id),
         }
     }
 }
 
 impl Drop for HotkeyService {
     fn drop(&mut self) {
-        // Unregister all hotkeys when service is dropped
-        let hotkey_ids: Vec<u32> = {
-            let hotkeys = self.registered_hotkeys.lock().unwrap();
-            hotkeys.keys().cloned().collect()
-        };
-
-        for id in hotkey_ids {
-            if let Err(e) = self.unregister_hotkey(id) {
-                error!("Failed to unregister hotkey {} during cleanup: {}", id, e);
-            }
+        // Stopping the loop drops its backend, which releases every OS registration
+        self.stop.store(true, Ordering::Relaxed);
+        self.commands.take();
+        if let Some(handle) = self.message_thread.take() {
+            let _ = handle.join();
         }
+        self.registered_hotkeys.lock().unwrap().clear();
 
         info!("Hotkey service cleanup completed");
     }
@@ -559,18 +975,31 @@ mod tests {
         
         // Test basic hotkey parsing
         let parsed = service.parse_hotkey_string("Ctrl+Alt+F1").unwrap();
-        assert_eq!(parsed.modifiers, (MOD_CONTROL | MOD_ALT) as u32);
-        assert_eq!(parsed.vk_code, 0x70); // F1
+        assert!(parsed.modifiers.ctrl && parsed.modifiers.alt && !parsed.modifiers.shift);
+        assert_eq!(parsed.modifiers.to_win32_flags(), 0x0002 | 0x0001); // MOD_CONTROL | MOD_ALT
+        assert_eq!(parsed.key, Key::Function(1));
+        assert_eq!(parsed.key.vk_code(), 0x70); // F1
         
         // Test single key
         let parsed = service.parse_hotkey_string("F12").unwrap();
-        assert_eq!(parsed.modifiers, 0);
-        assert_eq!(parsed.vk_code, 0x7B); // F12
+        assert!(parsed.modifiers.is_empty());
+        assert_eq!(parsed.key.vk_code(), 0x7B); // F12
         
         // Test letter key
         let parsed = service.parse_hotkey_string("Ctrl+A").unwrap();
-        assert_eq!(parsed.modifiers, MOD_CONTROL as u32);
-        assert_eq!(parsed.vk_code, 'A' as u32);
+        assert_eq!(parsed.modifiers.to_win32_flags(), 0x0002); // MOD_CONTROL
+        assert_eq!(parsed.key, Key::Letter('A'));
+        assert_eq!(parsed.hotkey, "Ctrl+A");
+
+        // Extended keys and canonical formatting
+        let parsed = service.parse_hotkey_string("shift+win+f13, numpad7, playpause").unwrap();
+        assert_eq!(parsed.key, Key::Function(13));
+        assert_eq!(parsed.sequence[1].key, Key::MediaPlayPause);
+        assert_eq!(parsed.to_hotkey_string(), "Shift+Win+F13, Numpad7, MediaPlayPause");
+        assert_eq!(parsed.display, parsed.to_hotkey_string());
+        assert_eq!(service.parse_hotkey_string("ctrl++").unwrap().display, "Ctrl+=");
+        let reparsed = service.parse_hotkey_string(&parsed.to_hotkey_string()).unwrap();
+        assert_eq!(reparsed.all_keys(), parsed.all_keys());
     }
 
     #[test]
@@ -579,7 +1008,7 @@ mod tests {
         
         assert_eq!(service.parse_key_code("F1").unwrap(), 0x70);
         assert_eq!(service.parse_key_code("A").unwrap(), 'A' as u32);
-        assert_eq!(service.parse_key_code("escape").unwrap(), VK_ESCAPE as u32);
+        assert_eq!(service.parse_key_code("escape").unwrap(), 0x1B); // VK_ESCAPE
         assert_eq!(service.parse_key_code("space").unwrap(), 0x20);
         
         // Test case insensitivity
@@ -600,6 +1029,286 @@ mod tests {
         assert_eq!(result, "F1");
     }
 
+    #[test]
+    fn test_message_loop_dispatches_backend_presses() {
+        let backend = hotkey_backend::MemoryHotkeyBackend::default();
+        backend.take(keys::parse_chord("Ctrl+Alt+T").unwrap());
+
+        let mut service = HotkeyService::with_backend(backend.factory()).unwrap();
+        let (tx, rx) = std::sync::mpsc::channel();
+        let tx = Mutex::new(tx);
+        service.set_callback(Arc::new(move |action: &str| {
+            let _ = tx.lock().unwrap().send(action.to_string());
+        }));
+
+        assert!(service.is_hotkey_available("F11"));
+        assert!(!service.is_hotkey_available("Ctrl+Alt+T"));
+        assert!(!service.is_hotkey_available("Hyper+Q"));
+
+        let f11 = service.register_hotkey("F11", "toggle_overlay".to_string()).unwrap();
+        service.register_hotkey("Ctrl+Alt+T", "taken".to_string()).unwrap();
+        service.start_message_loop().unwrap();
+
+        backend.press(keys::parse_chord("F11").unwrap());
+        assert_eq!(rx.recv_timeout(Duration::from_secs(2)).unwrap(), "toggle_overlay");
+
+        // Only the chord the OS accepted was registered
+        let registered = backend.registered();
+        assert_eq!(registered.len(), 1);
+        assert!(registered.contains_key(&f11));
+
+        // Dropping the service stops the loop
+        drop(service);
+        backend.press(keys::parse_chord("F11").unwrap());
+        assert!(rx.recv_timeout(Duration::from_millis(200)).is_err());
+    }
+
+    #[test]
+    fn test_runtime_registration_reports_os_results() {
+        let backend = hotkey_backend::MemoryHotkeyBackend::default();
+        backend.take(keys::parse_chord("Ctrl+Alt+T").unwrap());
+
+        let mut service = HotkeyService::with_backend(backend.factory()).unwrap();
+        let (tx, rx) = std::sync::mpsc::channel();
+        let tx = Mutex::new(tx);
+        service.set_callback(Arc::new(move |action: &str| {
+            let _ = tx.lock().unwrap().send(action.to_string());
+        }));
+        service.start_message_loop().unwrap();
+
+        // Added after start: live immediately, and OS refusals reach the caller
+        let id = service.register_hotkey("F9", "toggle_overlay".to_string()).unwrap();
+        assert!(backend.registered().contains_key(&id));
+        assert!(service.register_hotkey("Ctrl+Alt+T", "taken".to_string()).is_err());
+        assert_eq!(service.get_registered_hotkeys().len(), 1);
+
+        // A refused replacement keeps the old combination
+        assert!(service.replace_hotkey(id, "Ctrl+Alt+T").is_err());
+        assert_eq!(backend.registered()[&id], keys::parse_chord("F9").unwrap());
+
+        service.replace_hotkey(id, "F10").unwrap();
+        backend.press(keys::parse_chord("F10").unwrap());
+        assert_eq!(rx.recv_timeout(Duration::from_secs(2)).unwrap(), "toggle_overlay");
+
+        service.unregister_hotkey(id).unwrap();
+        assert!(backend.registered().is_empty());
+        assert!(service.unregister_hotkey(id).is_err());
+    }
+
+    #[test]
+    fn test_key_sequences_dispatch_through_leader() {
+        let backend = hotkey_backend::MemoryHotkeyBackend::default();
+        let mut service = HotkeyService::with_backend(backend.factory()).unwrap();
+        let (tx, rx) = std::sync::mpsc::channel();
+        let tx = Mutex::new(tx);
+        service.set_callback(Arc::new(move |action: &str| {
+            let _ = tx.lock().unwrap().send(action.to_string());
+        }));
+        service.start_message_loop().unwrap();
+
+        service.register_hotkey("Ctrl+Space, G, P", "run_p".to_string()).unwrap();
+        let s = service.register_hotkey("Ctrl+Space, G, S", "run_s".to_string()).unwrap();
+        assert!(service.register_hotkey("Ctrl+Space", "plain".to_string()).is_err());
+        assert!(service.register_hotkey("Ctrl+Space, G", "prefix".to_string()).is_err());
+        // Both sequences share one OS registration for the leader
+        assert_eq!(backend.registered().len(), 1);
+
+        let chord = |name: &str| keys::parse_chord(name).unwrap();
+        let wait_for_grab = |name: &str| {
+            let deadline = Instant::now() + Duration::from_secs(2);
+            while !backend.registered().values().any(|c| *c == chord(name)) {
+                assert!(Instant::now() < deadline, "{} was never captured", name);
+                thread::sleep(Duration::from_millis(5));
+            }
+        };
+
+        backend.press(chord("Ctrl+Space"));
+        wait_for_grab("G");
+        backend.press(chord("G"));
+        wait_for_grab("P");
+        backend.press(chord("P"));
+        assert_eq!(rx.recv_timeout(Duration::from_secs(2)).unwrap(), "run_p");
+
+        // Escape backs out and releases the captured keys
+        backend.press(chord("Ctrl+Space"));
+        wait_for_grab("Escape");
+        backend.press(chord("Escape"));
+        backend.press(chord("G"));
+        assert!(rx.recv_timeout(Duration::from_millis(200)).is_err());
+        assert_eq!(backend.registered().len(), 1);
+
+        // The sequence times out when the next key is too slow
+        service.set_sequence_timeout(Duration::from_millis(100));
+        backend.press(chord("Ctrl+Space"));
+        wait_for_grab("G");
+        thread::sleep(Duration::from_millis(300));
+        assert_eq!(backend.registered().len(), 1);
+
+        service.unregister_all();
+        assert!(backend.registered().is_empty());
+        assert!(service.unregister_hotkey(s).is_err());
+    }
+
+    #[test]
+    fn test_hold_and_double_tap_summon() {
+        use crate::modules::config::SummonMode;
+
+        let backend = hotkey_backend::MemoryHotkeyBackend::default();
+        let mut service = HotkeyService::with_backend(backend.factory()).unwrap();
+        let (tx, rx) = std::sync::mpsc::channel();
+        let tx = Mutex::new(tx);
+        service.set_callback(Arc::new(move |action: &str| {
+            let _ = tx.lock().unwrap().send(action.to_string());
+        }));
+        service.start_message_loop().unwrap();
+
+        let mut config = QDeckConfig::default();
+        config.ui.summon.mode = SummonMode::Hold;
+        config.ui.summon.hold_threshold_ms = 100;
+        config.ui.summon.double_tap = Some("Ctrl".to_string());
+        config.ui.summon.double_tap_interval_ms = 1000;
+        let report = service.register_config(&config);
+        assert!(!report.has_conflicts());
+        assert_eq!(report.effective.last().unwrap().source, "ui.summon.double_tap");
+        assert!(service.get_registered_hotkeys()[0].release_action.is_some());
+
+        let f11 = keys::parse_chord("F11").unwrap();
+        let next = || rx.recv_timeout(Duration::from_secs(2)).unwrap();
+
+        // A hold shows the overlay on press and lets go of it on release
+        backend.key_down(f11);
+        assert_eq!(next(), "toggle_overlay");
+        thread::sleep(Duration::from_millis(150));
+        backend.key_up(f11);
+        assert_eq!(next(), "release_overlay");
+
+        // A quick tap only toggles
+        backend.press(f11);
+        assert_eq!(next(), "toggle_overlay");
+        assert!(rx.recv_timeout(Duration::from_millis(200)).is_err());
+
+        // Ctrl Ctrl toggles; Ctrl used in a chord does not count as a tap
+        backend.modifier(ModifierKey::Ctrl, true);
+        backend.key_down(keys::parse_chord("Ctrl+C").unwrap());
+        backend.modifier(ModifierKey::Ctrl, false);
+        backend.modifier(ModifierKey::Ctrl, true);
+        backend.modifier(ModifierKey::Ctrl, false);
+        assert!(rx.recv_timeout(Duration::from_millis(200)).is_err());
+        backend.modifier(ModifierKey::Ctrl, true);
+        backend.modifier(ModifierKey::Ctrl, false);
+        assert_eq!(next(), "toggle_overlay");
+
+        service.unregister_all();
+        assert!(backend.registered().is_empty());
+        assert!(backend.watched().is_empty());
+    }
+
+    #[test]
+    fn test_config_bindings_and_conflicts() {
+        use crate::modules::action::ActionConfig;
+        use crate::modules::config::{ActionButton, FolderConfig, Position, Profile};
+
+        let button = |id: &str, col: u32, hotkey: Option<&str>, action: ActionConfig| ActionButton {
+            id: id.to_string(),
+            position: Position { row: 1, col },
+            label: id.to_string(),
+            icon: None,
+            style: None,
+            hotkey: hotkey.map(str::to_string),
+            action,
+        };
+        let open = ActionConfig::Open { target: "/tmp".to_string(), verb: None, fallback_editor: None };
+
+        let mut config = QDeckConfig::default();
+        config.profiles[0].hotkey = Some("Ctrl+Alt+1".to_string());
+        config.profiles[0].pages[0].hotkey = Some("Ctrl+Space, M".to_string());
+        let folder_page = Page {
+            buttons: vec![button("nested", 1, Some("Ctrl+Space, N"), open.clone())],
+            ..Page::default()
+        };
+        config.profiles[0].pages[0].buttons = vec![
+            button("term", 1, Some("Ctrl+Alt+T"), open.clone()),
+            button("dup", 2, Some("ctrl+alt+t"), open.clone()),
+            button("summon", 3, Some("F11"), open.clone()),
+            button("prefix", 4, Some("Ctrl+Space"), open.clone()),
+            button("folder", 5, None, ActionConfig::Folder(FolderConfig { page: Some(Box::new(folder_page)), page_ref: None })),
+        ];
+        config.profiles.push(Profile { name: "Other".to_string(), ..Profile::default() });
+
+        let bindings = config_hotkey_bindings(&config);
+        let actions: Vec<&str> = bindings.iter().map(|b| b.action.as_str()).collect();
+        assert_eq!(actions, vec![
+            "toggle_overlay",
+            "switch_profile:Default",
+            "switch_page:Default/Main",
+            "run_button:term",
+            "run_button:dup",
+            "run_button:summon",
+            "run_button:prefix",
+            "run_button:nested",
+        ]);
+        assert_eq!(bindings[7].source, "profiles[0].pages[0].buttons[4].action.page.buttons[0].hotkey");
+
+        let conflicts = find_hotkey_conflicts(&bindings);
+        let losers: Vec<(&str, &str)> = conflicts.iter().map(|c| (c.source.as_str(), c.conflicts_with.as_str())).collect();
+        assert_eq!(losers, vec![
+            ("profiles[0].pages[0].buttons[1].hotkey", "profiles[0].pages[0].buttons[0].hotkey"),
+            ("profiles[0].pages[0].buttons[2].hotkey", "ui.summon.hotkeys[0]"),
+            ("profiles[0].pages[0].buttons[3].hotkey", "profiles[0].pages[0].hotkey"),
+        ]);
+        assert!(conflicts[2].message.contains("overlaps 'Ctrl+Space, M'"));
+    }
+
+    #[test]
+    fn test_register_config_reports_conflicts_and_fallbacks() {
+        use crate::modules::config::Profile;
+
+        let backend = hotkey_backend::MemoryHotkeyBackend::default();
+        backend.take(keys::parse_chord("F11").unwrap());
+        backend.take(keys::parse_chord("Ctrl+Alt+Q").unwrap());
+
+        let mut service = HotkeyService::with_backend(backend.factory()).unwrap();
+        service.set_callback(Arc::new(|_: &str| {}));
+        service.start_message_loop().unwrap();
+
+        let mut config = QDeckConfig::default();
+        config.ui.summon.fallback_hotkeys = vec!["Ctrl+Alt+Q".to_string(), "Alt+F12".to_string(), "Ctrl+F11".to_string()];
+        config.profiles[0].hotkey = Some("Ctrl+1".to_string());
+        config.profiles.push(Profile { name: "Twin".to_string(), hotkey: Some("Ctrl+1".to_string()), ..Profile::default() });
+        config.profiles.push(Profile { name: "Shadow".to_string(), hotkey: Some("F11".to_string()), ..Profile::default() });
+
+        let report = service.register_config(&config);
+        assert!(report.has_conflicts());
+
+        let duplicates: Vec<(&str, &str)> = report.duplicates.iter().map(|c| (c.source.as_str(), c.conflicts_with.as_str())).collect();
+        assert_eq!(duplicates, vec![("profiles[1].hotkey", "profiles[0].hotkey"), ("profiles[2].hotkey", "ui.summon.hotkeys[0]")]);
+
+        let unavailable: Vec<&str> = report.unavailable.iter().map(|u| u.source.as_str()).collect();
+        assert_eq!(unavailable, vec!["ui.summon.hotkeys[0]", "ui.summon.fallback_hotkeys[0]"]);
+
+        // The first fallback that works stands in for the summon key; the rest are not tried
+        let effective: Vec<(&str, &str)> = report.effective.iter().map(|e| (e.hotkey.as_str(), e.action.as_str())).collect();
+        assert_eq!(effective, vec![("Ctrl+1", "switch_profile:Default"), ("Alt+F12", "toggle_overlay")]);
+        assert_eq!(backend.registered().len(), 2);
+    }
+
+    #[test]
+    fn test_double_tap_does_not_replace_summon_fallbacks() {
+        let backend = hotkey_backend::MemoryHotkeyBackend::default();
+        backend.take(keys::parse_chord("F11").unwrap());
+
+        let mut service = HotkeyService::with_backend(backend.factory()).unwrap();
+        service.set_callback(Arc::new(|_: &str| {}));
+        service.start_message_loop().unwrap();
+
+        let mut config = QDeckConfig::default();
+        config.ui.summon.double_tap = Some("Ctrl".to_string());
+        let report = service.register_config(&config);
+
+        let sources: Vec<&str> = report.effective.iter().map(|e| e.source.as_str()).collect();
+        assert_eq!(sources, vec!["ui.summon.fallback_hotkeys[0]", "ui.summon.double_tap"]);
+    }
+
     #[test]
     fn test_hotkey_config() {
         let config = HotkeyConfig {
@@ -0,0 +1,726 @@
+// OS backends for global hotkeys
+use anyhow::Result;
+use std::sync::Arc;
+use std::time::Duration;
+
+use crate::modules::keys::{KeyChord, ModifierKey};
+
+/// A registered hotkey or watched modifier going down or coming back up
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HotkeyEvent {
+    Pressed(u32),
+    Released(u32),
+}
+
+/// Registers global hotkeys with the OS and reports presses and releases.
+///
+/// A backend is created on the hotkey thread and only used from there: Windows ties
+/// `RegisterHotKey` registrations to the calling thread's message queue.
+pub trait HotkeyBackend {
+    /// Make `chord` global under `id`; fails if the OS or another application has it
+    fn register(&mut self, id: u32, chord: &KeyChord) -> Result<()>;
+
+    /// Report presses and releases of `modifier` pressed on its own under `id`, without
+    /// taking the key away from other applications. The release is left out when another
+    /// key was pressed while the modifier was down, as in Ctrl+C.
+    fn watch_modifier(&mut self, id: u32, modifier: ModifierKey) -> Result<()>;
+
+    /// Drop a registration or modifier watch
+    fn unregister(&mut self, id: u32) -> Result<()>;
+
+    /// Wait up to `timeout` for hotkey events. A held key is reported once, not per auto-repeat.
+    fn poll_events(&mut self, timeout: Duration) -> Result<Vec<HotkeyEvent>>;
+}
+
+/// Turns raw key transitions into presses and releases of a lone modifier
+#[derive(Debug, Default)]
+struct LoneModifierFilter {
+    held: Option<ModifierKey>,
+    interrupted: bool,
+}
+
+impl LoneModifierFilter {
+    /// Feed one key transition (`modifier` is None for ordinary keys); returns the
+    /// modifier event to report, if any, with `true` for a press
+    fn key_event(&mut self, modifier: Option<ModifierKey>, down: bool) -> Option<(ModifierKey, bool)> {
+        match (modifier, down) {
+            (Some(modifier), true) if self.held == Some(modifier) => None, // Auto-repeat
+            (Some(modifier), true) if self.held.is_none() => {
+                self.held = Some(modifier);
+                self.interrupted = false;
+                Some((modifier, true))
+            }
+            (_, true) => {
+                // Another key joined in: this is a chord, not a tap
+                self.interrupted = self.held.is_some();
+                None
+            }
+            (Some(modifier), false) if self.held == Some(modifier) => {
+                self.held = None;
+                (!self.interrupted).then_some((modifier, false))
+            }
+            (_, false) => None,
+        }
+    }
+}
+
+/// Creates a backend on the thread that will drive it
+pub type HotkeyBackendFactory = Arc<dyn Fn() -> Result<Box<dyn HotkeyBackend>> + Send + Sync>;
+
+/// Backend factory for the current platform
+pub fn platform_backend_factory() -> HotkeyBackendFactory {
+    #[cfg(windows)]
+    {
+        Arc::new(|| Ok(Box::new(windows_backend::WindowsHotkeyBackend::new()) as Box<dyn HotkeyBackend>))
+    }
+
+    #[cfg(target_os = "linux")]
+    {
+        Arc::new(|| Ok(Box::new(x11_backend::X11HotkeyBackend::new()?) as Box<dyn HotkeyBackend>))
+    }
+
+    #[cfg(not(any(windows, target_os = "linux")))]
+    {
+        Arc::new(|| Err(anyhow::anyhow!("Global hotkeys are not supported on this platform")))
+    }
+}
+
+#[cfg(windows)]
+mod windows_backend {
+    use super::{HotkeyBackend, HotkeyEvent, LoneModifierFilter};
+    use crate::modules::keys::{KeyChord, ModifierKey};
+    use anyhow::Result;
+    use std::cell::RefCell;
+    use std::collections::{HashMap, HashSet};
+    use std::os::raw::c_int;
+    use std::sync::mpsc::{self, Receiver, Sender};
+    use std::thread::JoinHandle;
+    use std::time::Duration;
+    use tracing::{debug, warn};
+    use winapi::shared::minwindef::{DWORD, LPARAM, LRESULT, WPARAM};
+    use winapi::um::errhandlingapi::GetLastError;
+    use winapi::um::libloaderapi::GetModuleHandleW;
+    use winapi::um::processthreadsapi::GetCurrentThreadId;
+    use winapi::um::winuser::{
+        CallNextHookEx, DispatchMessageW, GetAsyncKeyState, GetMessageW, MsgWaitForMultipleObjects, PeekMessageW,
+        PostThreadMessageW, RegisterHotKey, SetWindowsHookExW, TranslateMessage, UnhookWindowsHookEx, UnregisterHotKey,
+        KBDLLHOOKSTRUCT, MSG, PM_REMOVE, QS_ALLINPUT, WH_KEYBOARD_LL, WM_HOTKEY, WM_KEYDOWN, WM_NULL, WM_QUIT,
+        WM_SYSKEYDOWN,
+    };
+
+    const ERROR_HOTKEY_ALREADY_REGISTERED: u32 = 1409;
+    const ALL_MODIFIERS: [ModifierKey; 4] = [ModifierKey::Ctrl, ModifierKey::Alt, ModifierKey::Shift, ModifierKey::Meta];
+
+    // Where the keyboard hook sends lone-modifier events; set on the hook thread only
+    struct HookSink {
+        filter: LoneModifierFilter,
+        events: Sender<(ModifierKey, bool)>,
+        owner_thread: DWORD, // Hotkey thread, woken with WM_NULL when an event is queued
+    }
+
+    thread_local! {
+        static HOOK_SINK: RefCell<Option<HookSink>> = const { RefCell::new(None) };
+    }
+
+    unsafe extern "system" fn keyboard_hook(code: c_int, wparam: WPARAM, lparam: LPARAM) -> LRESULT {
+        if code >= 0 {
+            let info = &*(lparam as *const KBDLLHOOKSTRUCT);
+            let down = matches!(wparam as u32, WM_KEYDOWN | WM_SYSKEYDOWN);
+            let modifier = ALL_MODIFIERS.into_iter().find(|m| m.vk_codes().contains(&info.vkCode));
+            HOOK_SINK.with(|sink| {
+                if let Some(sink) = sink.borrow_mut().as_mut() {
+                    if let Some(event) = sink.filter.key_event(modifier, down) {
+                        if sink.events.send(event).is_ok() {
+                            PostThreadMessageW(sink.owner_thread, WM_NULL, 0, 0);
+                        }
+                    }
+                }
+            });
+        }
+        // Observe only: the key still reaches the focused application
+        CallNextHookEx(std::ptr::null_mut(), code, wparam, lparam)
+    }
+
+    /// The low-level keyboard hook on a thread that does nothing but pump messages. Windows
+    /// waits on the hook for every key press system-wide and silently removes hooks that
+    /// miss `LowLevelHooksTimeout`, so it must not share a thread with hotkey callbacks.
+    struct HookThread {
+        thread_id: DWORD,
+        handle: Option<JoinHandle<()>>,
+        events: Receiver<(ModifierKey, bool)>,
+    }
+
+    impl HookThread {
+        fn spawn() -> Result<Self> {
+            let owner_thread = unsafe { GetCurrentThreadId() };
+            let (event_tx, event_rx) = mpsc::channel();
+            let (ready_tx, ready_rx) = mpsc::channel();
+
+            let handle = std::thread::Builder::new()
+                .name("hotkey-keyboard-hook".to_string())
+                .spawn(move || unsafe {
+                    HOOK_SINK.with(|sink| {
+                        *sink.borrow_mut() = Some(HookSink {
+                            filter: LoneModifierFilter::default(),
+                            events: event_tx,
+                            owner_thread,
+                        })
+                    });
+
+                    let hook = SetWindowsHookExW(WH_KEYBOARD_LL, Some(keyboard_hook), GetModuleHandleW(std::ptr::null()), 0);
+                    if hook.is_null() {
+                        let _ = ready_tx.send(Err(GetLastError()));
+                        return;
+                    }
+                    // The hook call created this thread's message queue, so WM_QUIT can reach it
+                    let _ = ready_tx.send(Ok(GetCurrentThreadId()));
+
+                    let mut msg: MSG = std::mem::zeroed();
+                    while GetMessageW(&mut msg, std::ptr::null_mut(), 0, 0) > 0 {
+                        TranslateMessage(&msg);
+                        DispatchMessageW(&msg);
+                    }
+                    UnhookWindowsHookEx(hook);
+                })?;
+
+            match ready_rx.recv() {
+                Ok(Ok(thread_id)) => Ok(Self { thread_id, handle: Some(handle), events: event_rx }),
+                Ok(Err(error_code)) => {
+                    let _ = handle.join();
+                    Err(anyhow::anyhow!("SetWindowsHookExW failed with error code {}", error_code))
+                }
+                Err(_) => Err(anyhow::anyhow!("Keyboard hook thread exited during startup")),
+            }
+        }
+    }
+
+    impl Drop for HookThread {
+        fn drop(&mut self) {
+            unsafe { PostThreadMessageW(self.thread_id, WM_QUIT, 0, 0) };
+            if let Some(handle) = self.handle.take() {
+                if handle.join().is_err() {
+                    warn!("Keyboard hook thread panicked");
+                }
+            }
+        }
+    }
+
+    /// `RegisterHotKey` on the owning thread's message queue. Releases are detected by polling
+    /// the key state; lone modifiers are watched with a low-level keyboard hook on its own thread.
+    pub struct WindowsHotkeyBackend {
+        registered: HashMap<u32, u32>, // id -> virtual-key code
+        held: HashSet<u32>,            // Hotkeys pressed and not yet released
+        watched: HashMap<u32, ModifierKey>,
+        hook: Option<HookThread>,
+    }
+
+    impl WindowsHotkeyBackend {
+        pub fn new() -> Self {
+            Self {
+                registered: HashMap::new(),
+                held: HashSet::new(),
+                watched: HashMap::new(),
+                hook: None,
+            }
+        }
+
+        fn remove_hook(&mut self) {
+            // Dropping the hook thread unhooks and joins it
+            self.hook = None;
+        }
+    }
+
+    impl HotkeyBackend for WindowsHotkeyBackend {
+        fn register(&mut self, id: u32, chord: &KeyChord) -> Result<()> {
+            let vk = chord.key.vk_code();
+            let result = unsafe {
+                RegisterHotKey(std::ptr::null_mut(), id as i32, chord.modifiers.to_win32_flags(), vk)
+            };
+            if result == 0 {
+                let error_code = unsafe { GetLastError() };
+                return Err(if error_code == ERROR_HOTKEY_ALREADY_REGISTERED {
+                    anyhow::anyhow!("Hotkey is already registered by another application")
+                } else {
+                    anyhow::anyhow!("RegisterHotKey failed with error code {}", error_code)
+                });
+            }
+
+            self.registered.insert(id, vk);
+            Ok(())
+        }
+
+        fn watch_modifier(&mut self, id: u32, modifier: ModifierKey) -> Result<()> {
+            if self.hook.is_none() {
+                self.hook = Some(HookThread::spawn()?);
+            }
+            self.watched.insert(id, modifier);
+            Ok(())
+        }
+
+        fn unregister(&mut self, id: u32) -> Result<()> {
+            if self.watched.remove(&id).is_some() {
+                if self.watched.is_empty() {
+                    self.remove_hook();
+                }
+                return Ok(());
+            }
+
+            self.registered.remove(&id);
+            self.held.remove(&id);
+            if unsafe { UnregisterHotKey(std::ptr::null_mut(), id as i32) } == 0 {
+                let error_code = unsafe { GetLastError() };
+                return Err(anyhow::anyhow!("UnregisterHotKey failed with error code {}", error_code));
+            }
+            Ok(())
+        }
+
+        fn poll_events(&mut self, timeout: Duration) -> Result<Vec<HotkeyEvent>> {
+            let mut events = Vec::new();
+            unsafe {
+                MsgWaitForMultipleObjects(0, std::ptr::null(), 0, timeout.as_millis() as u32, QS_ALLINPUT);
+
+                let mut msg: MSG = std::mem::zeroed();
+                while PeekMessageW(&mut msg, std::ptr::null_mut(), 0, 0, PM_REMOVE) != 0 {
+                    if msg.message == WM_HOTKEY {
+                        let id = msg.wParam as u32;
+                        // WM_HOTKEY repeats while the key is held
+                        if self.held.insert(id) {
+                            debug!("🔥 WM_HOTKEY: ID {}", id);
+                            events.push(HotkeyEvent::Pressed(id));
+                        }
+                    } else {
+                        TranslateMessage(&msg);
+                        DispatchMessageW(&msg);
+                    }
+                }
+            }
+
+            // WM_HOTKEY has no key-up counterpart
+            let registered = &self.registered;
+            self.held.retain(|id| {
+                let down = registered.get(id)
+                    .map(|vk| unsafe { GetAsyncKeyState(*vk as c_int) } as u16 & 0x8000 != 0)
+                    .unwrap_or(false);
+                if !down {
+                    events.push(HotkeyEvent::Released(*id));
+                }
+                down
+            });
+
+            let modifier_events: Vec<_> = self.hook.as_ref()
+                .map(|hook| hook.events.try_iter().collect())
+                .unwrap_or_default();
+            for (modifier, pressed) in modifier_events {
+                for (id, _) in self.watched.iter().filter(|(_, watched)| **watched == modifier) {
+                    events.push(if pressed { HotkeyEvent::Pressed(*id) } else { HotkeyEvent::Released(*id) });
+                }
+            }
+            Ok(events)
+        }
+    }
+
+    impl Drop for WindowsHotkeyBackend {
+        fn drop(&mut self) {
+            self.remove_hook();
+            for (id, _) in self.registered.drain() {
+                if unsafe { UnregisterHotKey(std::ptr::null_mut(), id as i32) } == 0 {
+                    warn!("Failed to unregister hotkey {} during cleanup", id);
+                }
+            }
+        }
+    }
+}
+
+#[cfg(target_os = "linux")]
+mod x11_backend {
+    use super::{HotkeyBackend, HotkeyEvent, LoneModifierFilter};
+    use crate::modules::keys::{KeyChord, ModifierKey, Modifiers};
+    use crate::modules::x11::XConnection;
+    use anyhow::Result;
+    use std::collections::{HashMap, HashSet};
+    use std::ffi::CString;
+    use std::os::raw::{c_char, c_int, c_uint, c_ulong};
+    use std::time::{Duration, Instant};
+    use tracing::debug;
+    use x11_dl::xlib;
+
+    // Lock modifiers that must not stop a hotkey from firing: CapsLock and NumLock (Mod2)
+    const IGNORED_MASKS: [c_uint; 4] = [0, xlib::LockMask, xlib::Mod2Mask, xlib::LockMask | xlib::Mod2Mask];
+    const RELEVANT_MASK: c_uint = xlib::ShiftMask | xlib::ControlMask | xlib::Mod1Mask | xlib::Mod4Mask;
+
+    /// `XGrabKey` on the root window of the default screen. Lone modifiers are watched by
+    /// polling `XQueryKeymap`, which leaves the keys to other applications.
+    pub struct X11HotkeyBackend {
+        connection: XConnection,
+        root: c_ulong,
+        grabs: HashMap<u32, (c_int, c_uint)>, // id -> (keycode, modifier mask)
+        held: HashSet<u32>,                   // Hotkeys pressed and not yet released
+        watched: HashMap<u32, ModifierKey>,
+        modifier_keycodes: HashMap<c_int, ModifierKey>,
+        keymap: [c_char; 32], // Last XQueryKeymap snapshot
+        modifier_filter: LoneModifierFilter,
+    }
+
+    impl X11HotkeyBackend {
+        pub fn new() -> Result<Self> {
+            let connection = XConnection::open()?;
+            // Without this, a held key sends a release before every auto-repeated press
+            unsafe { (connection.xlib.XkbSetDetectableAutoRepeat)(connection.display, xlib::True, std::ptr::null_mut()) };
+            Ok(Self {
+                root: connection.root_window(),
+                connection,
+                grabs: HashMap::new(),
+                held: HashSet::new(),
+                watched: HashMap::new(),
+                modifier_keycodes: HashMap::new(),
+                keymap: [0; 32],
+                modifier_filter: LoneModifierFilter::default(),
+            })
+        }
+
+        fn modifier_mask(modifiers: &Modifiers) -> c_uint {
+            let mut mask = 0;
+            if modifiers.shift {
+                mask |= xlib::ShiftMask;
+            }
+            if modifiers.ctrl {
+                mask |= xlib::ControlMask;
+            }
+            if modifiers.alt {
+                mask |= xlib::Mod1Mask;
+            }
+            if modifiers.meta {
+                mask |= xlib::Mod4Mask;
+            }
+            mask
+        }
+
+        fn keysym_keycode(&self, name: &str) -> Option<c_int> {
+            let name = CString::new(name).ok()?;
+            let keysym = unsafe { (self.connection.xlib.XStringToKeysym)(name.as_ptr()) };
+            if keysym == xlib::NoSymbol as c_ulong {
+                return None;
+            }
+            match unsafe { (self.connection.xlib.XKeysymToKeycode)(self.connection.display, keysym) } {
+                0 => None,
+                keycode => Some(keycode as c_int),
+            }
+        }
+
+        fn keycode(&self, chord: &KeyChord) -> Result<c_int> {
+            let name = CString::new(chord.key.keysym_name())?;
+            let keysym = unsafe { (self.connection.xlib.XStringToKeysym)(name.as_ptr()) };
+            if keysym == xlib::NoSymbol as c_ulong {
+                return Err(anyhow::anyhow!("No X keysym for key {:?}", chord.key));
+            }
+
+            let keycode = unsafe { (self.connection.xlib.XKeysymToKeycode)(self.connection.display, keysym) };
+            if keycode == 0 {
+                return Err(anyhow::anyhow!("Key {:?} is not on the current keyboard layout", chord.key));
+            }
+            Ok(keycode as c_int)
+        }
+
+        fn ungrab(&self, keycode: c_int, mask: c_uint) {
+            let _ = self.connection.checked(|connection| {
+                for extra in IGNORED_MASKS {
+                    unsafe { (connection.xlib.XUngrabKey)(connection.display, keycode, mask | extra, self.root) };
+                }
+            });
+        }
+
+        fn query_keymap(&self) -> [c_char; 32] {
+            let mut keymap = [0; 32];
+            unsafe { (self.connection.xlib.XQueryKeymap)(self.connection.display, keymap.as_mut_ptr()) };
+            keymap
+        }
+
+        // Compare the keyboard state with the last snapshot and report lone modifier taps
+        fn poll_modifiers(&mut self, events: &mut Vec<HotkeyEvent>) {
+            let keymap = self.query_keymap();
+            for (byte, (now, before)) in keymap.iter().zip(self.keymap.iter()).enumerate() {
+                let changed = (*now ^ *before) as u8;
+                for bit in (0..8).filter(|bit| changed & (1 << bit) != 0) {
+                    let keycode = (byte * 8 + bit) as c_int;
+                    let down = *now as u8 & (1 << bit) != 0;
+                    let modifier = self.modifier_keycodes.get(&keycode).copied();
+                    if let Some((modifier, pressed)) = self.modifier_filter.key_event(modifier, down) {
+                        for (id, _) in self.watched.iter().filter(|(_, watched)| **watched == modifier) {
+                            events.push(if pressed { HotkeyEvent::Pressed(*id) } else { HotkeyEvent::Released(*id) });
+                        }
+                    }
+                }
+            }
+            self.keymap = keymap;
+        }
+    }
+
+    impl HotkeyBackend for X11HotkeyBackend {
+        fn register(&mut self, id: u32, chord: &KeyChord) -> Result<()> {
+            let keycode = self.keycode(chord)?;
+            let mask = Self::modifier_mask(&chord.modifiers);
+
+            // X silently lets a client re-grab its own keys, so catch duplicates here
+            if self.grabs.values().any(|grab| *grab == (keycode, mask)) {
+                return Err(anyhow::anyhow!("Hotkey is already registered"));
+            }
+
+            let result = self.connection.checked(|connection| {
+                for extra in IGNORED_MASKS {
+                    unsafe {
+                        (connection.xlib.XGrabKey)(
+                            connection.display, keycode, mask | extra, self.root,
+                            xlib::False, xlib::GrabModeAsync, xlib::GrabModeAsync,
+                        )
+                    };
+                }
+            });
+
+            match result {
+                Ok(()) => {
+                    self.grabs.insert(id, (keycode, mask));
+                    Ok(())
+                }
+                Err(code) => {
+                    // Some variants may have been grabbed before the failure
+                    self.ungrab(keycode, mask);
+                    Err(if code == xlib::BadAccess {
+                        anyhow::anyhow!("Hotkey is already grabbed by another application")
+                    } else {
+                        anyhow::anyhow!("XGrabKey failed with X error {}", code)
+                    })
+                }
+            }
+        }
+
+        fn watch_modifier(&mut self, id: u32, modifier: ModifierKey) -> Result<()> {
+            let keycodes: Vec<c_int> = modifier.keysym_names().iter().filter_map(|name| self.keysym_keycode(name)).collect();
+            if keycodes.is_empty() {
+                return Err(anyhow::anyhow!("{} is not on the current keyboard layout", modifier));
+            }
+            for keycode in keycodes {
+                self.modifier_keycodes.insert(keycode, modifier);
+            }
+
+            if self.watched.is_empty() {
+                self.keymap = self.query_keymap();
+                self.modifier_filter = LoneModifierFilter::default();
+            }
+            self.watched.insert(id, modifier);
+            Ok(())
+        }
+
+        fn unregister(&mut self, id: u32) -> Result<()> {
+            if self.watched.remove(&id).is_some() {
+                return Ok(());
+            }
+
+            let (keycode, mask) = self.grabs.remove(&id)
+                .ok_or_else(|| anyhow::anyhow!("Hotkey with ID {} is not registered", id))?;
+            self.held.remove(&id);
+            self.ungrab(keycode, mask);
+            Ok(())
+        }
+
+        fn poll_events(&mut self, timeout: Duration) -> Result<Vec<HotkeyEvent>> {
+            let deadline = Instant::now() + timeout;
+            let mut events = Vec::new();
+
+            loop {
+                while unsafe { (self.connection.xlib.XPending)(self.connection.display) } > 0 {
+                    let mut event: xlib::XEvent = unsafe { std::mem::zeroed() };
+                    unsafe { (self.connection.xlib.XNextEvent)(self.connection.display, &mut event) };
+                    match event.get_type() {
+                        xlib::KeyPress => {
+                            let key = unsafe { event.key };
+                            let pressed = (key.keycode as c_int, key.state & RELEVANT_MASK);
+                            if let Some((&id, _)) = self.grabs.iter().find(|(_, grab)| **grab == pressed) {
+                                if self.held.insert(id) {
+                                    debug!("🔥 X11 hotkey: ID {}", id);
+                                    events.push(HotkeyEvent::Pressed(id));
+                                }
+                            }
+                        }
+                        xlib::KeyRelease => {
+                            // The modifiers may already be up, so match on the key alone
+                            let keycode = unsafe { event.key }.keycode as c_int;
+                            let grabs = &self.grabs;
+                            self.held.retain(|id| {
+                                let released = grabs.get(id).map(|(code, _)| *code == keycode).unwrap_or(true);
+                                if released {
+                                    events.push(HotkeyEvent::Released(*id));
+                                }
+                                !released
+                            });
+                        }
+                        _ => {}
+                    }
+                }
+
+                if !self.watched.is_empty() {
+                    self.poll_modifiers(&mut events);
+                }
+
+                let now = Instant::now();
+                if !events.is_empty() || now >= deadline {
+                    return Ok(events);
+                }
+                std::thread::sleep((deadline - now).min(Duration::from_millis(10)));
+            }
+        }
+    }
+
+    impl Drop for X11HotkeyBackend {
+        fn drop(&mut self) {
+            for (_, (keycode, mask)) in self.grabs.drain().collect::<Vec<_>>() {
+                self.ungrab(keycode, mask);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+pub use memory::MemoryHotkeyBackend;
+
+#[cfg(test)]
+mod memory {
+    use super::{HotkeyBackend, HotkeyBackendFactory, HotkeyEvent, LoneModifierFilter};
+    use crate::modules::keys::{KeyChord, ModifierKey};
+    use anyhow::Result;
+    use std::collections::{HashMap, HashSet, VecDeque};
+    use std::sync::{Arc, Condvar, Mutex};
+    use std::time::Duration;
+
+    enum Input {
+        Down(KeyChord),
+        Up(KeyChord),
+        Modifier(ModifierKey, bool),
+    }
+
+    #[derive(Default)]
+    struct MemoryState {
+        registered: HashMap<u32, KeyChord>,
+        watched: HashMap<u32, ModifierKey>,
+        taken: HashSet<KeyChord>, // Chords "owned by other applications"
+        pending: VecDeque<Input>,
+        held: HashSet<u32>,
+        modifier_filter: LoneModifierFilter,
+    }
+
+    /// In-memory stand-in for the OS: records registrations and replays synthetic key events.
+    /// Clones share state, so a test can keep one handle while the service owns another.
+    #[derive(Clone, Default)]
+    pub struct MemoryHotkeyBackend {
+        state: Arc<(Mutex<MemoryState>, Condvar)>,
+    }
+
+    impl MemoryHotkeyBackend {
+        pub fn factory(&self) -> HotkeyBackendFactory {
+            let backend = self.clone();
+            Arc::new(move || Ok(Box::new(backend.clone()) as Box<dyn HotkeyBackend>))
+        }
+
+        /// Pretend another application holds `chord`
+        pub fn take(&self, chord: KeyChord) {
+            self.state.0.lock().unwrap().taken.insert(chord);
+        }
+
+        fn input(&self, inputs: impl IntoIterator<Item = Input>) {
+            let (state, signal) = &*self.state;
+            state.lock().unwrap().pending.extend(inputs);
+            signal.notify_all();
+        }
+
+        /// Simulate the user pressing and releasing `chord`
+        pub fn press(&self, chord: KeyChord) {
+            self.input([Input::Down(chord), Input::Up(chord)]);
+        }
+
+        pub fn key_down(&self, chord: KeyChord) {
+            self.input([Input::Down(chord)]);
+        }
+
+        pub fn key_up(&self, chord: KeyChord) {
+            self.input([Input::Up(chord)]);
+        }
+
+        /// Simulate a modifier going down (`true`) or up on its own
+        pub fn modifier(&self, modifier: ModifierKey, down: bool) {
+            self.input([Input::Modifier(modifier, down)]);
+        }
+
+        pub fn registered(&self) -> HashMap<u32, KeyChord> {
+            self.state.0.lock().unwrap().registered.clone()
+        }
+
+        pub fn watched(&self) -> HashMap<u32, ModifierKey> {
+            self.state.0.lock().unwrap().watched.clone()
+        }
+    }
+
+    impl HotkeyBackend for MemoryHotkeyBackend {
+        fn register(&mut self, id: u32, chord: &KeyChord) -> Result<()> {
+            let mut state = self.state.0.lock().unwrap();
+            if state.taken.contains(chord) || state.registered.values().any(|c| c == chord) {
+                return Err(anyhow::anyhow!("Hotkey is already registered by another application"));
+            }
+            state.registered.insert(id, *chord);
+            Ok(())
+        }
+
+        fn watch_modifier(&mut self, id: u32, modifier: ModifierKey) -> Result<()> {
+            self.state.0.lock().unwrap().watched.insert(id, modifier);
+            Ok(())
+        }
+
+        fn unregister(&mut self, id: u32) -> Result<()> {
+            let mut state = self.state.0.lock().unwrap();
+            state.held.remove(&id);
+            if state.watched.remove(&id).is_some() {
+                return Ok(());
+            }
+            state.registered.remove(&id)
+                .map(|_| ())
+                .ok_or_else(|| anyhow::anyhow!("Hotkey with ID {} is not registered", id))
+        }
+
+        fn poll_events(&mut self, timeout: Duration) -> Result<Vec<HotkeyEvent>> {
+            let (state, signal) = &*self.state;
+            let guard = state.lock().unwrap();
+            let (mut guard, _) = signal.wait_timeout_while(guard, timeout, |s| s.pending.is_empty()).unwrap();
+            let state = &mut *guard;
+
+            let mut events = Vec::new();
+            while let Some(input) = state.pending.pop_front() {
+                let (modifier, down) = match &input {
+                    Input::Down(_) => (None, true),
+                    Input::Up(_) => (None, false),
+                    Input::Modifier(modifier, down) => (Some(*modifier), *down),
+                };
+                if let Some((modifier, pressed)) = state.modifier_filter.key_event(modifier, down) {
+                    for (id, _) in state.watched.iter().filter(|(_, watched)| **watched == modifier) {
+                        events.push(if pressed { HotkeyEvent::Pressed(*id) } else { HotkeyEvent::Released(*id) });
+                    }
+                }
+
+                let chord_id = |chord: &KeyChord| state.registered.iter().find(|(_, c)| *c == chord).map(|(id, _)| *id);
+                match input {
+                    Input::Down(chord) => {
+                        if let Some(id) = chord_id(&chord).filter(|id| !state.held.contains(id)) {
+                            state.held.insert(id);
+                            events.push(HotkeyEvent::Pressed(id));
+                        }
+                    }
+                    Input::Up(chord) => {
+                        if let Some(id) = chord_id(&chord).filter(|id| state.held.contains(id)) {
+                            state.held.remove(&id);
+                            events.push(HotkeyEvent::Released(id));
+                        }
+                    }
+                    Input::Modifier(..) => {}
+                }
+            }
+            Ok(events)
+        }
+    }
+}
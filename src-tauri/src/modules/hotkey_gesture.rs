@@ -0,0 +1,133 @@
+// Timing for hold-to-show hotkeys and double-tapped modifiers
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
+
+/// Default press length after which releasing a hold hotkey counts as letting go
+pub const DEFAULT_HOLD_THRESHOLD_MS: u64 = 300;
+
+/// Default time allowed for a double tap: each tap and the gap between them
+pub const DEFAULT_DOUBLE_TAP_INTERVAL_MS: u64 = 300;
+
+/// Remembers when hold hotkeys went down. Time is passed in by the caller.
+#[derive(Debug, Default)]
+pub struct HoldTracker {
+    pressed: HashMap<u32, Instant>,
+}
+
+impl HoldTracker {
+    pub fn pressed(&mut self, id: u32, now: Instant) {
+        self.pressed.entry(id).or_insert(now);
+    }
+
+    /// The key came back up: true if it was held for at least `threshold`.
+    /// A shorter press is a tap and leaves whatever the press did in place.
+    pub fn released(&mut self, id: u32, threshold: Duration, now: Instant) -> bool {
+        match self.pressed.remove(&id) {
+            Some(pressed_at) => now.duration_since(pressed_at) >= threshold,
+            None => false,
+        }
+    }
+
+    pub fn forget(&mut self, id: u32) {
+        self.pressed.remove(&id);
+    }
+}
+
+/// Spots two quick taps of a lone modifier such as Ctrl Ctrl.
+///
+/// A tap is a press and release no longer than the interval, and the second tap has to
+/// start within the interval after the first one ended. Holding the key resets it.
+#[derive(Debug, Default)]
+pub struct DoubleTapDetector {
+    down_at: Option<Instant>,
+    last_tap: Option<Instant>, // When the previous tap was released
+}
+
+impl DoubleTapDetector {
+    pub fn pressed(&mut self, now: Instant) {
+        if self.down_at.is_some() {
+            // The release went missing (another key interrupted the tap): start over
+            self.last_tap = None;
+        }
+        self.down_at = Some(now);
+    }
+
+    /// The key came back up: true when this completes a double tap
+    pub fn released(&mut self, interval: Duration, now: Instant) -> bool {
+        let down_at = match self.down_at.take() {
+            Some(down_at) => down_at,
+            None => return false,
+        };
+        if now.duration_since(down_at) > interval {
+            self.last_tap = None;
+            return false;
+        }
+
+        match self.last_tap.take() {
+            Some(last_tap) if down_at.duration_since(last_tap) <= interval => true,
+            _ => {
+                self.last_tap = Some(now);
+                false
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MS: Duration = Duration::from_millis(1);
+
+    #[test]
+    fn test_hold_threshold() {
+        let start = Instant::now();
+        let mut holds = HoldTracker::default();
+
+        holds.pressed(1, start);
+        // Auto-repeat must not move the start of the hold
+        holds.pressed(1, start + 200 * MS);
+        assert!(holds.released(1, 300 * MS, start + 350 * MS));
+
+        holds.pressed(1, start);
+        assert!(!holds.released(1, 300 * MS, start + 100 * MS));
+        assert!(!holds.released(2, 300 * MS, start + 500 * MS));
+
+        holds.pressed(1, start);
+        holds.forget(1);
+        assert!(!holds.released(1, 300 * MS, start + 500 * MS));
+    }
+
+    #[test]
+    fn test_double_tap() {
+        let interval = 300 * MS;
+        let start = Instant::now();
+        let mut taps = DoubleTapDetector::default();
+
+        taps.pressed(start);
+        assert!(!taps.released(interval, start + 80 * MS));
+        taps.pressed(start + 200 * MS);
+        assert!(taps.released(interval, start + 260 * MS));
+
+        // Too slow between the taps: the second tap becomes the first of a new pair
+        taps.pressed(start);
+        taps.released(interval, start + 50 * MS);
+        taps.pressed(start + 500 * MS);
+        assert!(!taps.released(interval, start + 550 * MS));
+        taps.pressed(start + 600 * MS);
+        assert!(taps.released(interval, start + 650 * MS));
+
+        // Holding the modifier is not a tap
+        taps.pressed(start);
+        taps.released(interval, start + 50 * MS);
+        taps.pressed(start + 100 * MS);
+        assert!(!taps.released(interval, start + 900 * MS));
+
+        // A tap whose release was swallowed does not count
+        taps.pressed(start);
+        taps.released(interval, start + 50 * MS);
+        taps.pressed(start + 100 * MS);
+        taps.pressed(start + 150 * MS);
+        assert!(!taps.released(interval, start + 200 * MS));
+    }
+}
@@ -0,0 +1,172 @@
+// Leader-key sequences such as "Ctrl+Space, G, P"
+use std::time::{Duration, Instant};
+
+use crate::modules::keys::KeyChord;
+
+/// Default time allowed between the keys of a sequence
+pub const DEFAULT_SEQUENCE_TIMEOUT_MS: u64 = 1500;
+
+/// What the hotkey thread should do after feeding a key to the tracker
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SequenceStep {
+    /// Still armed: these keys can come next and need to be captured
+    Waiting(Vec<KeyChord>),
+    /// The sequence of this hotkey ID was typed in full
+    Matched(u32),
+    /// A key that continues no sequence was pressed, or nothing was armed
+    Cancelled,
+}
+
+#[derive(Debug)]
+struct Armed {
+    candidates: Vec<(u32, Vec<KeyChord>)>, // Hotkey ID and the keys that follow its leader
+    typed: usize,
+    timeout: Duration,
+    deadline: Instant,
+}
+
+/// State machine for the keys typed after a leader chord.
+///
+/// The hotkey thread arms it when a leader fires, captures the keys it is waiting for and
+/// feeds them back in. Each key restarts the timeout. Time is passed in by the caller.
+#[derive(Debug, Default)]
+pub struct SequenceTracker {
+    armed: Option<Armed>,
+}
+
+impl SequenceTracker {
+    pub fn is_armed(&self) -> bool {
+        self.armed.is_some()
+    }
+
+    /// The leader chord fired: wait for the remaining keys of `candidates`
+    pub fn arm(&mut self, mut candidates: Vec<(u32, Vec<KeyChord>)>, timeout: Duration, now: Instant) -> SequenceStep {
+        candidates.retain(|(_, keys)| !keys.is_empty());
+        if candidates.is_empty() {
+            self.armed = None;
+            return SequenceStep::Cancelled;
+        }
+
+        let armed = Armed {
+            candidates,
+            typed: 0,
+            timeout,
+            deadline: now + timeout,
+        };
+        let step = SequenceStep::Waiting(armed.next_keys());
+        self.armed = Some(armed);
+        step
+    }
+
+    pub fn key_pressed(&mut self, chord: KeyChord, now: Instant) -> SequenceStep {
+        let armed = match self.armed.as_mut() {
+            Some(armed) if now < armed.deadline => armed,
+            _ => {
+                self.armed = None;
+                return SequenceStep::Cancelled;
+            }
+        };
+
+        let typed = armed.typed;
+        armed.candidates.retain(|(_, keys)| keys.get(typed) == Some(&chord));
+        armed.typed += 1;
+        armed.deadline = now + armed.timeout;
+
+        if let Some(&(id, _)) = armed.candidates.iter().find(|(_, keys)| keys.len() == armed.typed) {
+            self.armed = None;
+            return SequenceStep::Matched(id);
+        }
+        if armed.candidates.is_empty() {
+            self.armed = None;
+            return SequenceStep::Cancelled;
+        }
+        SequenceStep::Waiting(armed.next_keys())
+    }
+
+    /// Disarm if the timeout passed; returns true when a pending sequence was dropped
+    pub fn expire(&mut self, now: Instant) -> bool {
+        match &self.armed {
+            Some(armed) if now >= armed.deadline => {
+                self.armed = None;
+                true
+            }
+            _ => false,
+        }
+    }
+
+    pub fn cancel(&mut self) {
+        self.armed = None;
+    }
+}
+
+impl Armed {
+    fn next_keys(&self) -> Vec<KeyChord> {
+        let mut keys = Vec::new();
+        for (_, sequence) in &self.candidates {
+            if let Some(key) = sequence.get(self.typed) {
+                if !keys.contains(key) {
+                    keys.push(*key);
+                }
+            }
+        }
+        keys
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::modules::keys::parse_chord;
+
+    fn keys(names: &[&str]) -> Vec<KeyChord> {
+        names.iter().map(|name| parse_chord(name).unwrap()).collect()
+    }
+
+    fn candidates() -> Vec<(u32, Vec<KeyChord>)> {
+        vec![
+            (1, keys(&["G", "P"])),
+            (2, keys(&["G", "S"])),
+            (3, keys(&["B"])),
+        ]
+    }
+
+    #[test]
+    fn test_sequence_picks_target_action() {
+        let timeout = Duration::from_millis(1000);
+        let start = Instant::now();
+        let mut tracker = SequenceTracker::default();
+
+        assert_eq!(tracker.arm(candidates(), timeout, start), SequenceStep::Waiting(keys(&["G", "B"])));
+        assert_eq!(tracker.key_pressed(keys(&["G"])[0], start + Duration::from_millis(300)), SequenceStep::Waiting(keys(&["P", "S"])));
+        // Each key restarts the timeout
+        assert!(!tracker.expire(start + Duration::from_millis(1200)));
+        assert_eq!(tracker.key_pressed(keys(&["S"])[0], start + Duration::from_millis(1200)), SequenceStep::Matched(2));
+        assert!(!tracker.is_armed());
+
+        tracker.arm(candidates(), timeout, start);
+        assert_eq!(tracker.key_pressed(keys(&["B"])[0], start), SequenceStep::Matched(3));
+    }
+
+    #[test]
+    fn test_sequence_cancels_on_wrong_key_or_timeout() {
+        let timeout = Duration::from_millis(1000);
+        let start = Instant::now();
+        let mut tracker = SequenceTracker::default();
+
+        tracker.arm(candidates(), timeout, start);
+        assert_eq!(tracker.key_pressed(keys(&["Escape"])[0], start), SequenceStep::Cancelled);
+        assert!(!tracker.is_armed());
+
+        tracker.arm(candidates(), timeout, start);
+        tracker.key_pressed(keys(&["G"])[0], start);
+        assert!(!tracker.expire(start + Duration::from_millis(999)));
+        assert!(tracker.expire(start + Duration::from_millis(1000)));
+        assert_eq!(tracker.key_pressed(keys(&["P"])[0], start + Duration::from_millis(1001)), SequenceStep::Cancelled);
+
+        // A key that arrives after the deadline but before expire() ran is also too late
+        tracker.arm(candidates(), timeout, start);
+        assert_eq!(tracker.key_pressed(keys(&["B"])[0], start + Duration::from_secs(2)), SequenceStep::Cancelled);
+
+        assert_eq!(tracker.arm(Vec::new(), timeout, start), SequenceStep::Cancelled);
+    }
+}
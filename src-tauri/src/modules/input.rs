@@ -0,0 +1,198 @@
+// Synthetic keyboard input backends used by the SendKeys action
+use anyhow::Result;
+use tracing::debug;
+
+use crate::modules::keys::KeyChord;
+
+#[cfg(windows)]
+use winapi::um::winuser::{
+    SendInput, INPUT, INPUT_KEYBOARD, KEYBDINPUT, KEYEVENTF_KEYUP, KEYEVENTF_UNICODE,
+    VK_CONTROL, VK_LWIN, VK_MENU, VK_SHIFT,
+};
+
+pub trait InputBackend: Send + Sync {
+    /// Press and release a chord (modifiers held while the key is tapped)
+    fn press_chord(&self, chord: &KeyChord) -> Result<()>;
+    /// Type literal text
+    fn type_text(&self, text: &str) -> Result<()>;
+}
+
+/// Backend for the current platform
+pub fn default_input_backend() -> std::sync::Arc<dyn InputBackend> {
+    #[cfg(windows)]
+    {
+        std::sync::Arc::new(SendInputBackend)
+    }
+
+    #[cfg(not(windows))]
+    {
+        std::sync::Arc::new(XdotoolBackend)
+    }
+}
+
+// Windows backend (SendInput)
+#[cfg(windows)]
+pub struct SendInputBackend;
+
+#[cfg(windows)]
+impl SendInputBackend {
+    fn key_input(vk: u16, scan: u16, flags: u32) -> INPUT {
+        unsafe {
+            let mut input: INPUT = std::mem::zeroed();
+            input.type_ = INPUT_KEYBOARD;
+            *input.u.ki_mut() = KEYBDINPUT {
+                wVk: vk,
+                wScan: scan,
+                dwFlags: flags,
+                time: 0,
+                dwExtraInfo: 0,
+            };
+            input
+        }
+    }
+
+    fn send(inputs: &mut [INPUT]) -> Result<()> {
+        let sent = unsafe {
+            SendInput(
+                inputs.len() as u32,
+                inputs.as_mut_ptr(),
+                std::mem::size_of::<INPUT>() as i32,
+            )
+        };
+
+        if sent as usize != inputs.len() {
+            let error_code = unsafe { winapi::um::errhandlingapi::GetLastError() };
+            return Err(anyhow::anyhow!(
+                "SendInput injected {} of {} events. Error code: {}",
+                sent, inputs.len(), error_code
+            ));
+        }
+        Ok(())
+    }
+}
+
+#[cfg(windows)]
+impl InputBackend for SendInputBackend {
+    fn press_chord(&self, chord: &KeyChord) -> Result<()> {
+        let mut modifier_vks = Vec::new();
+        if chord.modifiers.ctrl {
+            modifier_vks.push(VK_CONTROL as u16);
+        }
+        if chord.modifiers.alt {
+            modifier_vks.push(VK_MENU as u16);
+        }
+        if chord.modifiers.shift {
+            modifier_vks.push(VK_SHIFT as u16);
+        }
+        if chord.modifiers.meta {
+            modifier_vks.push(VK_LWIN as u16);
+        }
+
+        let key_vk = chord.key.vk_code() as u16;
+        let mut inputs = Vec::new();
+        for vk in &modifier_vks {
+            inputs.push(Self::key_input(*vk, 0, 0));
+        }
+        inputs.push(Self::key_input(key_vk, 0, 0));
+        inputs.push(Self::key_input(key_vk, 0, KEYEVENTF_KEYUP));
+        for vk in modifier_vks.iter().rev() {
+            inputs.push(Self::key_input(*vk, 0, KEYEVENTF_KEYUP));
+        }
+
+        debug!("⌨️ SendInput chord: {:?}", chord);
+        Self::send(&mut inputs)
+    }
+
+    fn type_text(&self, text: &str) -> Result<()> {
+        let mut inputs = Vec::new();
+        for unit in text.encode_utf16() {
+            inputs.push(Self::key_input(0, unit, KEYEVENTF_UNICODE));
+            inputs.push(Self::key_input(0, unit, KEYEVENTF_UNICODE | KEYEVENTF_KEYUP));
+        }
+
+        debug!("⌨️ SendInput text: {} chars", text.chars().count());
+        Self::send(&mut inputs)
+    }
+}
+
+// X11 backend (xdotool)
+#[cfg(not(windows))]
+pub struct XdotoolBackend;
+
+#[cfg(not(windows))]
+impl XdotoolBackend {
+    fn run(args: &[&str]) -> Result<()> {
+        let status = std::process::Command::new("xdotool")
+            .args(args)
+            .status()
+            .map_err(|e| anyhow::anyhow!("Failed to run xdotool (is it installed?): {}", e))?;
+
+        if !status.success() {
+            return Err(anyhow::anyhow!("xdotool exited with status {:?}", status.code()));
+        }
+        Ok(())
+    }
+}
+
+#[cfg(not(windows))]
+impl InputBackend for XdotoolBackend {
+    fn press_chord(&self, chord: &KeyChord) -> Result<()> {
+        let mut parts = Vec::new();
+        if chord.modifiers.ctrl {
+            parts.push("ctrl".to_string());
+        }
+        if chord.modifiers.alt {
+            parts.push("alt".to_string());
+        }
+        if chord.modifiers.shift {
+            parts.push("shift".to_string());
+        }
+        if chord.modifiers.meta {
+            parts.push("super".to_string());
+        }
+        parts.push(chord.key.keysym_name());
+
+        let combo = parts.join("+");
+        debug!("⌨️ xdotool key: {}", combo);
+        Self::run(&["key", "--clearmodifiers", &combo])
+    }
+
+    fn type_text(&self, text: &str) -> Result<()> {
+        debug!("⌨️ xdotool type: {} chars", text.chars().count());
+        Self::run(&["type", "--clearmodifiers", "--delay", "0", "--", text])
+    }
+}
+
+/// Test backend that records every call instead of injecting input
+#[cfg(test)]
+#[derive(Default)]
+pub struct RecordingInputBackend {
+    pub events: std::sync::Mutex<Vec<RecordedInput>>,
+}
+
+#[cfg(test)]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RecordedInput {
+    Chord(KeyChord),
+    Text(String),
+}
+
+#[cfg(test)]
+impl RecordingInputBackend {
+    pub fn events(&self) -> Vec<RecordedInput> {
+        self.events.lock().unwrap().clone()
+    }
+}
+
+#[cfg(test)]
+impl InputBackend for RecordingInputBackend {
+    fn press_chord(&self, chord: &KeyChord) -> Result<()> {
+        self.events.lock().unwrap().push(RecordedInput::Chord(*chord));
+        Ok(())
+    }
+
+    fn type_text(&self, text: &str) -> Result<()> {
+        self.events.lock().unwrap().push(RecordedInput::Text(text.to_string()));
+        Ok(())
+    }
+}
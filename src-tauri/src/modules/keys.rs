@@ -0,0 +1,704 @@
+// Platform-neutral key vocabulary shared by the hotkey parser and the SendKeys action
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum Key {
+    Letter(char), // 'A'..='Z'
+    Digit(u8),    // 0..=9
+    Function(u8), // F1..=F24
+    Numpad(u8),   // Numpad 0..=9
+    Escape,
+    Space,
+    Enter,
+    Tab,
+    Backspace,
+    Delete,
+    Insert,
+    Home,
+    End,
+    PageUp,
+    PageDown,
+    Up,
+    Down,
+    Left,
+    Right,
+    PrintScreen,
+    Pause,
+    CapsLock,
+    NumLock,
+    ScrollLock,
+    Menu,
+    NumpadAdd,
+    NumpadSubtract,
+    NumpadMultiply,
+    NumpadDivide,
+    NumpadDecimal,
+    MediaPlayPause,
+    MediaStop,
+    MediaNext,
+    MediaPrevious,
+    VolumeUp,
+    VolumeDown,
+    VolumeMute,
+    Backtick,
+    Semicolon,
+    Equals,
+    Comma,
+    Minus,
+    Period,
+    Slash,
+    LeftBracket,
+    Backslash,
+    RightBracket,
+    Quote,
+}
+
+/// Grouping used by the settings UI
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum KeyCategory {
+    Letter,
+    Digit,
+    Function,
+    Navigation,
+    Editing,
+    System,
+    Numpad,
+    Media,
+    Punctuation,
+}
+
+/// A supported key as shown in the settings UI
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct KeyInfo {
+    pub name: String,         // Canonical name, what formatting produces
+    pub aliases: Vec<String>, // Other accepted spellings (matched case-insensitively)
+    pub category: KeyCategory,
+}
+
+// One row of the key table. The first name is canonical; all names are matched case-insensitively.
+struct KeyDef {
+    key: Key,
+    names: &'static [&'static str],
+    vk: u32,              // Windows virtual-key code
+    keysym: &'static str, // X11 keysym name
+    category: KeyCategory,
+}
+
+const fn def(key: Key, names: &'static [&'static str], vk: u32, keysym: &'static str, category: KeyCategory) -> KeyDef {
+    KeyDef { key, names, vk, keysym, category }
+}
+
+// Letters, digits, F-keys and numpad digits are generated; everything else is listed here.
+// OEM keys are named after their unshifted character on a US layout: "+" is an alias of the
+// "=" key because both are the same physical key (VK_OEM_PLUS), while the keypad plus is NumpadAdd.
+#[rustfmt::skip]
+const NAMED_KEYS: &[KeyDef] = &[
+    def(Key::Escape, &["Escape", "Esc"], 0x1B, "Escape", KeyCategory::Navigation),
+    def(Key::Space, &["Space"], 0x20, "space", KeyCategory::Editing),
+    def(Key::Enter, &["Enter", "Return"], 0x0D, "Return", KeyCategory::Editing),
+    def(Key::Tab, &["Tab"], 0x09, "Tab", KeyCategory::Editing),
+    def(Key::Backspace, &["Backspace", "BS"], 0x08, "BackSpace", KeyCategory::Editing),
+    def(Key::Delete, &["Delete", "Del"], 0x2E, "Delete", KeyCategory::Editing),
+    def(Key::Insert, &["Insert", "Ins"], 0x2D, "Insert", KeyCategory::Editing),
+    def(Key::Home, &["Home"], 0x24, "Home", KeyCategory::Navigation),
+    def(Key::End, &["End"], 0x23, "End", KeyCategory::Navigation),
+    def(Key::PageUp, &["PageUp", "PgUp"], 0x21, "Prior", KeyCategory::Navigation),
+    def(Key::PageDown, &["PageDown", "PgDn"], 0x22, "Next", KeyCategory::Navigation),
+    def(Key::Up, &["Up"], 0x26, "Up", KeyCategory::Navigation),
+    def(Key::Down, &["Down"], 0x28, "Down", KeyCategory::Navigation),
+    def(Key::Left, &["Left"], 0x25, "Left", KeyCategory::Navigation),
+    def(Key::Right, &["Right"], 0x27, "Right", KeyCategory::Navigation),
+
+    def(Key::PrintScreen, &["PrintScreen", "PrtSc", "Print", "Snapshot"], 0x2C, "Print", KeyCategory::System),
+    def(Key::Pause, &["Pause", "Break"], 0x13, "Pause", KeyCategory::System),
+    def(Key::CapsLock, &["CapsLock", "Caps", "Capital"], 0x14, "Caps_Lock", KeyCategory::System),
+    def(Key::NumLock, &["NumLock"], 0x90, "Num_Lock", KeyCategory::System),
+    def(Key::ScrollLock, &["ScrollLock", "Scroll"], 0x91, "Scroll_Lock", KeyCategory::System),
+    def(Key::Menu, &["Menu", "Apps", "ContextMenu"], 0x5D, "Menu", KeyCategory::System),
+
+    def(Key::NumpadAdd, &["NumpadAdd", "NumAdd", "Numpad+"], 0x6B, "KP_Add", KeyCategory::Numpad),
+    def(Key::NumpadSubtract, &["NumpadSubtract", "NumSub", "Numpad-"], 0x6D, "KP_Subtract", KeyCategory::Numpad),
+    def(Key::NumpadMultiply, &["NumpadMultiply", "NumMul", "Numpad*"], 0x6A, "KP_Multiply", KeyCategory::Numpad),
+    def(Key::NumpadDivide, &["NumpadDivide", "NumDiv", "Numpad/"], 0x6F, "KP_Divide", KeyCategory::Numpad),
+    def(Key::NumpadDecimal, &["NumpadDecimal", "NumDec", "Numpad."], 0x6E, "KP_Decimal", KeyCategory::Numpad),
+
+    def(Key::MediaPlayPause, &["MediaPlayPause", "PlayPause"], 0xB3, "XF86AudioPlay", KeyCategory::Media),
+    def(Key::MediaStop, &["MediaStop"], 0xB2, "XF86AudioStop", KeyCategory::Media),
+    def(Key::MediaNext, &["MediaNext", "NextTrack"], 0xB0, "XF86AudioNext", KeyCategory::Media),
+    def(Key::MediaPrevious, &["MediaPrevious", "MediaPrev", "PrevTrack"], 0xB1, "XF86AudioPrev", KeyCategory::Media),
+    def(Key::VolumeUp, &["VolumeUp"], 0xAF, "XF86AudioRaiseVolume", KeyCategory::Media),
+    def(Key::VolumeDown, &["VolumeDown"], 0xAE, "XF86AudioLowerVolume", KeyCategory::Media),
+    def(Key::VolumeMute, &["VolumeMute", "Mute"], 0xAD, "XF86AudioMute", KeyCategory::Media),
+
+    def(Key::Backtick, &["`", "Backtick", "Grave", "OEM3", "~"], 0xC0, "grave", KeyCategory::Punctuation),
+    def(Key::Semicolon, &[";", "Semicolon", "OEM1", ":"], 0xBA, "semicolon", KeyCategory::Punctuation),
+    def(Key::Equals, &["=", "Equals", "Equal", "OEMPlus", "+", "Plus"], 0xBB, "equal", KeyCategory::Punctuation),
+    def(Key::Comma, &[",", "Comma", "OEMComma", "<"], 0xBC, "comma", KeyCategory::Punctuation),
+    def(Key::Minus, &["-", "Minus", "OEMMinus", "_"], 0xBD, "minus", KeyCategory::Punctuation),
+    def(Key::Period, &[".", "Period", "OEMPeriod", ">"], 0xBE, "period", KeyCategory::Punctuation),
+    def(Key::Slash, &["/", "Slash", "OEM2", "?"], 0xBF, "slash", KeyCategory::Punctuation),
+    def(Key::LeftBracket, &["[", "LeftBracket", "OEM4", "{"], 0xDB, "bracketleft", KeyCategory::Punctuation),
+    def(Key::Backslash, &["\\", "Backslash", "OEM5", "|"], 0xDC, "backslash", KeyCategory::Punctuation),
+    def(Key::RightBracket, &["]", "RightBracket", "OEM6", "}"], 0xDD, "bracketright", KeyCategory::Punctuation),
+    def(Key::Quote, &["'", "Quote", "Apostrophe", "OEM7", "\""], 0xDE, "apostrophe", KeyCategory::Punctuation),
+];
+
+const MAX_FUNCTION_KEY: u8 = 24;
+
+/// Every key the parser accepts, in the order the settings UI lists them
+pub fn supported_keys() -> Vec<KeyInfo> {
+    let generated = ('A'..='Z').map(Key::Letter)
+        .chain((0..=9).map(Key::Digit))
+        .chain((1..=MAX_FUNCTION_KEY).map(Key::Function))
+        .chain((0..=9).map(Key::Numpad))
+        .map(|key| KeyInfo {
+            name: key.name(),
+            aliases: match key {
+                Key::Numpad(n) => vec![format!("Num{}", n), format!("KP{}", n)],
+                _ => Vec::new(),
+            },
+            category: key.category(),
+        });
+
+    let named = NAMED_KEYS.iter().map(|def| KeyInfo {
+        name: def.names[0].to_string(),
+        aliases: def.names[1..].iter().map(|alias| alias.to_string()).collect(),
+        category: def.category,
+    });
+
+    generated.chain(named).collect()
+}
+
+impl Key {
+    /// Look up a key by name (case-insensitive), e.g. "F1", "Esc", "A", "PageUp", ";", "Numpad5", "VolumeUp"
+    pub fn from_name(name: &str) -> Result<Self> {
+        if let Some(def) = NAMED_KEYS.iter().find(|def| def.names.iter().any(|n| n.eq_ignore_ascii_case(name))) {
+            return Ok(def.key);
+        }
+
+        let lower = name.to_lowercase();
+        let mut chars = lower.chars();
+        if let (Some(ch), None) = (chars.next(), chars.next()) {
+            if ch.is_ascii_alphabetic() {
+                return Ok(Key::Letter(ch.to_ascii_uppercase()));
+            }
+            if ch.is_ascii_digit() {
+                return Ok(Key::Digit(ch as u8 - b'0'));
+            }
+        }
+
+        let number = |prefix: &str, range: std::ops::RangeInclusive<u8>| {
+            lower.strip_prefix(prefix)
+                .and_then(|rest| rest.parse::<u8>().ok())
+                .filter(|n| range.contains(n))
+        };
+        if let Some(n) = number("f", 1..=MAX_FUNCTION_KEY) {
+            return Ok(Key::Function(n));
+        }
+        if let Some(n) = ["numpad", "num", "kp"].iter().find_map(|prefix| number(prefix, 0..=9)) {
+            return Ok(Key::Numpad(n));
+        }
+
+        Err(anyhow::anyhow!("Unknown key: {}", name))
+    }
+
+    fn def(&self) -> Option<&'static KeyDef> {
+        NAMED_KEYS.iter().find(|def| def.key == *self)
+    }
+
+    /// Canonical name; `Key::from_name` accepts it back
+    pub fn name(&self) -> String {
+        match self {
+            Key::Letter(c) => c.to_string(),
+            Key::Digit(d) => d.to_string(),
+            Key::Function(n) => format!("F{}", n),
+            Key::Numpad(n) => format!("Numpad{}", n),
+            _ => self.def().map(|def| def.names[0].to_string()).unwrap_or_default(),
+        }
+    }
+
+    pub fn category(&self) -> KeyCategory {
+        match self {
+            Key::Letter(_) => KeyCategory::Letter,
+            Key::Digit(_) => KeyCategory::Digit,
+            Key::Function(_) => KeyCategory::Function,
+            Key::Numpad(_) => KeyCategory::Numpad,
+            _ => self.def().map(|def| def.category).unwrap_or(KeyCategory::System),
+        }
+    }
+
+    /// Windows virtual-key code
+    #[cfg_attr(not(windows), allow(dead_code))]
+    pub fn vk_code(&self) -> u32 {
+        match self {
+            Key::Letter(c) => *c as u32,
+            Key::Digit(d) => 0x30 + *d as u32,
+            Key::Function(n) => 0x70 + (*n as u32 - 1),
+            Key::Numpad(n) => 0x60 + *n as u32,
+            _ => self.def().map(|def| def.vk).unwrap_or(0),
+        }
+    }
+
+    /// X11 keysym name (as understood by xdotool / XStringToKeysym)
+    pub fn keysym_name(&self) -> String {
+        match self {
+            Key::Letter(c) => c.to_ascii_lowercase().to_string(),
+            Key::Digit(d) => d.to_string(),
+            Key::Function(n) => format!("F{}", n),
+            Key::Numpad(n) => format!("KP_{}", n),
+            _ => self.def().map(|def| def.keysym.to_string()).unwrap_or_default(),
+        }
+    }
+}
+
+impl fmt::Display for Key {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.name())
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct Modifiers {
+    pub ctrl: bool,
+    pub alt: bool,
+    pub shift: bool,
+    pub meta: bool, // Win / Cmd / Super
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct KeyChord {
+    pub modifiers: Modifiers,
+    pub key: Key,
+}
+
+/// One step of a SendKeys sequence
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum KeyStroke {
+    Text(String),
+    Chord { chord: KeyChord, repeat: u32 },
+}
+
+impl Modifiers {
+    pub fn is_empty(&self) -> bool {
+        !(self.ctrl || self.alt || self.shift || self.meta)
+    }
+
+    /// Apply a modifier by name; returns false if the name is not a modifier
+    pub fn apply(&mut self, name: &str) -> bool {
+        match name.to_lowercase().as_str() {
+            "ctrl" | "control" => self.ctrl = true,
+            "alt" => self.alt = true,
+            "shift" => self.shift = true,
+            "win" | "windows" | "cmd" | "super" | "meta" => self.meta = true,
+            _ => return false,
+        }
+        true
+    }
+
+    /// RegisterHotKey MOD_* flags
+    #[cfg_attr(not(windows), allow(dead_code))]
+    pub fn to_win32_flags(self) -> u32 {
+        let mut flags = 0u32;
+        if self.alt {
+            flags |= 0x0001; // MOD_ALT
+        }
+        if self.ctrl {
+            flags |= 0x0002; // MOD_CONTROL
+        }
+        if self.shift {
+            flags |= 0x0004; // MOD_SHIFT
+        }
+        if self.meta {
+            flags |= 0x0008; // MOD_WIN
+        }
+        flags
+    }
+}
+
+/// A single modifier key on its own, for gestures such as double-tapping Ctrl
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum ModifierKey {
+    Ctrl,
+    Alt,
+    Shift,
+    Meta,
+}
+
+impl ModifierKey {
+    /// Look up a modifier by the names `Modifiers::apply` accepts, e.g. "Ctrl" or "Win"
+    pub fn from_name(name: &str) -> Result<Self> {
+        let mut modifiers = Modifiers::default();
+        if !modifiers.apply(name.trim()) {
+            return Err(anyhow::anyhow!("Not a modifier key: {}", name));
+        }
+        Ok(match modifiers {
+            Modifiers { ctrl: true, .. } => ModifierKey::Ctrl,
+            Modifiers { alt: true, .. } => ModifierKey::Alt,
+            Modifiers { shift: true, .. } => ModifierKey::Shift,
+            _ => ModifierKey::Meta,
+        })
+    }
+
+    /// Windows virtual-key codes of the left, right and generic key
+    #[cfg_attr(not(windows), allow(dead_code))]
+    pub fn vk_codes(&self) -> &'static [u32] {
+        match self {
+            ModifierKey::Ctrl => &[0xA2, 0xA3, 0x11],
+            ModifierKey::Alt => &[0xA4, 0xA5, 0x12],
+            ModifierKey::Shift => &[0xA0, 0xA1, 0x10],
+            ModifierKey::Meta => &[0x5B, 0x5C],
+        }
+    }
+
+    /// X11 keysym names of the left and right key
+    pub fn keysym_names(&self) -> [&'static str; 2] {
+        match self {
+            ModifierKey::Ctrl => ["Control_L", "Control_R"],
+            ModifierKey::Alt => ["Alt_L", "Alt_R"],
+            ModifierKey::Shift => ["Shift_L", "Shift_R"],
+            ModifierKey::Meta => ["Super_L", "Super_R"],
+        }
+    }
+}
+
+impl fmt::Display for ModifierKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            ModifierKey::Ctrl => "Ctrl",
+            ModifierKey::Alt => "Alt",
+            ModifierKey::Shift => "Shift",
+            ModifierKey::Meta => "Win",
+        })
+    }
+}
+
+/// Canonical form, e.g. "Ctrl+Shift+Numpad5"; `parse_chord` accepts it back
+impl fmt::Display for KeyChord {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let modifiers = [
+            (self.modifiers.ctrl, "Ctrl"),
+            (self.modifiers.alt, "Alt"),
+            (self.modifiers.shift, "Shift"),
+            (self.modifiers.meta, "Win"),
+        ];
+        for (_, name) in modifiers.iter().filter(|(held, _)| *held) {
+            write!(f, "{}+", name)?;
+        }
+        write!(f, "{}", self.key)
+    }
+}
+
+/// Canonical form of a chord sequence, e.g. "Ctrl+Space, G, P"
+pub fn format_chord_sequence(chords: &[KeyChord]) -> String {
+    chords.iter().map(|chord| chord.to_string()).collect::<Vec<_>>().join(", ")
+}
+
+// Characters typed with Shift held on a US layout. In hotkeys they only name the physical key
+// ("Ctrl++" is Ctrl+=); in SendKeys groups they also press Shift so `{~}` types a tilde.
+const SHIFTED_CHARS: &[&str] = &["~", ":", "+", "<", "_", ">", "?", "{", "|", "}", "\""];
+
+/// Largest `{NAME N}` repeat count accepted by `parse_key_sequence`
+pub const MAX_REPEAT: u32 = 255;
+
+/// Parse a single chord such as "Ctrl+Shift+T", "F11" or "Ctrl++"
+pub fn parse_chord(input: &str) -> Result<KeyChord> {
+    parse_chord_with(input, false)
+}
+
+fn parse_chord_with(input: &str, shift_symbols: bool) -> Result<KeyChord> {
+    let trimmed = input.trim();
+    if trimmed.is_empty() {
+        return Err(anyhow::anyhow!("Invalid hotkey string: {}", input));
+    }
+
+    // A lone "+" or a trailing "++" means the plus key itself
+    let (body, plus_key) = if trimmed == "+" {
+        ("", true)
+    } else if trimmed.len() > 1 && trimmed.ends_with("++") {
+        (&trimmed[..trimmed.len() - 2], true)
+    } else {
+        (trimmed, false)
+    };
+
+    let mut parts: Vec<&str> = if body.is_empty() {
+        Vec::new()
+    } else {
+        body.split('+').map(|s| s.trim()).collect()
+    };
+
+    let key_name = if plus_key {
+        "+"
+    } else {
+        parts.pop().ok_or_else(|| anyhow::anyhow!("Invalid hotkey string: {}", input))?
+    };
+
+    let mut modifiers = Modifiers::default();
+    for modifier in parts {
+        if !modifiers.apply(modifier) {
+            return Err(anyhow::anyhow!("Unknown modifier: {}", modifier));
+        }
+    }
+
+    if shift_symbols && SHIFTED_CHARS.contains(&key_name) {
+        modifiers.shift = true;
+    }
+
+    Ok(KeyChord {
+        modifiers,
+        key: Key::from_name(key_name)?,
+    })
+}
+
+/// Parse a hotkey that may be a sequence of chords, e.g. "Ctrl+Space, G, P".
+/// A comma directly after a '+' (or on its own) is the comma key, as in "Ctrl+,".
+pub fn parse_chord_sequence(input: &str) -> Result<Vec<KeyChord>> {
+    let mut parts = Vec::new();
+    let mut current = String::new();
+    for ch in input.chars() {
+        let pending = current.trim();
+        if ch == ',' && !pending.is_empty() && !pending.ends_with('+') {
+            parts.push(std::mem::take(&mut current));
+        } else {
+            current.push(ch);
+        }
+    }
+    parts.push(current);
+
+    parts.iter()
+        .map(|part| parse_chord(part))
+        .collect::<Result<Vec<_>>>()
+        .map_err(|e| anyhow::anyhow!("Invalid hotkey '{}': {}", input.trim(), e))
+}
+
+/// Parse a SendKeys sequence.
+///
+/// Plain text is typed as-is; `{NAME}` presses a key or chord (`{ENTER}`, `{Ctrl+Shift+T}`),
+/// `{NAME N}` repeats it N times (`{TAB 3}`, at most `MAX_REPEAT`), shifted symbols such as
+/// `{~}` press Shift with their key, and `{{}` / `{}}` type literal braces.
+/// A string without braces that is a valid chord with at least one modifier
+/// (e.g. `Ctrl+Shift+T`) is treated as that chord.
+pub fn parse_key_sequence(input: &str) -> Result<Vec<KeyStroke>> {
+    if !input.contains('{') && !input.contains('}') {
+        if let Ok(chord) = parse_chord(input) {
+            if !chord.modifiers.is_empty() {
+                return Ok(vec![KeyStroke::Chord { chord, repeat: 1 }]);
+            }
+        }
+    }
+
+    let mut strokes = Vec::new();
+    let mut text = String::new();
+    let mut chars = input.chars().peekable();
+
+    while let Some(ch) = chars.next() {
+        match ch {
+            '{' => {
+                // Literal braces: "{{}" and "{}}"
+                if chars.peek() == Some(&'{') || chars.peek() == Some(&'}') {
+                    let literal = chars.next().unwrap();
+                    if chars.next() != Some('}') {
+                        return Err(anyhow::anyhow!("Unterminated '{{' in key sequence: {}", input));
+                    }
+                    text.push(literal);
+                    continue;
+                }
+
+                let mut group = String::new();
+                let mut closed = false;
+                for inner in chars.by_ref() {
+                    if inner == '}' {
+                        closed = true;
+                        break;
+                    }
+                    group.push(inner);
+                }
+                if !closed {
+                    return Err(anyhow::anyhow!("Unterminated '{{' in key sequence: {}", input));
+                }
+
+                if !text.is_empty() {
+                    strokes.push(KeyStroke::Text(std::mem::take(&mut text)));
+                }
+                strokes.push(parse_group(&group)?);
+            }
+            '}' => {
+                return Err(anyhow::anyhow!("Unexpected '}}' in key sequence: {}", input));
+            }
+            _ => text.push(ch),
+        }
+    }
+
+    if !text.is_empty() {
+        strokes.push(KeyStroke::Text(text));
+    }
+
+    Ok(strokes)
+}
+
+fn parse_group(group: &str) -> Result<KeyStroke> {
+    let group = group.trim();
+    let (chord_str, repeat) = match group.rsplit_once(char::is_whitespace) {
+        Some((chord_str, count)) if !chord_str.trim_end().ends_with('+') => {
+            let repeat = count.trim().parse::<u32>()
+                .map_err(|_| anyhow::anyhow!("Invalid repeat count in {{{}}}", group))?;
+            if repeat > MAX_REPEAT {
+                return Err(anyhow::anyhow!("Repeat count in {{{}}} exceeds {}", group, MAX_REPEAT));
+            }
+            (chord_str.trim(), repeat)
+        }
+        _ => (group, 1),
+    };
+
+    Ok(KeyStroke::Chord {
+        chord: parse_chord_with(chord_str, true)?,
+        repeat,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_chord() {
+        let chord = parse_chord("Ctrl+Shift+T").unwrap();
+        assert!(chord.modifiers.ctrl && chord.modifiers.shift);
+        assert!(!chord.modifiers.alt && !chord.modifiers.meta);
+        assert_eq!(chord.key, Key::Letter('T'));
+
+        let chord = parse_chord("f11").unwrap();
+        assert!(chord.modifiers.is_empty());
+        assert_eq!(chord.key, Key::Function(11));
+
+        let chord = parse_chord("Ctrl++").unwrap();
+        assert!(chord.modifiers.ctrl);
+        assert_eq!(chord.key, Key::Equals);
+
+        let chord = parse_chord("+").unwrap();
+        assert!(chord.modifiers.is_empty());
+        assert_eq!(chord.key, Key::Equals);
+        assert_eq!(parse_key_sequence("{+}").unwrap(), vec![
+            KeyStroke::Chord { chord: parse_chord("Shift+=").unwrap(), repeat: 1 },
+        ]);
+
+        assert!(parse_chord("Hyper+A").is_err());
+        assert!(parse_chord("Ctrl+Nope").is_err());
+        assert!(parse_chord("").is_err());
+    }
+
+    #[test]
+    fn test_parse_chord_sequence() {
+        let chords = parse_chord_sequence("Ctrl+Space, G, P").unwrap();
+        assert_eq!(chords, vec![
+            parse_chord("Ctrl+Space").unwrap(),
+            parse_chord("G").unwrap(),
+            parse_chord("P").unwrap(),
+        ]);
+
+        assert_eq!(parse_chord_sequence("F11").unwrap(), vec![parse_chord("F11").unwrap()]);
+        assert_eq!(parse_chord_sequence("Ctrl+,").unwrap(), vec![parse_chord("Ctrl+,").unwrap()]);
+        assert_eq!(parse_chord_sequence("Alt+K, ,").unwrap()[1].key, Key::Comma);
+
+        assert!(parse_chord_sequence("Ctrl+Space, ").is_err());
+        assert!(parse_chord_sequence("Ctrl+Space, Nope").is_err());
+    }
+
+    #[test]
+    fn test_vk_codes() {
+        assert_eq!(Key::Function(1).vk_code(), 0x70);
+        assert_eq!(Key::Function(12).vk_code(), 0x7B);
+        assert_eq!(Key::Letter('A').vk_code(), 'A' as u32);
+        assert_eq!(Key::Digit(7).vk_code(), 0x37);
+        assert_eq!(Key::Escape.vk_code(), 0x1B);
+        assert_eq!(Key::Function(24).vk_code(), 0x87);
+        assert_eq!(Key::Numpad(0).vk_code(), 0x60);
+        assert_eq!(Key::NumpadAdd.vk_code(), 0x6B);
+        assert_eq!(Key::MediaPlayPause.vk_code(), 0xB3);
+        assert_eq!(Key::PrintScreen.vk_code(), 0x2C);
+        assert_eq!(Key::Quote.vk_code(), 0xDE);
+    }
+
+    #[test]
+    fn test_key_table_round_trips() {
+        let keys = supported_keys();
+        let mut vk_codes = std::collections::HashSet::new();
+        for info in &keys {
+            let key = Key::from_name(&info.name).unwrap();
+            assert_eq!(key.name(), info.name);
+            assert_eq!(key.category(), info.category);
+            for alias in &info.aliases {
+                assert_eq!(Key::from_name(alias).unwrap(), key, "alias {}", alias);
+            }
+            assert!(!key.keysym_name().is_empty(), "{} has no keysym", info.name);
+            // Distinct keys never share a virtual-key code
+            assert!(vk_codes.insert(key.vk_code()), "{} reuses VK 0x{:X}", info.name, key.vk_code());
+        }
+        assert!(keys.iter().any(|info| info.name == "F24"));
+        assert_eq!(Key::from_name("kp7").unwrap(), Key::Numpad(7));
+        assert!(Key::from_name("F25").is_err());
+        assert!(Key::from_name("Numpad10").is_err());
+    }
+
+    #[test]
+    fn test_format_chords() {
+        for input in ["Ctrl+Shift+T", "Alt+Win+F13", "Ctrl+Space, G, P", "Ctrl+,", "Alt+K, ,", "Shift+VolumeUp", "Ctrl+Numpad5"] {
+            assert_eq!(format_chord_sequence(&parse_chord_sequence(input).unwrap()), input);
+        }
+        // Aliases and modifier order normalise to the canonical spelling
+        assert_eq!(parse_chord("shift+ctrl+esc").unwrap().to_string(), "Ctrl+Shift+Escape");
+        assert_eq!(parse_chord("Ctrl++").unwrap().to_string(), "Ctrl+=");
+        assert_eq!(parse_chord("Alt+NumAdd").unwrap().to_string(), "Alt+NumpadAdd");
+    }
+
+    #[test]
+    fn test_modifier_keys() {
+        assert_eq!(ModifierKey::from_name("control").unwrap(), ModifierKey::Ctrl);
+        assert_eq!(ModifierKey::from_name(" Super ").unwrap(), ModifierKey::Meta);
+        assert_eq!(ModifierKey::Meta.to_string(), "Win");
+        assert!(ModifierKey::from_name("A").is_err());
+        assert!(ModifierKey::from_name("Ctrl+Alt").is_err());
+    }
+
+    #[test]
+    fn test_parse_key_sequence() {
+        let strokes = parse_key_sequence("Hello{ENTER}{TAB 3}").unwrap();
+        assert_eq!(strokes, vec![
+            KeyStroke::Text("Hello".to_string()),
+            KeyStroke::Chord { chord: parse_chord("Enter").unwrap(), repeat: 1 },
+            KeyStroke::Chord { chord: parse_chord("Tab").unwrap(), repeat: 3 },
+        ]);
+
+        let strokes = parse_key_sequence("Ctrl+Shift+T").unwrap();
+        assert_eq!(strokes, vec![KeyStroke::Chord { chord: parse_chord("Ctrl+Shift+T").unwrap(), repeat: 1 }]);
+
+        let strokes = parse_key_sequence("{Ctrl+C}a{{}b{}}").unwrap();
+        assert_eq!(strokes, vec![
+            KeyStroke::Chord { chord: parse_chord("Ctrl+C").unwrap(), repeat: 1 },
+            KeyStroke::Text("a{b}".to_string()),
+        ]);
+
+        // Plain text that merely looks like a key name is typed
+        assert_eq!(parse_key_sequence("A+B").unwrap(), vec![KeyStroke::Text("A+B".to_string())]);
+
+        assert!(parse_key_sequence("{ENTER").is_err());
+        assert!(parse_key_sequence("{TAB x}").is_err());
+        assert!(parse_key_sequence("{TAB 255}").is_ok());
+        assert!(parse_key_sequence("{TAB 256}").is_err());
+        assert!(parse_key_sequence("{TAB 4000000000}").is_err());
+
+        // Shifted symbols press Shift in SendKeys, but stay bare keys in hotkeys
+        assert_eq!(parse_key_sequence("{~}").unwrap(), vec![
+            KeyStroke::Chord { chord: parse_chord("Shift+`").unwrap(), repeat: 1 },
+        ]);
+        assert_eq!(parse_key_sequence("{Ctrl+~}").unwrap(), vec![
+            KeyStroke::Chord { chord: parse_chord("Ctrl+Shift+`").unwrap(), repeat: 1 },
+        ]);
+        assert_eq!(parse_key_sequence("{`}").unwrap(), vec![
+            KeyStroke::Chord { chord: parse_chord("`").unwrap(), repeat: 1 },
+        ]);
+        assert!(!parse_chord("~").unwrap().modifiers.shift);
+    }
+}
@@ -0,0 +1,424 @@
+// Config schema versioning and migrations
+use super::config::legacy_to_tagged;
+use anyhow::{Context, Result};
+use serde_yaml::{Mapping, Value};
+use std::fmt;
+use tracing::{debug, info};
+
+/// Schema version written by this build
+pub const CURRENT_CONFIG_VERSION: ConfigVersion = ConfigVersion { major: 1, minor: 1 };
+
+/// Version assumed for configs that predate the `version` field
+const UNVERSIONED: ConfigVersion = ConfigVersion { major: 1, minor: 0 };
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ConfigVersion {
+    pub major: u32,
+    pub minor: u32,
+}
+
+impl ConfigVersion {
+    /// Parse "1", "1.1" or "1.1.0" (patch components are ignored)
+    pub fn parse(version: &str) -> Result<Self> {
+        let mut parts = version.trim().split('.');
+        let major = parts.next()
+            .filter(|p| !p.is_empty())
+            .ok_or_else(|| anyhow::anyhow!("Config version cannot be empty"))?
+            .parse::<u32>()
+            .with_context(|| format!("Invalid config version '{}'", version))?;
+        let minor = match parts.next() {
+            Some(minor) => minor.parse::<u32>()
+                .with_context(|| format!("Invalid config version '{}'", version))?,
+            None => 0,
+        };
+
+        Ok(Self { major, minor })
+    }
+}
+
+impl fmt::Display for ConfigVersion {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}.{}", self.major, self.minor)
+    }
+}
+
+struct Migration {
+    from: ConfigVersion,
+    to: ConfigVersion,
+    description: &'static str,
+    apply: fn(&mut Value) -> Result<()>,
+}
+
+/// Ordered upgrade steps; each one takes the config from `from` to `to`
+const MIGRATIONS: &[Migration] = &[
+    Migration {
+        from: ConfigVersion { major: 1, minor: 0 },
+        to: ConfigVersion { major: 1, minor: 1 },
+        description: "typed button actions and default UI settings",
+        apply: migrate_1_0_to_1_1,
+    },
+];
+
+#[derive(Debug, Clone)]
+pub struct MigrationOutcome {
+    pub from: ConfigVersion,
+    pub to: ConfigVersion,
+    pub applied: Vec<String>,
+}
+
+impl MigrationOutcome {
+    pub fn migrated(&self) -> bool {
+        !self.applied.is_empty()
+    }
+}
+
+/// Read the schema version of a raw config document
+pub fn detect_version(config: &Value) -> Result<ConfigVersion> {
+    match config.get("version") {
+        None | Some(Value::Null) => Ok(UNVERSIONED),
+        Some(Value::String(version)) => ConfigVersion::parse(version),
+        Some(Value::Number(number)) => ConfigVersion::parse(&number.to_string()),
+        Some(other) => Err(anyhow::anyhow!("Invalid config version: {:?}", other)),
+    }
+}
+
+/// Upgrade a raw config document in place to `CURRENT_CONFIG_VERSION`
+pub fn migrate(config: &mut Value) -> Result<MigrationOutcome> {
+    let from = detect_version(config)?;
+
+    if from > CURRENT_CONFIG_VERSION {
+        return Err(anyhow::anyhow!(
+            "Config version {} is newer than the newest version this build supports ({}). Please update Q-Deck.",
+            from, CURRENT_CONFIG_VERSION
+        ));
+    }
+
+    let mut version = from;
+    let mut applied = Vec::new();
+    while version < CURRENT_CONFIG_VERSION {
+        let migration = MIGRATIONS.iter()
+            .find(|m| m.from == version)
+            .ok_or_else(|| anyhow::anyhow!("No migration path from config version {}", version))?;
+
+        info!("🔄 Migrating config {} -> {}: {}", migration.from, migration.to, migration.description);
+        (migration.apply)(config)
+            .with_context(|| format!("Failed to migrate config from {} to {}", migration.from, migration.to))?;
+
+        version = migration.to;
+        set_version(config, version)?;
+        applied.push(format!("{} -> {}: {}", migration.from, migration.to, migration.description));
+    }
+
+    Ok(MigrationOutcome {
+        from,
+        to: version,
+        applied,
+    })
+}
+
+fn set_version(config: &mut Value, version: ConfigVersion) -> Result<()> {
+    let mapping = config.as_mapping_mut()
+        .ok_or_else(|| anyhow::anyhow!("Config root must be a mapping"))?;
+    mapping.insert(Value::from("version"), Value::from(version.to_string()));
+    Ok(())
+}
+
+// UI defaults as of 1.1. Written out so the step keeps its meaning when `UIConfig` changes.
+const UI_DEFAULTS_1_1: &str = r#"
+summon:
+  hotkeys: ["F11"]
+  edge_trigger:
+    enabled: false
+    edges: ["top"]
+    dwell_ms: 300
+    margin_px: 5
+window:
+  placement: dropdown-top
+  width_px: 1000
+  height_px: 600
+  cell_size_px: 96
+  gap_px: 8
+  opacity: 0.92
+  theme: dark
+  animation:
+    enabled: true
+    duration_ms: 150
+"#;
+
+// 1.0 -> 1.1: buttons move from `action_type` + `config` (+ `folder`) to a typed `action`,
+// and UI sections missing from hand-written configs get their defaults.
+// Works on the raw document only, so later changes to the config structs don't alter it.
+fn migrate_1_0_to_1_1(config: &mut Value) -> Result<()> {
+    let defaults: Value = serde_yaml::from_str(UI_DEFAULTS_1_1)?;
+    let root = config.as_mapping_mut()
+        .ok_or_else(|| anyhow::anyhow!("Config root must be a mapping"))?;
+    let ui = root.entry(Value::from("ui")).or_insert_with(|| Value::Mapping(Mapping::new()));
+    fill_missing(ui, &defaults);
+
+    if let Some(profiles) = config.get_mut("profiles").and_then(Value::as_sequence_mut) {
+        for profile in profiles {
+            if let Some(pages) = profile.get_mut("pages").and_then(Value::as_sequence_mut) {
+                for page in pages {
+                    migrate_page_buttons(page)?;
+                }
+            }
+        }
+    }
+
+    Ok(())
+}
+
+fn migrate_page_buttons(page: &mut Value) -> Result<()> {
+    let buttons = match page.get_mut("buttons").and_then(Value::as_sequence_mut) {
+        Some(buttons) => buttons,
+        None => return Ok(()),
+    };
+
+    for button in buttons {
+        let is_typed = button.get("action").and_then(|a| a.get("type")).is_some();
+        if !is_typed {
+            migrate_legacy_button(button)?;
+        }
+        if let Some(child) = button.get_mut("action").and_then(|a| a.get_mut("page")) {
+            if child.is_mapping() {
+                migrate_page_buttons(child)?;
+            }
+        }
+    }
+
+    Ok(())
+}
+
+// Fold `action_type`, `config` and `folder` into `action: { type, ... }`. The old untyped
+// `action` field only carried `{ action_type: system, system_action }` for System buttons.
+fn migrate_legacy_button(button: &mut Value) -> Result<()> {
+    let context = button_context(button);
+    let fields = button.as_mapping_mut()
+        .ok_or_else(|| anyhow::anyhow!("{}: button must be a mapping", context))?;
+
+    let legacy_action = fields.remove("action").unwrap_or(Value::Null);
+    let system_action = Some(&legacy_action)
+        .filter(|action| action.get("action_type").and_then(Value::as_str) == Some("system"))
+        .and_then(|action| action.get("system_action"))
+        .filter(|system_action| !system_action.is_null())
+        .cloned();
+
+    let action_type = match fields.remove("action_type") {
+        Some(Value::String(action_type)) if action_type == "system" => Value::from("System"),
+        Some(Value::String(action_type)) => Value::from(action_type),
+        Some(Value::Null) | None if system_action.is_some() => Value::from("System"),
+        Some(Value::Null) | None => return Err(anyhow::anyhow!("{}: missing 'action'", context)),
+        Some(other) => return Err(anyhow::anyhow!("{}: invalid action_type {:?}", context, other)),
+    };
+
+    let mut action = match fields.remove("config") {
+        Some(Value::Mapping(config)) => config,
+        _ => Mapping::new(),
+    };
+    if let Some(Value::Mapping(folder)) = fields.remove("folder") {
+        action.extend(folder);
+    }
+    if let (Some("System"), Some(system_action)) = (action_type.as_str(), system_action) {
+        action.entry(Value::from("system_action")).or_insert(system_action);
+    }
+
+    let tag = serde_json::to_value(&action_type)
+        .with_context(|| format!("{}: invalid action_type", context))?;
+    let action = match serde_json::to_value(Value::Mapping(action)) {
+        Ok(serde_json::Value::Object(action)) => action,
+        Ok(_) => serde_json::Map::new(),
+        Err(e) => return Err(anyhow::anyhow!("{}: invalid action config: {}", context, e)),
+    };
+
+    debug!("🔄 Migrated {} to typed {:?} action", context, action_type);
+    let action = serde_yaml::to_value(legacy_to_tagged(tag, action))
+        .with_context(|| format!("{}: invalid action config", context))?;
+    fields.insert(Value::from("action"), action);
+    Ok(())
+}
+
+// e.g. "button 'Docs' at (1, 2)", for migration errors
+fn button_context(button: &Value) -> String {
+    let label = button.get("label").and_then(Value::as_str).unwrap_or("<unnamed>");
+    let coordinate = |axis: &str| button.get("position")
+        .and_then(|p| p.get(axis))
+        .and_then(Value::as_u64)
+        .map(|n| n.to_string())
+        .unwrap_or_else(|| "?".to_string());
+    format!("button '{}' at ({}, {})", label, coordinate("row"), coordinate("col"))
+}
+
+// Recursively copy keys from `defaults` that are absent in `target`
+fn fill_missing(target: &mut Value, defaults: &Value) {
+    if let (Some(target), Some(defaults)) = (target.as_mapping_mut(), defaults.as_mapping()) {
+        for (key, default) in defaults {
+            match target.get_mut(key) {
+                Some(existing) => fill_missing(existing, default),
+                None => {
+                    target.insert(key.clone(), default.clone());
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_and_order_versions() {
+        assert_eq!(ConfigVersion::parse("1.0").unwrap(), ConfigVersion { major: 1, minor: 0 });
+        assert_eq!(ConfigVersion::parse("2").unwrap(), ConfigVersion { major: 2, minor: 0 });
+        assert_eq!(ConfigVersion::parse("1.1.3").unwrap().to_string(), "1.1");
+        assert!(ConfigVersion::parse("").is_err());
+        assert!(ConfigVersion::parse("v1").is_err());
+        assert!(ConfigVersion::parse("1.10").unwrap() > ConfigVersion::parse("1.9").unwrap());
+    }
+
+    #[test]
+    fn test_migrate_legacy_config() {
+        let yaml = r#"
+version: "1.0"
+ui:
+  summon:
+    hotkeys: ["F11"]
+    edge_trigger: null
+  window:
+    placement: dropdown-top
+    width_px: 1000
+    height_px: 600
+    cell_size_px: 96
+    gap_px: 8
+    opacity: 0.9
+    theme: dark
+profiles:
+  - name: Default
+    hotkey: null
+    pages:
+      - name: Main
+        rows: 3
+        cols: 6
+        buttons:
+          - position: { row: 1, col: 1 }
+            action_type: Open
+            label: Docs
+            icon: null
+            config: { target: "/tmp" }
+            style: null
+            action: null
+"#;
+        let mut config: Value = serde_yaml::from_str(yaml).unwrap();
+        let outcome = migrate(&mut config).unwrap();
+
+        assert!(outcome.migrated());
+        assert_eq!(outcome.from, ConfigVersion { major: 1, minor: 0 });
+        assert_eq!(outcome.to, CURRENT_CONFIG_VERSION);
+        assert_eq!(config["version"], Value::from(CURRENT_CONFIG_VERSION.to_string()));
+
+        // New defaults filled in without touching existing values
+        assert_eq!(config["ui"]["window"]["animation"]["enabled"], Value::from(true));
+        assert_eq!(config["ui"]["window"]["width_px"], Value::from(1000));
+
+        let button = &config["profiles"][0]["pages"][0]["buttons"][0];
+        assert_eq!(button["action"]["type"], Value::from("Open"));
+        assert_eq!(button["action"]["target"], Value::from("/tmp"));
+        assert!(button.get("action_type").is_none());
+        assert!(button.get("config").is_none());
+
+        // Migrating again is a no-op
+        let outcome = migrate(&mut config).unwrap();
+        assert!(!outcome.migrated());
+    }
+
+    #[test]
+    fn test_unversioned_config_is_treated_as_1_0() {
+        let mut config: Value = serde_yaml::from_str("profiles: []").unwrap();
+        assert_eq!(detect_version(&config).unwrap(), UNVERSIONED);
+        let outcome = migrate(&mut config).unwrap();
+        assert_eq!(outcome.from, UNVERSIONED);
+        assert!(config["ui"]["summon"]["hotkeys"].is_sequence());
+    }
+
+    #[test]
+    fn test_refuses_newer_config() {
+        let mut config: Value = serde_yaml::from_str("version: \"9.0\"\nprofiles: []").unwrap();
+        let err = migrate(&mut config).unwrap_err().to_string();
+        assert!(err.contains("newer"), "{}", err);
+        assert_eq!(config["version"], Value::from("9.0"));
+    }
+
+    #[test]
+    fn test_migrates_system_multi_action_and_folder_buttons() {
+        let yaml = r#"
+profiles:
+  - name: Default
+    pages:
+      - name: Main
+        buttons:
+          - position: { row: 1, col: 1 }
+            action_type: system
+            label: Settings
+            config: {}
+            action: { action_type: system, system_action: config }
+          - position: { row: 1, col: 2 }
+            action_type: MultiAction
+            label: Both
+            config:
+              actions:
+                - action_type: Open
+                  config: { target: "/tmp" }
+          - position: { row: 1, col: 3 }
+            action_type: Folder
+            label: Tools
+            action: { action_type: system, system_action: null }
+            folder:
+              page:
+                name: Tools
+                buttons:
+                  - position: { row: 1, col: 1 }
+                    action_type: Terminal
+                    label: Shell
+                    config: { terminal: auto }
+"#;
+        let mut config: Value = serde_yaml::from_str(yaml).unwrap();
+        migrate(&mut config).unwrap();
+
+        let buttons = &config["profiles"][0]["pages"][0]["buttons"];
+        assert_eq!(buttons[0]["action"]["type"], Value::from("System"));
+        assert_eq!(buttons[0]["action"]["system_action"], Value::from("config"));
+        assert_eq!(buttons[1]["action"]["actions"][0]["type"], Value::from("Open"));
+        assert_eq!(buttons[1]["action"]["actions"][0]["target"], Value::from("/tmp"));
+        assert_eq!(buttons[2]["action"]["type"], Value::from("Folder"));
+        assert!(buttons[2].get("folder").is_none());
+        let child = &buttons[2]["action"]["page"]["buttons"][0];
+        assert_eq!(child["action"]["type"], Value::from("Terminal"));
+        assert!(child.get("action_type").is_none());
+    }
+
+    #[test]
+    fn test_fills_literal_1_1_defaults() {
+        let mut config: Value = serde_yaml::from_str("profiles: []").unwrap();
+        migrate(&mut config).unwrap();
+        let ui: Value = serde_yaml::from_str(UI_DEFAULTS_1_1).unwrap();
+        assert_eq!(config["ui"], ui);
+    }
+
+    #[test]
+    fn test_invalid_legacy_button_fails_migration() {
+        let yaml = r#"
+version: "1.0"
+profiles:
+  - name: Default
+    pages:
+      - name: Main
+        buttons:
+          - position: { row: 2, col: 3 }
+            label: Broken
+            config: {}
+"#;
+        let mut config: Value = serde_yaml::from_str(yaml).unwrap();
+        let err = format!("{:#}", migrate(&mut config).unwrap_err());
+        assert!(err.contains("button 'Broken' at (2, 3)"), "{}", err);
+    }
+}
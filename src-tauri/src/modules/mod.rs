@@ -1,8 +1,22 @@
 pub mod config;
+pub mod migration;
+pub mod validation;
+pub mod config_watcher;
 pub mod hotkey;
+pub mod hotkey_backend;
+pub mod hotkey_sequence;
+pub mod hotkey_gesture;
 pub mod action;
+pub mod action_registry;
 pub mod profile;
+pub mod foreground;
+pub mod edge_trigger;
 pub mod logger;
 pub mod window;
+pub mod placement;
 pub mod icon;
-pub mod drag_drop;
\ No newline at end of file
+pub mod drag_drop;
+pub mod keys;
+pub mod input;
+#[cfg(target_os = "linux")]
+pub mod x11;
\ No newline at end of file
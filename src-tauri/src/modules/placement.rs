@@ -0,0 +1,303 @@
+// Where the overlay appears and how big it is: placement styles, monitor choice and the geometry behind them
+use anyhow::{anyhow, Result};
+use serde::{Deserialize, Serialize};
+
+use crate::modules::config::{Page, WindowConfig};
+use crate::modules::edge_trigger::MonitorRect;
+
+/// Gap kept between a dropdown overlay and the screen edge it drops from
+pub const DROPDOWN_EDGE_GAP_PX: i32 = 20;
+
+// Overlay chrome around the grid, in logical pixels (see the overlay's CSS)
+const GRID_PADDING_PX: f64 = 12.0; // 0.75rem on each side
+const BORDER_PX: f64 = 2.0;
+const CONFIG_BUTTON_SPACE_PX: f64 = 48.0; // 2.5rem button + 0.5rem margin above the grid
+
+/// How the overlay is placed on the chosen monitor (`ui.window.placement`)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum Placement {
+    /// Centered horizontally, just below the top edge
+    DropdownTop,
+    /// Centered horizontally, just above the bottom edge
+    DropdownBottom,
+    /// Against the left edge, centered vertically
+    SlideLeft,
+    /// Against the right edge, centered vertically
+    SlideRight,
+    Center,
+    /// Centered on the pointer, on the monitor under it
+    AtCursor,
+    /// Where the overlay was last time; dropdown-top until there is a last time
+    RememberLast,
+}
+
+impl Placement {
+    pub const NAMES: &'static [&'static str] = &[
+        "dropdown-top", "dropdown-bottom", "slide-left", "slide-right", "center", "at-cursor", "remember-last",
+    ];
+
+    pub fn from_name(name: &str) -> Result<Self> {
+        match name.trim().to_lowercase().as_str() {
+            "dropdown-top" => Ok(Placement::DropdownTop),
+            "dropdown-bottom" => Ok(Placement::DropdownBottom),
+            "slide-left" | "left-dock" => Ok(Placement::SlideLeft),
+            "slide-right" | "right-dock" => Ok(Placement::SlideRight),
+            "center" => Ok(Placement::Center),
+            "at-cursor" => Ok(Placement::AtCursor),
+            "remember-last" => Ok(Placement::RememberLast),
+            _ => Err(anyhow!("Unknown placement '{}'; use one of {}", name, Self::NAMES.join(", "))),
+        }
+    }
+
+    /// Whether the spot follows from the screen alone, so a resized overlay can be placed again
+    /// without jumping to the pointer or away from where it was left
+    pub fn is_screen_anchored(self) -> bool {
+        !matches!(self, Placement::AtCursor | Placement::RememberLast)
+    }
+}
+
+/// Which monitor the overlay opens on (`ui.window.monitor`)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MonitorPolicy {
+    Primary,
+    /// The monitor the pointer is on
+    UnderCursor,
+    /// A monitor by its system name, e.g. "\\.\DISPLAY2" or "HDMI-1"
+    Named(String),
+}
+
+impl MonitorPolicy {
+    /// "primary", "cursor", or any other text as a monitor name
+    pub fn from_name(name: &str) -> Self {
+        match name.trim().to_lowercase().as_str() {
+            "" | "primary" => MonitorPolicy::Primary,
+            "cursor" => MonitorPolicy::UnderCursor,
+            _ => MonitorPolicy::Named(name.trim().to_string()),
+        }
+    }
+}
+
+/// A connected monitor as far as placement cares
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DisplayMonitor {
+    pub name: Option<String>,
+    pub bounds: MonitorRect,
+    pub work_area: MonitorRect, // Bounds minus taskbars and docks
+    pub scale_factor: f64,
+    pub primary: bool,
+}
+
+/// The monitor `policy` asks for, falling back to the primary one (or the first) when it
+/// can't be found. None only when there are no monitors at all.
+pub fn select_monitor(monitors: &[DisplayMonitor], policy: &MonitorPolicy, cursor: Option<(i32, i32)>) -> Option<usize> {
+    let primary = monitors.iter().position(|monitor| monitor.primary).or((!monitors.is_empty()).then_some(0));
+    let chosen = match policy {
+        MonitorPolicy::Primary => None,
+        MonitorPolicy::UnderCursor => cursor.and_then(|(x, y)| monitor_at(monitors, x, y)),
+        MonitorPolicy::Named(name) => monitors.iter().position(|monitor| {
+            monitor.name.as_deref().is_some_and(|monitor_name| monitor_name.eq_ignore_ascii_case(name))
+        }),
+    };
+    chosen.or(primary)
+}
+
+fn monitor_at(monitors: &[DisplayMonitor], x: i32, y: i32) -> Option<usize> {
+    monitors.iter().position(|monitor| monitor.bounds.contains(x, y))
+}
+
+/// The monitor the overlay lands on: the one under the pointer for at-cursor, the one holding
+/// the remembered spot for remember-last, otherwise whatever `policy` picks
+pub fn target_monitor(
+    placement: Placement,
+    policy: &MonitorPolicy,
+    monitors: &[DisplayMonitor],
+    cursor: Option<(i32, i32)>,
+    last: Option<(i32, i32)>,
+) -> Option<usize> {
+    let preferred = match (placement, last) {
+        (Placement::AtCursor, _) => cursor.and_then(|(x, y)| monitor_at(monitors, x, y)),
+        (Placement::RememberLast, Some((x, y))) => monitor_at(monitors, x, y),
+        _ => None,
+    };
+    preferred.or_else(|| select_monitor(monitors, policy, cursor))
+}
+
+/// Top-left corner for an overlay of `size` physical pixels.
+///
+/// `last` is the remembered position for remember-last. The result always keeps the overlay
+/// inside the work area of the monitor it lands on, as far as the overlay fits.
+pub fn overlay_position(
+    placement: Placement,
+    policy: &MonitorPolicy,
+    monitors: &[DisplayMonitor],
+    size: (u32, u32),
+    cursor: Option<(i32, i32)>,
+    last: Option<(i32, i32)>,
+) -> Option<(i32, i32)> {
+    let (width, height) = (size.0 as i32, size.1 as i32);
+    let area = monitors[target_monitor(placement, policy, monitors, cursor, last)?].work_area;
+
+    let center_x = area.x + (area.width as i32 - width) / 2;
+    let center_y = area.y + (area.height as i32 - height) / 2;
+    let (x, y) = match placement {
+        Placement::DropdownTop => (center_x, area.y + DROPDOWN_EDGE_GAP_PX),
+        Placement::DropdownBottom => (center_x, area.bottom() - height - DROPDOWN_EDGE_GAP_PX),
+        Placement::SlideLeft => (area.x, center_y),
+        Placement::SlideRight => (area.right() - width, center_y),
+        Placement::Center => (center_x, center_y),
+        Placement::AtCursor => match cursor {
+            Some((cursor_x, cursor_y)) => (cursor_x - width / 2, cursor_y - height / 2),
+            None => (center_x, center_y),
+        },
+        Placement::RememberLast => last.unwrap_or((center_x, area.y + DROPDOWN_EDGE_GAP_PX)),
+    };
+
+    Some((clamp_axis(x, width, area.x, area.right()), clamp_axis(y, height, area.y, area.bottom())))
+}
+
+/// The grid a page shows, in logical pixels, which decides the overlay's size
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct OverlayGrid {
+    pub rows: u32,
+    pub cols: u32,
+    pub cell_size_px: u32,
+    pub gap_px: u32,
+}
+
+impl OverlayGrid {
+    pub fn for_page(page: &Page, window: &WindowConfig) -> Self {
+        Self {
+            rows: page.rows,
+            cols: page.cols,
+            cell_size_px: window.cell_size_px,
+            gap_px: window.gap_px,
+        }
+    }
+
+    /// Physical size of the overlay window on a monitor with this scale factor
+    pub fn window_size(&self, scale_factor: f64) -> (u32, u32) {
+        let span = |count: u32| count as f64 * self.cell_size_px as f64 + count.saturating_sub(1) as f64 * self.gap_px as f64;
+        let width = span(self.cols) + GRID_PADDING_PX * 2.0 + BORDER_PX;
+        let height = span(self.rows) + GRID_PADDING_PX * 2.0 + BORDER_PX + CONFIG_BUTTON_SPACE_PX;
+        ((width * scale_factor).round() as u32, (height * scale_factor).round() as u32)
+    }
+}
+
+// Pull a span back inside [start, end); one that is too large sticks to the start
+fn clamp_axis(position: i32, length: i32, start: i32, end: i32) -> i32 {
+    position.min(end - length).max(start)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rect(x: i32, y: i32, width: u32, height: u32) -> MonitorRect {
+        MonitorRect { x, y, width, height }
+    }
+
+    // A primary 1920x1080 with a 40px taskbar at the bottom, and a 2560x1440 monitor to its right
+    fn monitors() -> Vec<DisplayMonitor> {
+        vec![
+            DisplayMonitor {
+                name: Some("DISPLAY1".to_string()),
+                bounds: rect(0, 0, 1920, 1080),
+                work_area: rect(0, 0, 1920, 1040),
+                scale_factor: 1.0,
+                primary: true,
+            },
+            DisplayMonitor {
+                name: Some("DISPLAY2".to_string()),
+                bounds: rect(1920, -200, 2560, 1440),
+                work_area: rect(1920, -200, 2560, 1440),
+                scale_factor: 1.5,
+                primary: false,
+            },
+        ]
+    }
+
+    #[test]
+    fn test_placement_names() {
+        assert_eq!(Placement::from_name("dropdown-top").unwrap(), Placement::DropdownTop);
+        assert_eq!(Placement::from_name("Right-Dock").unwrap(), Placement::SlideRight);
+        assert!(Placement::from_name("custom").is_err());
+        for name in Placement::NAMES {
+            assert!(Placement::from_name(name).is_ok(), "{}", name);
+        }
+
+        assert_eq!(MonitorPolicy::from_name("Primary"), MonitorPolicy::Primary);
+        assert_eq!(MonitorPolicy::from_name("cursor"), MonitorPolicy::UnderCursor);
+        assert_eq!(MonitorPolicy::from_name("HDMI-1"), MonitorPolicy::Named("HDMI-1".to_string()));
+    }
+
+    #[test]
+    fn test_select_monitor() {
+        let monitors = monitors();
+        assert_eq!(select_monitor(&monitors, &MonitorPolicy::Primary, Some((3000, 0))), Some(0));
+        assert_eq!(select_monitor(&monitors, &MonitorPolicy::UnderCursor, Some((3000, 0))), Some(1));
+        assert_eq!(select_monitor(&monitors, &MonitorPolicy::UnderCursor, None), Some(0));
+        assert_eq!(select_monitor(&monitors, &MonitorPolicy::Named("display2".to_string()), None), Some(1));
+        // A monitor that is not connected falls back to the primary
+        assert_eq!(select_monitor(&monitors, &MonitorPolicy::Named("DISPLAY9".to_string()), None), Some(0));
+        assert_eq!(select_monitor(&[], &MonitorPolicy::Primary, None), None);
+    }
+
+    #[test]
+    fn test_overlay_position() {
+        let monitors = monitors();
+        let size = (1000, 400);
+        let place = |placement, policy: &MonitorPolicy| overlay_position(placement, policy, &monitors, size, Some((2000, 1000)), None);
+
+        assert_eq!(place(Placement::DropdownTop, &MonitorPolicy::Primary), Some((460, 20)));
+        // The bottom placements stay above the taskbar
+        assert_eq!(place(Placement::DropdownBottom, &MonitorPolicy::Primary), Some((460, 620)));
+        assert_eq!(place(Placement::SlideLeft, &MonitorPolicy::Primary), Some((0, 320)));
+        assert_eq!(place(Placement::SlideRight, &MonitorPolicy::Primary), Some((920, 320)));
+        assert_eq!(place(Placement::Center, &MonitorPolicy::Primary), Some((460, 320)));
+        // Positions are in desktop coordinates of the chosen monitor
+        assert_eq!(place(Placement::DropdownTop, &MonitorPolicy::UnderCursor), Some((2700, -180)));
+        assert_eq!(place(Placement::SlideRight, &MonitorPolicy::Named("DISPLAY2".to_string())), Some((3480, 320)));
+
+        // At the cursor, kept on screen near the monitor's left edge
+        assert_eq!(place(Placement::AtCursor, &MonitorPolicy::Primary), Some((1920, 800)));
+        assert_eq!(overlay_position(Placement::AtCursor, &MonitorPolicy::Primary, &monitors, size, None, None), Some((460, 320)));
+
+        // Remember-last falls back to dropdown-top, then reuses the remembered spot
+        assert_eq!(place(Placement::RememberLast, &MonitorPolicy::Primary), Some((460, 20)));
+        let remembered = |last| overlay_position(Placement::RememberLast, &MonitorPolicy::Primary, &monitors, size, None, Some(last));
+        assert_eq!(remembered((2500, 300)), Some((2500, 300)));
+        // A spot that hangs off the screen is pulled back in
+        assert_eq!(remembered((1200, 900)), Some((920, 640)));
+        // A spot on a monitor that was unplugged moves to the primary
+        assert_eq!(remembered((-3000, 0)), Some((0, 0)));
+
+        // Larger than the screen: stick to the top-left of the work area
+        assert_eq!(overlay_position(Placement::Center, &MonitorPolicy::Primary, &monitors, (2000, 1200), None, None), Some((0, 0)));
+        assert_eq!(overlay_position(Placement::Center, &MonitorPolicy::Primary, &[], size, None, None), None);
+    }
+
+    #[test]
+    fn test_overlay_grid_size() {
+        let mut page = Page::default();
+        let window = WindowConfig::default();
+        page.rows = 3;
+        page.cols = 6;
+        let grid = OverlayGrid::for_page(&page, &window);
+        assert_eq!(grid.window_size(1.0), (642, 378));
+        // Scaled for a 150% monitor
+        assert_eq!(grid.window_size(1.5), (963, 567));
+
+        page.rows = 4;
+        page.cols = 8;
+        assert_eq!(OverlayGrid::for_page(&page, &window).window_size(1.0), (850, 482));
+
+        // The size follows the monitor the overlay will open on
+        let monitors = monitors();
+        let index = target_monitor(Placement::DropdownTop, &MonitorPolicy::UnderCursor, &monitors, Some((3000, 0)), None).unwrap();
+        assert_eq!(grid.window_size(monitors[index].scale_factor), (963, 567));
+
+        page.rows = 0;
+        assert_eq!(OverlayGrid::for_page(&page, &window).window_size(1.0).1, 74);
+    }
+}
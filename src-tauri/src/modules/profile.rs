@@ -1,15 +1,35 @@
 // Profile management module
-use anyhow::Result;
+use anyhow::{Context, Result};
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
+use std::path::{Path, PathBuf};
+use std::sync::mpsc;
+use std::thread;
+use std::time::Duration;
 use tracing::{info, warn, debug};
-use crate::modules::config::{QDeckConfig, Profile, Page};
+use crate::modules::config::{self, QDeckConfig, Profile, Page, ActionButton, ButtonLocation};
+
+/// File name of the persisted profile state, stored beside config.yaml
+pub const PROFILE_STATE_FILE: &str = "profile_state.yaml";
+
+// Quiet period before a burst of switches is written to disk
+const STATE_SAVE_DEBOUNCE: Duration = Duration::from_millis(500);
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ProfileState {
     pub current_profile_index: usize,
     pub current_page_index: usize,
     pub last_active_pages: HashMap<String, usize>, // profile_name -> page_index
+    #[serde(default)]
+    pub folder_stack: Vec<FolderFrame>, // Open folders, outermost first
+}
+
+/// An open folder, identified by the position of its Folder button in the parent page
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FolderFrame {
+    pub row: u32,
+    pub col: u32,
+    pub label: String,
 }
 
 impl Default for ProfileState {
@@ -18,6 +38,107 @@ impl Default for ProfileState {
             current_profile_index: 0,
             current_page_index: 0,
             last_active_pages: HashMap::new(),
+            folder_stack: Vec::new(),
+        }
+    }
+}
+
+/// On-disk form of `ProfileState`, keyed by profile name so it survives reordering
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct PersistedProfileState {
+    pub current_profile: Option<String>,
+    #[serde(default)]
+    pub profiles: BTreeMap<String, PersistedProfileEntry>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PersistedProfileEntry {
+    /// Position of the profile when saved; used to follow renames
+    pub index: usize,
+    pub last_page: usize,
+}
+
+impl PersistedProfileState {
+    pub fn load(path: &Path) -> Result<Self> {
+        let content = std::fs::read_to_string(path)
+            .with_context(|| format!("Failed to read profile state {}", path.display()))?;
+        serde_yaml::from_str(&content)
+            .with_context(|| format!("Failed to parse profile state {}", path.display()))
+    }
+
+    pub fn save(&self, path: &Path) -> Result<()> {
+        let yaml_content = serde_yaml::to_string(self)
+            .context("Failed to serialize profile state")?;
+        config::write_atomic(path, &yaml_content)
+    }
+
+    // Entry for a config profile: by name, or an unclaimed entry at the same index (a rename)
+    fn entry_for<'a>(&'a self, index: usize, profile: &Profile, config: &QDeckConfig) -> Option<(&'a str, &'a PersistedProfileEntry)> {
+        if let Some((name, entry)) = self.profiles.get_key_value(&profile.name) {
+            return Some((name.as_str(), entry));
+        }
+
+        self.profiles.iter()
+            .find(|(name, entry)| entry.index == index && !config.profiles.iter().any(|p| &p.name == *name))
+            .map(|(name, entry)| (name.as_str(), entry))
+    }
+}
+
+// Writes profile state on a background thread once switching has settled
+struct StatePersister {
+    sender: Option<mpsc::Sender<PersistedProfileState>>,
+    handle: Option<thread::JoinHandle<()>>,
+}
+
+impl StatePersister {
+    fn spawn(path: PathBuf, debounce: Duration) -> Self {
+        let (sender, receiver) = mpsc::channel::<PersistedProfileState>();
+
+        let handle = thread::spawn(move || {
+            let mut last_written: Option<PersistedProfileState> = None;
+
+            while let Ok(mut pending) = receiver.recv() {
+                // Keep only the newest snapshot until the debounce window passes quietly
+                loop {
+                    match receiver.recv_timeout(debounce) {
+                        Ok(newer) => pending = newer,
+                        Err(mpsc::RecvTimeoutError::Timeout) => break,
+                        Err(mpsc::RecvTimeoutError::Disconnected) => break,
+                    }
+                }
+
+                if last_written.as_ref() == Some(&pending) {
+                    continue;
+                }
+                match pending.save(&path) {
+                    Ok(_) => {
+                        debug!("💾 Saved profile state to: {}", path.display());
+                        last_written = Some(pending);
+                    }
+                    Err(e) => warn!("⚠️ Failed to save profile state: {:#}", e),
+                }
+            }
+        });
+
+        Self {
+            sender: Some(sender),
+            handle: Some(handle),
+        }
+    }
+
+    fn schedule(&self, state: PersistedProfileState) {
+        if let Some(sender) = &self.sender {
+            let _ = sender.send(state);
+        }
+    }
+}
+
+impl Drop for StatePersister {
+    fn drop(&mut self) {
+        // Closing the channel flushes the pending snapshot and ends the thread
+        self.sender.take();
+        if let Some(handle) = self.handle.take() {
+            let _ = handle.join();
         }
     }
 }
@@ -42,6 +163,9 @@ pub struct PageInfo {
 
 pub struct ProfileManager {
     state: ProfileState,
+    profile_names: Vec<String>, // Profile order the state was last computed against
+    restored: Option<PersistedProfileState>, // Loaded from disk, applied by initialize_from_config
+    persister: Option<StatePersister>,
 }
 
 impl ProfileManager {
@@ -50,9 +174,98 @@ impl ProfileManager {
         
         Ok(Self {
             state: ProfileState::default(),
+            profile_names: Vec::new(),
+            restored: None,
+            persister: None,
         })
     }
 
+    /// Create a manager that restores and saves its state in `state_path`
+    pub fn with_state_file(state_path: PathBuf) -> Result<Self> {
+        let mut manager = Self::new()?;
+
+        if state_path.exists() {
+            match PersistedProfileState::load(&state_path) {
+                Ok(persisted) => {
+                    info!("📋 Loaded profile state from: {}", state_path.display());
+                    manager.restored = Some(persisted);
+                }
+                Err(e) => warn!("⚠️ Ignoring unreadable profile state: {:#}", e),
+            }
+        }
+
+        manager.persister = Some(StatePersister::spawn(state_path, STATE_SAVE_DEBOUNCE));
+        Ok(manager)
+    }
+
+    /// Snapshot of the current state keyed by profile name
+    pub fn persisted_state(&self) -> PersistedProfileState {
+        let mut profiles = BTreeMap::new();
+        for (index, name) in self.profile_names.iter().enumerate() {
+            let last_page = if index == self.state.current_profile_index {
+                self.state.current_page_index
+            } else {
+                self.state.last_active_pages.get(name).copied().unwrap_or(0)
+            };
+            profiles.insert(name.clone(), PersistedProfileEntry { index, last_page });
+        }
+
+        PersistedProfileState {
+            current_profile: self.profile_names.get(self.state.current_profile_index).cloned(),
+            profiles,
+        }
+    }
+
+    // Queue a debounced save of the current state
+    fn persist(&mut self, config: &QDeckConfig) {
+        self.profile_names = config.profiles.iter().map(|p| p.name.clone()).collect();
+        if let Some(persister) = &self.persister {
+            persister.schedule(self.persisted_state());
+        }
+    }
+
+    // Map a name-keyed snapshot onto `config`, following renames and dropping removed profiles
+    fn apply_persisted(&mut self, persisted: &PersistedProfileState, config: &QDeckConfig) {
+        let mut last_active_pages = HashMap::new();
+        let mut current = None;
+
+        for (index, profile) in config.profiles.iter().enumerate() {
+            let max_page = profile.pages.len().saturating_sub(1);
+            let last_page = match persisted.entry_for(index, profile, config) {
+                Some((name, entry)) => {
+                    if name != profile.name {
+                        info!("📋 Profile '{}' was renamed to '{}', keeping its state", name, profile.name);
+                    }
+                    if persisted.current_profile.as_deref() == Some(name) {
+                        current = Some(index);
+                    }
+                    entry.last_page
+                }
+                None => self.state.last_active_pages.get(&profile.name).copied().unwrap_or(0),
+            };
+            last_active_pages.insert(profile.name.clone(), last_page.min(max_page));
+        }
+
+        let current_profile_index = match (current, &persisted.current_profile) {
+            (Some(index), _) => index,
+            (None, Some(name)) => {
+                warn!("⚠️ Last active profile '{}' no longer exists, falling back to the first profile", name);
+                0
+            }
+            (None, None) => self.state.current_profile_index,
+        };
+
+        if let Some(profile) = config.profiles.get(current_profile_index) {
+            let page_index = last_active_pages.get(&profile.name).copied().unwrap_or(0);
+            if current_profile_index != self.state.current_profile_index || page_index != self.state.current_page_index {
+                self.state.folder_stack.clear();
+            }
+            self.state.current_page_index = page_index;
+        }
+        self.state.current_profile_index = current_profile_index;
+        self.state.last_active_pages = last_active_pages;
+    }
+
     /// Get current profile state
     pub fn get_state(&self) -> &ProfileState {
         &self.state
@@ -110,6 +323,7 @@ impl ProfileManager {
 
         // Update current profile
         self.state.current_profile_index = profile_index;
+        self.state.folder_stack.clear();
 
         // Get the last active page for this profile, or default to 0
         let page_index = self.state.last_active_pages
@@ -124,6 +338,8 @@ impl ProfileManager {
                profile.name, page_index, 
                profile.pages.get(page_index).map(|p| p.name.as_str()).unwrap_or("Unknown"));
 
+        self.persist(config);
+
         Ok(ProfileInfo {
             name: profile.name.clone(),
             index: profile_index,
@@ -142,6 +358,20 @@ impl ProfileManager {
         self.switch_to_profile(profile_index, config)
     }
 
+    /// Switch to a page of a profile by their names (the target of a page hotkey)
+    pub fn switch_to_page_by_name(&mut self, profile_name: &str, page_name: &str, config: &QDeckConfig) -> Result<ProfileInfo> {
+        let profile_index = config.profiles.iter()
+            .position(|p| p.name == profile_name)
+            .ok_or_else(|| anyhow::anyhow!("Profile '{}' not found", profile_name))?;
+        let page_index = config.profiles[profile_index].pages.iter()
+            .position(|p| p.name == page_name)
+            .ok_or_else(|| anyhow::anyhow!("Page '{}' not found in profile '{}'", page_name, profile_name))?;
+
+        self.switch_to_profile(profile_index, config)?;
+        self.switch_to_page(page_index, config)?;
+        self.get_current_profile(config)
+    }
+
     /// Get pages for current profile
     pub fn get_current_profile_pages(&self, config: &QDeckConfig) -> Result<Vec<PageInfo>> {
         if self.state.current_profile_index >= config.profiles.len() {
@@ -178,23 +408,13 @@ impl ProfileManager {
         }).collect())
     }
 
-    /// Get current page information
+    /// Get current page information (the innermost open folder, if any)
     pub fn get_current_page(&self, config: &QDeckConfig) -> Result<PageInfo> {
-        if self.state.current_profile_index >= config.profiles.len() {
-            return Err(anyhow::anyhow!("Current profile index {} is out of bounds", self.state.current_profile_index));
-        }
-
-        let profile = &config.profiles[self.state.current_profile_index];
-        let page_index = self.state.current_page_index;
-
-        if page_index >= profile.pages.len() {
-            return Err(anyhow::anyhow!("Current page index {} is out of bounds for profile '{}'", page_index, profile.name));
-        }
+        let (_profile, page) = self.get_current_profile_and_page(config)?;
 
-        let page = &profile.pages[page_index];
         Ok(PageInfo {
             name: page.name.clone(),
-            index: page_index,
+            index: self.state.current_page_index,
             rows: page.rows,
             cols: page.cols,
             button_count: page.buttons.len(),
@@ -218,17 +438,21 @@ impl ProfileManager {
 
         // Update current page
         self.state.current_page_index = page_index;
+        self.state.folder_stack.clear();
 
         // Remember this as the last active page for this profile
         self.state.last_active_pages.insert(profile.name.clone(), page_index);
 
-        Ok(PageInfo {
+        let page_info = PageInfo {
             name: page.name.clone(),
             index: page_index,
             rows: page.rows,
             cols: page.cols,
             button_count: page.buttons.len(),
-        })
+        };
+        self.persist(config);
+
+        Ok(page_info)
     }
 
     /// Switch to next page in current profile (circular)
@@ -266,8 +490,20 @@ impl ProfileManager {
         self.switch_to_page(prev_page_index, config)
     }
 
-    /// Get the current profile and page data
+    /// Get the current profile and page data (the innermost open folder, if any)
     pub fn get_current_profile_and_page<'a>(&self, config: &'a QDeckConfig) -> Result<(&'a Profile, &'a Page)> {
+        let (profile, root_page) = self.get_root_profile_and_page(config)?;
+
+        let mut page = root_page;
+        for frame in &self.state.folder_stack {
+            page = Self::resolve_folder(profile, page, frame.row, frame.col)?.1;
+        }
+
+        Ok((profile, page))
+    }
+
+    /// Get the current profile and top-level page, ignoring open folders
+    fn get_root_profile_and_page<'a>(&self, config: &'a QDeckConfig) -> Result<(&'a Profile, &'a Page)> {
         if self.state.current_profile_index >= config.profiles.len() {
             return Err(anyhow::anyhow!("Current profile index {} is out of bounds", self.state.current_profile_index));
         }
@@ -283,6 +519,110 @@ impl ProfileManager {
         Ok((profile, page))
     }
 
+    /// Find the Folder button at (row, col) in `page` and resolve the page it opens
+    fn resolve_folder<'a>(profile: &'a Profile, page: &'a Page, row: u32, col: u32) -> Result<(&'a ActionButton, &'a Page)> {
+        let button = page.buttons.iter()
+            .find(|b| b.position.row == row && b.position.col == col)
+            .ok_or_else(|| anyhow::anyhow!("No button at ({}, {}) in page '{}'", row, col, page.name))?;
+
+        let folder = button.folder()
+            .ok_or_else(|| anyhow::anyhow!("Button '{}' is not a folder", button.label))?;
+
+        if let Some(child) = &folder.page {
+            return Ok((button, child.as_ref()));
+        }
+
+        if let Some(page_ref) = &folder.page_ref {
+            let target = profile.pages.iter()
+                .find(|p| &p.name == page_ref)
+                .ok_or_else(|| anyhow::anyhow!("Folder '{}' references unknown page '{}'", button.label, page_ref))?;
+            return Ok((button, target));
+        }
+
+        Err(anyhow::anyhow!("Folder button '{}' has no target page", button.label))
+    }
+
+    /// Open the Folder button at (row, col) of the current page
+    pub fn enter_folder(&mut self, row: u32, col: u32, config: &QDeckConfig) -> Result<PageInfo> {
+        let (profile, page) = self.get_current_profile_and_page(config)?;
+        let (button, target) = Self::resolve_folder(profile, page, row, col)?;
+
+        info!("📁 Entering folder '{}' -> page '{}' (depth {})", button.label, target.name, self.state.folder_stack.len() + 1);
+
+        self.state.folder_stack.push(FolderFrame {
+            row,
+            col,
+            label: button.label.clone(),
+        });
+
+        self.get_current_page(config)
+    }
+
+    /// Navigate to the page containing a Folder button and open it
+    pub fn open_folder_at(&mut self, location: &ButtonLocation, config: &QDeckConfig) -> Result<PageInfo> {
+        if location.profile_index != self.state.current_profile_index {
+            self.switch_to_profile(location.profile_index, config)?;
+        }
+        self.switch_to_page(location.page_index, config)?;
+
+        let mut page = &config.profiles[location.profile_index].pages[location.page_index];
+        for &folder_index in location.folder_path.iter().chain(std::iter::once(&location.button_index)) {
+            let button = page.buttons.get(folder_index)
+                .ok_or_else(|| anyhow::anyhow!("Button index {} is out of bounds for page '{}'", folder_index, page.name))?;
+            self.enter_folder(button.position.row, button.position.col, config)?;
+            page = self.get_current_profile_and_page(config)?.1;
+        }
+
+        self.get_current_page(config)
+    }
+
+    /// Close the innermost open folder
+    pub fn exit_folder(&mut self, config: &QDeckConfig) -> Result<PageInfo> {
+        match self.state.folder_stack.pop() {
+            Some(frame) => info!("📁 Leaving folder '{}'", frame.label),
+            None => debug!("📁 Not inside a folder, staying on current page"),
+        }
+
+        self.get_current_page(config)
+    }
+
+    /// Close all open folders and return to the top-level page
+    pub fn exit_all_folders(&mut self, config: &QDeckConfig) -> Result<PageInfo> {
+        if !self.state.folder_stack.is_empty() {
+            info!("📁 Leaving {} folder(s)", self.state.folder_stack.len());
+            self.state.folder_stack.clear();
+        }
+
+        self.get_current_page(config)
+    }
+
+    /// Drop folder frames that no longer resolve against the config
+    fn clamp_folder_stack(&mut self, config: &QDeckConfig) {
+        let (profile, mut page) = match self.get_root_profile_and_page(config) {
+            Ok(pair) => pair,
+            Err(_) => {
+                self.state.folder_stack.clear();
+                return;
+            }
+        };
+
+        let mut valid_depth = 0;
+        for frame in &self.state.folder_stack {
+            match Self::resolve_folder(profile, page, frame.row, frame.col) {
+                Ok((_, child)) => {
+                    page = child;
+                    valid_depth += 1;
+                }
+                Err(e) => {
+                    warn!("⚠️ Closing stale folder '{}': {}", frame.label, e);
+                    break;
+                }
+            }
+        }
+
+        self.state.folder_stack.truncate(valid_depth);
+    }
+
     /// Initialize state from config (called on startup)
     pub fn initialize_from_config(&mut self, config: &QDeckConfig) -> Result<()> {
         info!("🔧 Initializing ProfileManager from config");
@@ -292,6 +632,13 @@ impl ProfileManager {
             return Ok(());
         }
 
+        // Restore the saved state on startup; on reload carry the live state across the edit
+        let persisted = match self.restored.take() {
+            Some(persisted) => persisted,
+            None => self.persisted_state(),
+        };
+        self.apply_persisted(&persisted, config);
+
         // Ensure current profile index is valid
         if self.state.current_profile_index >= config.profiles.len() {
             warn!("⚠️ Current profile index {} is out of bounds, resetting to 0", self.state.current_profile_index);
@@ -306,6 +653,8 @@ impl ProfileManager {
             self.state.current_page_index = 0;
         }
 
+        self.clamp_folder_stack(config);
+
         // Initialize last active pages for all profiles
         for (_index, profile) in config.profiles.iter().enumerate() {
             if !self.state.last_active_pages.contains_key(&profile.name) {
@@ -319,6 +668,8 @@ impl ProfileManager {
                profile.pages.get(self.state.current_page_index).map(|p| p.name.as_str()).unwrap_or("Unknown"),
                self.state.current_page_index);
 
+        self.persist(config);
+
         Ok(())
     }
 
@@ -330,7 +681,11 @@ impl ProfileManager {
 
     /// Get navigation context (for breadcrumbs, etc.)
     pub fn get_navigation_context(&self, config: &QDeckConfig) -> Result<NavigationContext> {
-        let (profile, page) = self.get_current_profile_and_page(config)?;
+        let (profile, root_page) = self.get_root_profile_and_page(config)?;
+        let (_, page) = self.get_current_profile_and_page(config)?;
+
+        let mut breadcrumbs = vec![root_page.name.clone()];
+        breadcrumbs.extend(self.state.folder_stack.iter().map(|frame| frame.label.clone()));
         
         Ok(NavigationContext {
             profile_name: profile.name.clone(),
@@ -341,6 +696,8 @@ impl ProfileManager {
             total_pages: profile.pages.len(),
             has_previous_page: self.state.current_page_index > 0,
             has_next_page: self.state.current_page_index < profile.pages.len().saturating_sub(1),
+            breadcrumbs,
+            folder_depth: self.state.folder_stack.len(),
         })
     }
 }
@@ -355,12 +712,15 @@ pub struct NavigationContext {
     pub total_pages: usize,
     pub has_previous_page: bool,
     pub has_next_page: bool,
+    pub breadcrumbs: Vec<String>, // Top-level page name followed by open folder labels
+    pub folder_depth: usize,
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::modules::config::{QDeckConfig, Profile, Page, UIConfig, SummonConfig, WindowConfig, AnimationConfig};
+    use crate::modules::config::{QDeckConfig, Profile, Page, UIConfig, SummonConfig, SummonMode, WindowConfig, AnimationConfig, ActionButton, FolderConfig, Position};
+    use crate::modules::action::ActionConfig;
 
     fn create_test_config() -> QDeckConfig {
         QDeckConfig {
@@ -369,9 +729,16 @@ mod tests {
                 summon: SummonConfig {
                     hotkeys: vec!["F11".to_string()],
                     edge_trigger: None,
+                    sequence_timeout_ms: 1500,
+                    fallback_hotkeys: vec![],
+                    mode: SummonMode::Toggle,
+                    hold_threshold_ms: 300,
+                    double_tap: None,
+                    double_tap_interval_ms: 300,
                 },
                 window: WindowConfig {
                     placement: "dropdown-top".to_string(),
+                    monitor: "primary".to_string(),
                     width_px: 1000,
                     height_px: 600,
                     cell_size_px: 96,
@@ -388,17 +755,20 @@ mod tests {
                 Profile {
                     name: "Profile1".to_string(),
                     hotkey: Some("Ctrl+1".to_string()),
+                    match_rule: None,
                     pages: vec![
                         Page {
                             name: "Page1".to_string(),
                             rows: 3,
                             cols: 4,
+                            hotkey: None,
                             buttons: vec![],
                         },
                         Page {
                             name: "Page2".to_string(),
                             rows: 2,
                             cols: 3,
+                            hotkey: None,
                             buttons: vec![],
                         },
                     ],
@@ -406,11 +776,13 @@ mod tests {
                 Profile {
                     name: "Profile2".to_string(),
                     hotkey: Some("Ctrl+2".to_string()),
+                    match_rule: None,
                     pages: vec![
                         Page {
                             name: "MainPage".to_string(),
                             rows: 4,
                             cols: 5,
+                            hotkey: None,
                             buttons: vec![],
                         },
                     ],
@@ -451,6 +823,22 @@ mod tests {
         assert_eq!(profile_info.index, 1);
     }
 
+    #[test]
+    fn test_switch_to_page_by_name() {
+        let mut manager = ProfileManager::new().unwrap();
+        let config = create_test_config();
+        manager.initialize_from_config(&config).unwrap();
+        manager.switch_to_profile(1, &config).unwrap();
+
+        let profile_info = manager.switch_to_page_by_name("Profile1", "Page2", &config).unwrap();
+        assert_eq!(profile_info.index, 0);
+        assert_eq!(profile_info.current_page_index, 1);
+
+        // An unknown page leaves the current profile alone
+        assert!(manager.switch_to_page_by_name("Profile2", "Nope", &config).is_err());
+        assert_eq!(manager.state.current_profile_index, 0);
+    }
+
     #[test]
     fn test_page_navigation() {
         let mut manager = ProfileManager::new().unwrap();
@@ -489,4 +877,163 @@ mod tests {
         let profile_info = manager.switch_to_profile(0, &config).unwrap();
         assert_eq!(profile_info.current_page_index, 1);
     }
+
+    fn folder_button(row: u32, col: u32, label: &str, folder: FolderConfig) -> ActionButton {
+        ActionButton {
+            id: String::new(),
+            position: Position { row, col },
+            label: label.to_string(),
+            icon: None,
+            style: None,
+            hotkey: None,
+            action: ActionConfig::Folder(folder),
+        }
+    }
+
+    #[test]
+    fn test_folder_navigation() {
+        let mut manager = ProfileManager::new().unwrap();
+        let mut config = create_test_config();
+
+        let inner = Page {
+            name: "Inner".to_string(),
+            rows: 2,
+            cols: 2,
+            hotkey: None,
+            buttons: vec![],
+        };
+        let tools = Page {
+            name: "Tools".to_string(),
+            rows: 4,
+            cols: 8,
+            hotkey: None,
+            buttons: vec![folder_button(1, 1, "More", FolderConfig { page: Some(Box::new(inner)), page_ref: None })],
+        };
+        config.profiles[0].pages[0].buttons.push(
+            folder_button(2, 3, "Tools", FolderConfig { page: Some(Box::new(tools)), page_ref: None })
+        );
+        config.profiles[0].pages[0].buttons.push(
+            folder_button(1, 1, "Second", FolderConfig { page: None, page_ref: Some("Page2".to_string()) })
+        );
+
+        manager.initialize_from_config(&config).unwrap();
+
+        let page_info = manager.enter_folder(2, 3, &config).unwrap();
+        assert_eq!(page_info.name, "Tools");
+        assert_eq!((page_info.rows, page_info.cols), (4, 8));
+
+        let page_info = manager.enter_folder(1, 1, &config).unwrap();
+        assert_eq!(page_info.name, "Inner");
+
+        let context = manager.get_navigation_context(&config).unwrap();
+        assert_eq!(context.breadcrumbs, vec!["Page1", "Tools", "More"]);
+        assert_eq!(context.folder_depth, 2);
+
+        let page_info = manager.exit_folder(&config).unwrap();
+        assert_eq!(page_info.name, "Tools");
+
+        let page_info = manager.exit_all_folders(&config).unwrap();
+        assert_eq!(page_info.name, "Page1");
+
+        // Folder by reference to a named page
+        let page_info = manager.enter_folder(1, 1, &config).unwrap();
+        assert_eq!(page_info.name, "Page2");
+
+        // Switching pages closes open folders
+        manager.switch_to_page(0, &config).unwrap();
+        assert!(manager.get_state().folder_stack.is_empty());
+
+        // Not a folder / empty cell
+        assert!(manager.enter_folder(3, 3, &config).is_err());
+    }
+
+    #[test]
+    fn test_open_folder_at_location() {
+        let mut config = create_test_config();
+        let mut inner = Page { name: "Inner".to_string(), ..Page::default() };
+        inner.buttons.push(folder_button(1, 1, "Deep", FolderConfig { page: None, page_ref: Some("MainPage".to_string()) }));
+        config.profiles[1].pages[0].buttons.push(folder_button(2, 3, "Outer", FolderConfig {
+            page: Some(Box::new(inner)),
+            page_ref: None,
+        }));
+
+        let mut manager = ProfileManager::new().unwrap();
+        let location = ButtonLocation {
+            profile_index: 1,
+            page_index: 0,
+            folder_path: vec![0],
+            button_index: 0,
+        };
+        let page = manager.open_folder_at(&location, &config).unwrap();
+
+        assert_eq!(page.name, "MainPage");
+        assert_eq!(manager.get_state().current_profile_index, 1);
+        let labels: Vec<_> = manager.get_state().folder_stack.iter().map(|f| f.label.as_str()).collect();
+        assert_eq!(labels, vec!["Outer", "Deep"]);
+    }
+
+    #[test]
+    fn test_stale_folder_stack_is_clamped() {
+        let mut manager = ProfileManager::new().unwrap();
+        let mut config = create_test_config();
+        config.profiles[0].pages[0].buttons.push(
+            folder_button(1, 1, "Second", FolderConfig { page: None, page_ref: Some("Page2".to_string()) })
+        );
+
+        manager.initialize_from_config(&config).unwrap();
+        manager.enter_folder(1, 1, &config).unwrap();
+
+        config.profiles[0].pages[0].buttons.clear();
+        manager.initialize_from_config(&config).unwrap();
+        assert!(manager.get_state().folder_stack.is_empty());
+    }
+
+    #[test]
+    fn test_state_is_restored_from_file() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let state_path = dir.path().join(PROFILE_STATE_FILE);
+        let config = create_test_config();
+
+        {
+            let mut manager = ProfileManager::with_state_file(state_path.clone()).unwrap();
+            manager.initialize_from_config(&config).unwrap();
+            manager.switch_to_page(1, &config).unwrap();
+            manager.switch_to_profile(1, &config).unwrap();
+            // Dropping the manager flushes the pending save
+        }
+
+        let saved = PersistedProfileState::load(&state_path).unwrap();
+        assert_eq!(saved.current_profile.as_deref(), Some("Profile2"));
+        assert_eq!(saved.profiles["Profile1"].last_page, 1);
+
+        let mut manager = ProfileManager::with_state_file(state_path).unwrap();
+        manager.initialize_from_config(&config).unwrap();
+        assert_eq!(manager.get_state().current_profile_index, 1);
+        let profile_info = manager.switch_to_profile(0, &config).unwrap();
+        assert_eq!(profile_info.current_page_index, 1);
+    }
+
+    #[test]
+    fn test_state_follows_renames_and_removals() {
+        let mut manager = ProfileManager::new().unwrap();
+        let mut config = create_test_config();
+        manager.initialize_from_config(&config).unwrap();
+        manager.switch_to_page(1, &config).unwrap();
+
+        // Renamed in place keeps its page and stays current
+        config.profiles[0].name = "Work".to_string();
+        manager.initialize_from_config(&config).unwrap();
+        assert_eq!(manager.get_state().current_profile_index, 0);
+        assert_eq!(manager.get_state().current_page_index, 1);
+        assert_eq!(manager.persisted_state().profiles["Work"].last_page, 1);
+        assert!(!manager.persisted_state().profiles.contains_key("Profile1"));
+
+        // Removing the current profile falls back to the first remaining one
+        manager.switch_to_profile(1, &config).unwrap();
+        config.profiles.remove(1);
+        manager.initialize_from_config(&config).unwrap();
+        assert_eq!(manager.get_state().current_profile_index, 0);
+        assert_eq!(manager.get_state().current_page_index, 1);
+        assert_eq!(manager.persisted_state().profiles.len(), 1);
+    }
 }
\ No newline at end of file
@@ -0,0 +1,543 @@
+// Structured config validation
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+
+use crate::modules::action::ActionConfig;
+use crate::modules::config::{ActionButton, FolderConfig, Page, Profile, QDeckConfig, SummonMode};
+use crate::modules::edge_trigger::ScreenEdge;
+use crate::modules::placement::Placement;
+use crate::modules::hotkey::{self, HotkeyBinding};
+use crate::modules::keys;
+use crate::modules::migration::{ConfigVersion, CURRENT_CONFIG_VERSION};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ValidationSeverity {
+    Error,
+    Warning,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ValidationIssue {
+    pub severity: ValidationSeverity,
+    /// Location in the config document, e.g. `profiles[0].pages[1].buttons[3].action`
+    pub path: String,
+    /// Set when the issue belongs to a button, so the editor can highlight it
+    pub button_id: Option<String>,
+    pub message: String,
+}
+
+impl fmt::Display for ValidationIssue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {}", self.path, self.message)
+    }
+}
+
+/// Every problem found in a config; errors make the config unusable, warnings don't
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ValidationReport {
+    pub valid: bool,
+    pub issues: Vec<ValidationIssue>,
+}
+
+impl ValidationReport {
+    pub fn errors(&self) -> impl Iterator<Item = &ValidationIssue> {
+        self.issues.iter().filter(|i| i.severity == ValidationSeverity::Error)
+    }
+
+    pub fn warnings(&self) -> impl Iterator<Item = &ValidationIssue> {
+        self.issues.iter().filter(|i| i.severity == ValidationSeverity::Warning)
+    }
+
+    pub fn has_errors(&self) -> bool {
+        self.errors().next().is_some()
+    }
+
+    /// Collapse the errors into a single `anyhow` error (warnings are ignored)
+    pub fn into_result(self) -> anyhow::Result<()> {
+        let errors: Vec<String> = self.errors().map(|e| e.to_string()).collect();
+        match errors.len() {
+            0 => Ok(()),
+            1 => Err(anyhow::anyhow!("Invalid config: {}", errors[0])),
+            n => Err(anyhow::anyhow!("Invalid config ({} errors): {}", n, errors.join("; "))),
+        }
+    }
+
+    fn push(&mut self, severity: ValidationSeverity, path: &str, button_id: Option<&str>, message: String) {
+        self.issues.push(ValidationIssue {
+            severity,
+            path: path.to_string(),
+            button_id: button_id.filter(|id| !id.is_empty()).map(|id| id.to_string()),
+            message,
+        });
+    }
+
+    fn error(&mut self, path: &str, message: String) {
+        self.push(ValidationSeverity::Error, path, None, message);
+    }
+
+    fn warning(&mut self, path: &str, message: String) {
+        self.push(ValidationSeverity::Warning, path, None, message);
+    }
+}
+
+/// Check a whole config and report every problem instead of stopping at the first
+pub fn validate(config: &QDeckConfig) -> ValidationReport {
+    let mut report = ValidationReport::default();
+
+    match ConfigVersion::parse(&config.version) {
+        Ok(version) if version > CURRENT_CONFIG_VERSION => report.error("version", format!(
+            "Config version {} is newer than the newest supported version {}",
+            version, CURRENT_CONFIG_VERSION
+        )),
+        Ok(_) => {}
+        Err(e) => report.error("version", e.to_string()),
+    }
+
+    // UI config
+    let window = &config.ui.window;
+    if window.width_px == 0 || window.height_px == 0 {
+        report.error("ui.window", "Window dimensions must be greater than 0".to_string());
+    }
+    if let Err(e) = Placement::from_name(&window.placement) {
+        report.warning("ui.window.placement", format!("{}; the overlay will use dropdown-top", e));
+    }
+    if !(0.0..=1.0).contains(&window.opacity) {
+        report.error("ui.window.opacity", "Window opacity must be between 0.0 and 1.0".to_string());
+    } else if window.opacity == 0.0 {
+        report.warning("ui.window.opacity", "Window opacity is 0, the overlay will be invisible".to_string());
+    }
+    if let Some(edge_trigger) = &config.ui.summon.edge_trigger {
+        for (index, edge) in edge_trigger.edges.iter().enumerate() {
+            if let Err(e) = ScreenEdge::from_name(edge) {
+                report.error(&format!("ui.summon.edge_trigger.edges[{}]", index), e.to_string());
+            }
+        }
+        if edge_trigger.enabled && edge_trigger.edges.is_empty() {
+            report.warning("ui.summon.edge_trigger.edges", "Edge trigger is enabled but no edges are listed".to_string());
+        }
+    }
+
+    // Profiles
+    if config.profiles.is_empty() {
+        report.error("profiles", "At least one profile must be defined".to_string());
+    }
+
+    let mut profile_names = HashSet::new();
+    let mut button_ids = HashMap::new();
+    for (profile_index, profile) in config.profiles.iter().enumerate() {
+        let profile_path = format!("profiles[{}]", profile_index);
+        if profile.name.is_empty() {
+            report.error(&profile_path, "Profile name cannot be empty".to_string());
+        } else if !profile_names.insert(profile.name.as_str()) {
+            report.warning(&profile_path, format!(
+                "Duplicate profile name '{}'; switching by name picks the first one", profile.name
+            ));
+        }
+
+        if profile.pages.is_empty() {
+            report.error(&profile_path, format!("Profile '{}' must have at least one page", profile.name));
+        }
+
+        if let Some(rule) = &profile.match_rule {
+            let match_path = format!("{}.match", profile_path);
+            if rule.exe.is_none() && rule.window_class.is_none() && rule.title.is_none() {
+                report.warning(&match_path, format!(
+                    "Profile '{}' has an empty match rule and will never be selected automatically", profile.name
+                ));
+            }
+            if let Some(pattern) = &rule.title {
+                if let Err(e) = regex::Regex::new(pattern) {
+                    report.error(&format!("{}.title", match_path), format!("Invalid title pattern: {}", e));
+                }
+            }
+        }
+
+        let mut page_names = HashSet::new();
+        for (page_index, page) in profile.pages.iter().enumerate() {
+            let page_path = format!("{}.pages[{}]", profile_path, page_index);
+            if page.name.is_empty() {
+                report.error(&page_path, "Page name cannot be empty".to_string());
+            } else if !page_names.insert(page.name.as_str()) {
+                report.warning(&page_path, format!(
+                    "Duplicate page name '{}' in profile '{}'; folder references resolve to the first one",
+                    page.name, profile.name
+                ));
+            }
+
+            validate_page(&mut report, &page_path, page, &page.name, profile, &mut button_ids);
+        }
+    }
+
+    validate_hotkeys(&mut report, config);
+
+    report.valid = !report.has_errors();
+    report
+}
+
+// Hotkeys must parse; clashing ones are skipped at registration, so they only warn
+fn validate_hotkeys(report: &mut ValidationReport, config: &QDeckConfig) {
+    let bindings = hotkey::config_hotkey_bindings(config);
+    let button_id = |binding: &HotkeyBinding| binding.action.strip_prefix("run_button:").map(str::to_string);
+
+    let hold_mode = config.ui.summon.mode == SummonMode::Hold;
+    let check_summon = |report: &mut ValidationReport, path: &str, hotkey: &str| match keys::parse_chord_sequence(hotkey) {
+        Ok(chords) if hold_mode && chords.len() > 1 => {
+            report.error(path, format!("Hotkey '{}' is a key sequence; hold mode needs a single key combination", hotkey));
+        }
+        Ok(_) => {}
+        Err(e) => report.error(path, e.to_string()),
+    };
+
+    for binding in &bindings {
+        if binding.source.starts_with("ui.summon.") {
+            check_summon(report, &binding.source, &binding.hotkey);
+        } else if let Err(e) = keys::parse_chord_sequence(&binding.hotkey) {
+            report.push(ValidationSeverity::Error, &binding.source, button_id(binding).as_deref(), e.to_string());
+        }
+    }
+    for (index, hotkey) in config.ui.summon.fallback_hotkeys.iter().enumerate() {
+        check_summon(report, &format!("ui.summon.fallback_hotkeys[{}]", index), hotkey);
+    }
+    if let Some(modifier) = &config.ui.summon.double_tap {
+        if let Err(e) = keys::ModifierKey::from_name(modifier) {
+            report.error("ui.summon.double_tap", format!("{}; use Ctrl, Alt, Shift or Win", e));
+        }
+    }
+
+    for conflict in hotkey::find_hotkey_conflicts(&bindings) {
+        let id = bindings.iter().find(|b| b.source == conflict.source).and_then(button_id);
+        report.push(ValidationSeverity::Warning, &conflict.source, id.as_deref(), format!(
+            "{}; this binding will be ignored", conflict.message
+        ));
+    }
+}
+
+// Grid checks shared by profile pages and inline folder pages
+fn validate_page<'a>(
+    report: &mut ValidationReport,
+    page_path: &str,
+    page: &'a Page,
+    page_label: &str,
+    profile: &Profile,
+    button_ids: &mut HashMap<&'a str, String>,
+) {
+    if page.rows == 0 || page.cols == 0 {
+        report.error(page_path, format!("Page '{}' dimensions must be greater than 0", page_label));
+    }
+
+    let mut occupied: HashMap<(u32, u32), &str> = HashMap::new();
+    for (button_index, button) in page.buttons.iter().enumerate() {
+        let button_path = format!("{}.buttons[{}]", page_path, button_index);
+        let id = Some(button.id.as_str());
+        let (row, col) = (button.position.row, button.position.col);
+
+        if button.label.is_empty() {
+            report.push(ValidationSeverity::Error, &button_path, id, "Button label cannot be empty".to_string());
+        }
+
+        if row == 0 || col == 0 {
+            report.push(ValidationSeverity::Error, &button_path, id, format!(
+                "Button '{}' position ({}, {}) is invalid; rows and columns start at 1",
+                button.label, row, col
+            ));
+        } else if row > page.rows || col > page.cols {
+            report.push(ValidationSeverity::Error, &button_path, id, format!(
+                "Button '{}' position ({}, {}) exceeds page '{}' dimensions ({}, {})",
+                button.label, row, col, page_label, page.rows, page.cols
+            ));
+        } else if let Some(other) = occupied.insert((row, col), button.label.as_str()) {
+            occupied.insert((row, col), other);
+            report.push(ValidationSeverity::Error, &button_path, id, format!(
+                "Button '{}' overlaps button '{}' at ({}, {})",
+                button.label, other, row, col
+            ));
+        }
+
+        if !button.id.is_empty() {
+            if let Some(first_path) = button_ids.get(button.id.as_str()) {
+                report.push(ValidationSeverity::Warning, &button_path, id, format!(
+                    "Button ID '{}' is also used at {}; a new ID will be assigned on save",
+                    button.id, first_path
+                ));
+            } else {
+                button_ids.insert(button.id.as_str(), button_path.clone());
+            }
+        }
+
+        validate_action(report, &format!("{}.action", button_path), button, &button.action, profile, button_ids, false);
+    }
+}
+
+// Required fields per action type
+fn validate_action<'a>(
+    report: &mut ValidationReport,
+    path: &str,
+    button: &'a ActionButton,
+    action: &'a ActionConfig,
+    profile: &Profile,
+    button_ids: &mut HashMap<&'a str, String>,
+    in_multi_action: bool,
+) {
+    let id = Some(button.id.as_str());
+    if action.timeout_ms() == Some(0) {
+        report.push(ValidationSeverity::Warning, &format!("{}.timeout_ms", path), id, format!(
+            "Button '{}': a timeout_ms of 0 kills the process as soon as it starts", button.label
+        ));
+    }
+    let mut missing = |field: &str| {
+        report.push(ValidationSeverity::Error, &format!("{}.{}", path, field), id, format!(
+            "Button '{}': {:?} action requires a non-empty '{}'",
+            button.label, action.action_type(), field
+        ));
+    };
+
+    match action {
+        ActionConfig::LaunchApp { path, .. } if path.trim().is_empty() => missing("path"),
+        ActionConfig::Open { target, .. } if target.trim().is_empty() => missing("target"),
+        ActionConfig::Terminal { terminal, .. } if terminal.trim().is_empty() => missing("terminal"),
+        ActionConfig::SendKeys { keys, .. } if keys.trim().is_empty() => missing("keys"),
+        ActionConfig::PowerShell { script, .. } if script.trim().is_empty() => missing("script"),
+        ActionConfig::MultiAction { actions, .. } => {
+            if actions.is_empty() {
+                report.push(ValidationSeverity::Warning, path, id, format!(
+                    "Button '{}': MultiAction has no actions and does nothing", button.label
+                ));
+            }
+            for (index, child) in actions.iter().enumerate() {
+                validate_action(report, &format!("{}.actions[{}]", path, index), button, child, profile, button_ids, true);
+            }
+        }
+        ActionConfig::Folder(_) | ActionConfig::System { .. } if in_multi_action => {
+            report.push(ValidationSeverity::Error, path, id, format!(
+                "Button '{}': {:?} actions cannot be part of a MultiAction", button.label, action.action_type()
+            ));
+        }
+        ActionConfig::Folder(folder) => validate_folder(report, path, button, folder, profile, button_ids),
+        _ => {}
+    }
+}
+
+fn validate_folder<'a>(
+    report: &mut ValidationReport,
+    path: &str,
+    button: &'a ActionButton,
+    folder: &'a FolderConfig,
+    profile: &Profile,
+    button_ids: &mut HashMap<&'a str, String>,
+) {
+    let id = Some(button.id.as_str());
+    match (&folder.page, &folder.page_ref) {
+        (Some(page), None) => {
+            let label = format!("folder '{}'", button.label);
+            validate_page(report, &format!("{}.page", path), page, &label, profile, button_ids);
+        }
+        (None, Some(page_ref)) => {
+            if !profile.pages.iter().any(|p| &p.name == page_ref) {
+                report.push(ValidationSeverity::Error, &format!("{}.page_ref", path), id, format!(
+                    "Folder '{}' references unknown page '{}' in profile '{}'",
+                    button.label, page_ref, profile.name
+                ));
+            }
+        }
+        _ => report.push(ValidationSeverity::Error, path, id, format!(
+            "Folder '{}' must define exactly one of 'page' or 'page_ref'",
+            button.label
+        )),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::modules::config::{Position, ProfileMatch};
+
+    fn button(id: &str, label: &str, row: u32, col: u32, action: ActionConfig) -> ActionButton {
+        ActionButton {
+            id: id.to_string(),
+            position: Position { row, col },
+            label: label.to_string(),
+            icon: None,
+            style: None,
+            hotkey: None,
+            action,
+        }
+    }
+
+    fn open(target: &str) -> ActionConfig {
+        ActionConfig::Open {
+            target: target.to_string(),
+            verb: None,
+            fallback_editor: None,
+        }
+    }
+
+    #[test]
+    fn test_default_config_is_clean() {
+        let report = validate(&QDeckConfig::default());
+        assert!(report.valid);
+        assert!(report.issues.is_empty(), "{:?}", report.issues);
+    }
+
+    #[test]
+    fn test_reports_every_problem_with_paths() {
+        let mut config = QDeckConfig::default();
+        config.ui.window.width_px = 0;
+        let page = &mut config.profiles[0].pages[0];
+        page.buttons = vec![
+            button("a", "First", 1, 1, open("/tmp")),
+            button("b", "Overlap", 1, 1, open("/tmp")),
+            button("c", "Zero", 0, 2, open("/tmp")),
+            button("d", "Empty", 2, 2, ActionConfig::SendKeys { keys: " ".to_string(), delay_ms: None }),
+            button("e", "Nothing", 2, 3, ActionConfig::MultiAction {
+                actions: vec![],
+                delay_between_ms: None,
+                stop_on_error: None,
+            }),
+        ];
+
+        let report = validate(&config);
+        assert!(!report.valid);
+
+        let errors: Vec<_> = report.errors().collect();
+        assert_eq!(errors.len(), 4, "{:?}", errors);
+        assert!(errors.iter().any(|e| e.path == "ui.window"));
+        let overlap = errors.iter().find(|e| e.button_id.as_deref() == Some("b")).unwrap();
+        assert_eq!(overlap.path, "profiles[0].pages[0].buttons[1]");
+        assert!(overlap.message.contains("overlaps button 'First'"));
+        assert!(errors.iter().any(|e| e.button_id.as_deref() == Some("c") && e.message.contains("start at 1")));
+        assert!(errors.iter().any(|e| e.path == "profiles[0].pages[0].buttons[3].action.keys"));
+
+        let warnings: Vec<_> = report.warnings().collect();
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(warnings[0].button_id.as_deref(), Some("e"));
+
+        let err = report.into_result().unwrap_err().to_string();
+        assert!(err.contains("4 errors"), "{}", err);
+    }
+
+    #[test]
+    fn test_match_rules() {
+        let mut config = QDeckConfig::default();
+        config.profiles[0].match_rule = Some(ProfileMatch { title: Some("(unclosed".to_string()), ..Default::default() });
+        config.profiles.push(Profile {
+            name: "Empty rule".to_string(),
+            match_rule: Some(ProfileMatch::default()),
+            ..Profile::default()
+        });
+
+        let report = validate(&config);
+        assert_eq!(report.errors().map(|e| e.path.as_str()).collect::<Vec<_>>(), vec!["profiles[0].match.title"]);
+        assert_eq!(report.warnings().map(|e| e.path.as_str()).collect::<Vec<_>>(), vec!["profiles[1].match"]);
+    }
+
+    #[test]
+    fn test_hotkeys() {
+        let mut config = QDeckConfig::default();
+        config.profiles[0].pages[0].hotkey = Some("Ctrl+Nope".to_string());
+        let mut run = button("run", "Run", 1, 1, open("/tmp"));
+        run.hotkey = Some("F11".to_string());
+        config.profiles[0].pages[0].buttons = vec![run];
+
+        let report = validate(&config);
+        assert_eq!(report.errors().map(|e| e.path.as_str()).collect::<Vec<_>>(), vec!["profiles[0].pages[0].hotkey"]);
+        let warnings: Vec<_> = report.warnings().collect();
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(warnings[0].path, "profiles[0].pages[0].buttons[0].hotkey");
+        assert_eq!(warnings[0].button_id.as_deref(), Some("run"));
+        assert!(warnings[0].message.contains("ui.summon.hotkeys[0]"), "{}", warnings[0].message);
+
+        let mut config = QDeckConfig::default();
+        config.ui.summon.mode = SummonMode::Hold;
+        config.ui.summon.hotkeys = vec!["Ctrl+Space, S".to_string()];
+        config.ui.summon.fallback_hotkeys = vec!["Alt+F12".to_string()];
+        config.ui.summon.double_tap = Some("Ctrl+Alt".to_string());
+        let report = validate(&config);
+        assert_eq!(report.errors().map(|e| e.path.as_str()).collect::<Vec<_>>(), vec!["ui.summon.hotkeys[0]", "ui.summon.double_tap"]);
+    }
+
+    #[test]
+    fn test_edge_trigger() {
+        let mut config = QDeckConfig::default();
+        let edge_trigger = config.ui.summon.edge_trigger.as_mut().unwrap();
+        edge_trigger.enabled = true;
+        edge_trigger.edges = vec!["Top".to_string(), "middle".to_string()];
+        let report = validate(&config);
+        assert_eq!(report.errors().map(|e| e.path.as_str()).collect::<Vec<_>>(), vec!["ui.summon.edge_trigger.edges[1]"]);
+
+        config.ui.summon.edge_trigger.as_mut().unwrap().edges.clear();
+        let report = validate(&config);
+        assert!(!report.has_errors());
+        assert_eq!(report.warnings().map(|w| w.path.as_str()).collect::<Vec<_>>(), vec!["ui.summon.edge_trigger.edges"]);
+    }
+
+    #[test]
+    fn test_window_placement() {
+        let mut config = QDeckConfig::default();
+        config.ui.window.placement = "at-cursor".to_string();
+        config.ui.window.monitor = "HDMI-1".to_string();
+        assert!(!validate(&config).has_errors());
+
+        // An unknown placement falls back to dropdown-top instead of refusing the config
+        config.ui.window.placement = "custom".to_string();
+        let report = validate(&config);
+        assert!(!report.has_errors());
+        assert_eq!(report.warnings().map(|w| w.path.as_str()).collect::<Vec<_>>(), vec!["ui.window.placement"]);
+    }
+
+    #[test]
+    fn test_action_timeout() {
+        let mut config = QDeckConfig::default();
+        config.profiles[0].pages[0].buttons = vec![
+            button("build", "Build", 1, 1, ActionConfig::PowerShell {
+                script: "cargo build".to_string(),
+                workdir: None,
+                execution_policy: None,
+                shell: None,
+                wait: Some(true),
+                timeout_ms: Some(0),
+                capture: None,
+            }),
+        ];
+
+        let report = validate(&config);
+        assert!(!report.has_errors());
+        assert_eq!(report.warnings().map(|w| w.path.as_str()).collect::<Vec<_>>(), vec!["profiles[0].pages[0].buttons[0].action.timeout_ms"]);
+    }
+
+    #[test]
+    fn test_nested_actions_and_folders() {
+        let mut config = QDeckConfig::default();
+        let child_page = Page {
+            name: "Inner".to_string(),
+            rows: 1,
+            cols: 1,
+            hotkey: None,
+            buttons: vec![button("child", "Child", 1, 2, open("/tmp"))],
+        };
+        config.profiles[0].pages[0].buttons = vec![
+            button("folder", "Folder", 1, 1, ActionConfig::Folder(FolderConfig {
+                page: Some(Box::new(child_page)),
+                page_ref: None,
+            })),
+            button("multi", "Multi", 1, 2, ActionConfig::MultiAction {
+                actions: vec![
+                    ActionConfig::LaunchApp { path: String::new(), args: None, workdir: None, env: None, timeout_ms: None, wait: None, capture: None },
+                    ActionConfig::Folder(FolderConfig { page: None, page_ref: Some("Main".to_string()) }),
+                ],
+                delay_between_ms: None,
+                stop_on_error: None,
+            }),
+        ];
+
+        let report = validate(&config);
+        let paths: Vec<_> = report.errors().map(|e| e.path.as_str()).collect();
+        assert_eq!(paths, vec![
+            "profiles[0].pages[0].buttons[0].action.page.buttons[0]",
+            "profiles[0].pages[0].buttons[1].action.actions[0].path",
+            "profiles[0].pages[0].buttons[1].action.actions[1]",
+        ]);
+        assert_eq!(report.errors().next().unwrap().button_id.as_deref(), Some("child"));
+    }
+}
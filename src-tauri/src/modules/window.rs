@@ -3,6 +3,9 @@ use std::sync::{Arc, Mutex};
 use tauri::{AppHandle, Manager, WebviewWindow, WebviewWindowBuilder, WebviewUrl, Listener};
 use tracing::{debug, error, info, warn};
 
+use crate::modules::edge_trigger::{CursorSource, MonitorRect};
+use crate::modules::placement::{self, DisplayMonitor, MonitorPolicy, OverlayGrid, Placement};
+
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub struct WindowConfig {
     pub width: f64,
@@ -42,6 +45,9 @@ pub struct WindowManager {
     app_handle: AppHandle,
     overlay_config: Arc<Mutex<WindowConfig>>,
     is_overlay_visible: Arc<Mutex<bool>>,
+    placement: Arc<Mutex<(Placement, MonitorPolicy)>>, // From ui.window.placement and ui.window.monitor
+    last_position: Arc<Mutex<Option<(i32, i32)>>>,     // Where the overlay was when it was last hidden
+    grid: Arc<Mutex<Option<OverlayGrid>>>,              // Grid of the page on show, sizes the overlay
 }
 
 impl WindowManager {
@@ -50,6 +56,9 @@ impl WindowManager {
             app_handle,
             overlay_config: Arc::new(Mutex::new(WindowConfig::default())),
             is_overlay_visible: Arc::new(Mutex::new(false)),
+            placement: Arc::new(Mutex::new((Placement::DropdownTop, MonitorPolicy::Primary))),
+            last_position: Arc::new(Mutex::new(None)),
+            grid: Arc::new(Mutex::new(None)),
         }
     }
 
@@ -70,14 +79,11 @@ impl WindowManager {
             *is_visible = true;
         }
 
-        // Calculate optimal window size based on grid
-        self.calculate_and_update_window_size()?;
-
         // Try to get existing overlay window
         if let Some(overlay_window) = self.app_handle.get_webview_window("overlay") {
             info!("Using existing overlay window");
             self.configure_overlay_window(&overlay_window)?;
-            self.position_overlay_dropdown_top()?; // Position for dropdown animation
+            self.position_overlay(self.placement())?;
             
             // Ensure window is always on top and focused
             overlay_window.set_always_on_top(true)?;
@@ -87,7 +93,7 @@ impl WindowManager {
         } else {
             info!("Creating new overlay window");
             self.create_overlay_window()?;
-            self.position_overlay_dropdown_top()?; // Position for dropdown animation
+            self.position_overlay(self.placement())?;
         }
 
         info!("Overlay window shown successfully");
@@ -108,6 +114,11 @@ impl WindowManager {
         }
 
         if let Some(overlay_window) = self.app_handle.get_webview_window("overlay") {
+            // Remember where the overlay was, for the remember-last placement
+            match overlay_window.outer_position() {
+                Ok(position) => *self.last_position.lock().unwrap() = Some((position.x, position.y)),
+                Err(e) => debug!("Failed to read overlay position: {}", e),
+            }
             overlay_window.hide()?;
             info!("Overlay window hidden successfully");
         } else {
@@ -260,88 +271,111 @@ impl WindowManager {
         Ok(())
     }
 
-    pub fn position_overlay_dropdown_top(&self) -> Result<()> {
-        if let Some(overlay_window) = self.app_handle.get_webview_window("overlay") {
-            // Get primary monitor size
-            if let Some(monitor) = overlay_window.primary_monitor()? {
-                let monitor_size = monitor.size();
-                let config = self.get_overlay_config();
-                
-                // Position at top center of screen
-                let x = (monitor_size.width as f64 - config.width) / 2.0;
-                let y = 20.0; // 20px from top for better visibility
-                
-                overlay_window.set_position(tauri::Position::Physical(tauri::PhysicalPosition {
-                    x: x as i32,
-                    y: y as i32,
-                }))?;
-                
-                info!("Positioned overlay at dropdown-top: ({}, {})", x, y);
-            }
-        }
-        Ok(())
+    /// Use the placement and monitor from `ui.window` the next time the overlay is shown
+    pub fn set_placement(&self, placement: Placement, monitor: MonitorPolicy) {
+        *self.placement.lock().unwrap() = (placement, monitor);
+    }
+
+    fn placement(&self) -> Placement {
+        self.placement.lock().unwrap().0
     }
 
-    pub fn position_overlay_center(&self) -> Result<()> {
+    /// Size the overlay for `grid`, the page that is now showing. A visible overlay is resized
+    /// right away for the monitor it is on.
+    pub fn set_grid(&self, grid: OverlayGrid) -> Result<()> {
+        *self.grid.lock().unwrap() = Some(grid);
+        if !self.is_overlay_visible() {
+            return Ok(());
+        }
+
+        let placement = self.placement();
+        if placement.is_screen_anchored() {
+            return self.position_overlay(placement);
+        }
+        // Keep a pointer-placed or remembered overlay where it is and only resize it
         if let Some(overlay_window) = self.app_handle.get_webview_window("overlay") {
-            if let Some(monitor) = overlay_window.primary_monitor()? {
-                let monitor_size = monitor.size();
-                let config = self.get_overlay_config();
-                
-                // Position at center of screen
-                let x = (monitor_size.width as f64 - config.width) / 2.0;
-                let y = (monitor_size.height as f64 - config.height) / 2.0;
-                
-                overlay_window.set_position(tauri::Position::Physical(tauri::PhysicalPosition {
-                    x: x as i32,
-                    y: y as i32,
-                }))?;
-                
-                info!("Positioned overlay at center: ({}, {})", x, y);
-            }
+            let (width, height) = grid.window_size(overlay_window.scale_factor()?);
+            self.set_overlay_size(width, height);
+            overlay_window.set_size(tauri::Size::Physical(tauri::PhysicalSize { width, height }))?;
+            info!("Resized overlay to {}x{} for grid {}x{}", width, height, grid.cols, grid.rows);
         }
         Ok(())
     }
 
-    fn calculate_and_update_window_size(&self) -> Result<()> {
-        // Load config to get grid dimensions
-        use crate::modules::config::ConfigManager;
-        
-        let config_manager = ConfigManager::new()?;
-        let config = config_manager.get_config();
-        
-        // Get the first profile and page for size calculation
-        if let Some(profile) = config.profiles.first() {
-            if let Some(page) = profile.pages.first() {
-                // Calculate grid size
-                let cell_size = config.ui.window.cell_size_px as f64;
-                let gap_size = config.ui.window.gap_px as f64;
-                let padding = 0.75 * 16.0; // 0.75rem converted to pixels (assuming 16px = 1rem)
-                
-                // Calculate content size
-                let grid_width = (page.cols as f64 * cell_size) + ((page.cols - 1) as f64 * gap_size);
-                let grid_height = (page.rows as f64 * cell_size) + ((page.rows - 1) as f64 * gap_size);
-                
-                // Add padding, border, and config button space
-                // Config button: 2.5rem (40px) + margin-bottom 0.5rem (8px) = 48px
-                let config_button_space = 48.0; // Space for config button
-                let total_width = grid_width + (padding * 2.0) + 2.0; // 2px for border
-                let total_height = grid_height + (padding * 2.0) + 2.0 + config_button_space; // Add config button space
-                
-                // Update window config
-                {
-                    let mut overlay_config = self.overlay_config.lock().unwrap();
-                    overlay_config.width = total_width;
-                    overlay_config.height = total_height;
-                }
-                
-                info!("Calculated window size: {}x{} for grid {}x{}", 
-                      total_width, total_height, page.cols, page.rows);
+    fn set_overlay_size(&self, width: u32, height: u32) {
+        let mut overlay_config = self.overlay_config.lock().unwrap();
+        overlay_config.width = width as f64;
+        overlay_config.height = height as f64;
+    }
+
+    /// Size the overlay for the current page and move it to `placement` on the monitor
+    /// picked by the configured monitor policy
+    pub fn position_overlay(&self, placement: Placement) -> Result<()> {
+        let overlay_window = match self.app_handle.get_webview_window("overlay") {
+            Some(overlay_window) => overlay_window,
+            None => return Ok(()),
+        };
+
+        let monitors = self.display_monitors()?;
+        let cursor = match self.app_handle.cursor_position() {
+            Ok(position) => Some((position.x.round() as i32, position.y.round() as i32)),
+            Err(e) => {
+                debug!("Failed to read cursor position: {}", e);
+                None
             }
+        };
+        let monitor = self.placement.lock().unwrap().1.clone();
+        let last = *self.last_position.lock().unwrap();
+
+        // Size for the DPI of the monitor the overlay is about to land on
+        let grid = *self.grid.lock().unwrap();
+        let target = placement::target_monitor(placement, &monitor, &monitors, cursor, last);
+        if let (Some(grid), Some(index)) = (grid, target) {
+            let (width, height) = grid.window_size(monitors[index].scale_factor);
+            self.set_overlay_size(width, height);
+            info!("Calculated window size: {}x{} for grid {}x{} at {}x scale",
+                  width, height, grid.cols, grid.rows, monitors[index].scale_factor);
+        }
+        let config = self.get_overlay_config();
+        let size = (config.width.round() as u32, config.height.round() as u32);
+
+        match placement::overlay_position(placement, &monitor, &monitors, size, cursor, last) {
+            Some((x, y)) => {
+                // Move first: crossing to a monitor with another scale factor can resize the window
+                overlay_window.set_position(tauri::Position::Physical(tauri::PhysicalPosition { x, y }))?;
+                overlay_window.set_size(tauri::Size::Physical(tauri::PhysicalSize { width: size.0, height: size.1 }))?;
+                info!("Positioned overlay at {:?} on {:?}: ({}, {})", placement, monitor, x, y);
+            }
+            None => warn!("No monitors found, leaving the overlay where it is"),
         }
-        
         Ok(())
     }
+
+    /// The connected monitors in desktop coordinates
+    pub fn display_monitors(&self) -> Result<Vec<DisplayMonitor>> {
+        let primary = self.app_handle.primary_monitor()?;
+        let rect = |position: &tauri::PhysicalPosition<i32>, size: &tauri::PhysicalSize<u32>| MonitorRect {
+            x: position.x,
+            y: position.y,
+            width: size.width,
+            height: size.height,
+        };
+
+        Ok(self
+            .app_handle
+            .available_monitors()?
+            .iter()
+            .map(|monitor| DisplayMonitor {
+                name: monitor.name().cloned(),
+                bounds: rect(monitor.position(), monitor.size()),
+                work_area: rect(&monitor.work_area().position, &monitor.work_area().size),
+                scale_factor: monitor.scale_factor(),
+                primary: primary.as_ref().is_some_and(|primary| {
+                    primary.name() == monitor.name() && primary.position() == monitor.position()
+                }),
+            })
+            .collect())
+    }
 }
 
 impl Clone for WindowManager {
@@ -350,10 +384,45 @@ impl Clone for WindowManager {
             app_handle: self.app_handle.clone(),
             overlay_config: Arc::clone(&self.overlay_config),
             is_overlay_visible: Arc::clone(&self.is_overlay_visible),
+            placement: Arc::clone(&self.placement),
+            last_position: Arc::clone(&self.last_position),
+            grid: Arc::clone(&self.grid),
         }
     }
 }
 
+/// Pointer position and monitor layout as seen by Tauri, for the edge trigger
+pub struct TauriCursorSource {
+    app_handle: AppHandle,
+}
+
+impl TauriCursorSource {
+    pub fn new(app_handle: AppHandle) -> Self {
+        Self { app_handle }
+    }
+}
+
+impl CursorSource for TauriCursorSource {
+    fn cursor_position(&mut self) -> Result<Option<(i32, i32)>> {
+        let position = self.app_handle.cursor_position()?;
+        Ok(Some((position.x.round() as i32, position.y.round() as i32)))
+    }
+
+    fn monitors(&mut self) -> Result<Vec<MonitorRect>> {
+        Ok(self
+            .app_handle
+            .available_monitors()?
+            .iter()
+            .map(|monitor| MonitorRect {
+                x: monitor.position().x,
+                y: monitor.position().y,
+                width: monitor.size().width,
+                height: monitor.size().height,
+            })
+            .collect())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
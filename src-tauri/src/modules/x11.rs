@@ -0,0 +1,158 @@
+// Shared Xlib connection handling (Linux)
+use anyhow::Result;
+use std::ffi::{CStr, CString};
+use std::os::raw::{c_char, c_int, c_uchar, c_ulong};
+use std::ptr;
+use std::sync::{Mutex, Once};
+use x11_dl::xlib::{self, Display, XErrorEvent, Xlib};
+
+type ErrorHandler = unsafe extern "C" fn(*mut Display, *mut XErrorEvent) -> c_int;
+
+// Connections opened through XConnection and the last X error seen on each.
+// Xlib's error handler is process-wide, so errors on other connections (GTK's) are passed on.
+static OWN_CONNECTIONS: Mutex<Vec<(usize, Option<u8>)>> = Mutex::new(Vec::new());
+static PREVIOUS_HANDLER: Mutex<Option<ErrorHandler>> = Mutex::new(None);
+static INSTALL_HANDLER: Once = Once::new();
+
+unsafe extern "C" fn error_handler(display: *mut Display, event: *mut XErrorEvent) -> c_int {
+    if let Ok(mut connections) = OWN_CONNECTIONS.lock() {
+        if let Some((_, last_error)) = connections.iter_mut().find(|(d, _)| *d == display as usize) {
+            *last_error = Some((*event).error_code);
+            return 0;
+        }
+    }
+
+    let previous = PREVIOUS_HANDLER.lock().ok().and_then(|handler| *handler);
+    match previous {
+        Some(handler) => handler(display, event),
+        None => 0,
+    }
+}
+
+/// An X display connection owned by one thread. X errors on it are captured
+/// instead of aborting the process; use `checked` to observe them.
+pub struct XConnection {
+    pub xlib: Xlib,
+    pub display: *mut Display,
+}
+
+// The display pointer is only ever used by the thread that currently owns the connection
+unsafe impl Send for XConnection {}
+
+impl XConnection {
+    pub fn open() -> Result<Self> {
+        let xlib = Xlib::open().map_err(|e| anyhow::anyhow!("Failed to load libX11: {}", e))?;
+        let display = unsafe { (xlib.XOpenDisplay)(ptr::null()) };
+        if display.is_null() {
+            return Err(anyhow::anyhow!("Failed to open X display (is DISPLAY set?)"));
+        }
+
+        INSTALL_HANDLER.call_once(|| {
+            let previous = unsafe { (xlib.XSetErrorHandler)(Some(error_handler)) };
+            if let Ok(mut handler) = PREVIOUS_HANDLER.lock() {
+                *handler = previous;
+            }
+        });
+        if let Ok(mut connections) = OWN_CONNECTIONS.lock() {
+            connections.push((display as usize, None));
+        }
+
+        Ok(Self { xlib, display })
+    }
+
+    pub fn root_window(&self) -> c_ulong {
+        unsafe { (self.xlib.XDefaultRootWindow)(self.display) }
+    }
+
+    pub fn intern_atom(&self, name: &str) -> c_ulong {
+        let name = CString::new(name).expect("atom names contain no NUL bytes");
+        unsafe { (self.xlib.XInternAtom)(self.display, name.as_ptr(), xlib::False) }
+    }
+
+    /// Run `f`, flush its requests and return the X error code it caused, if any
+    pub fn checked<T>(&self, f: impl FnOnce(&Self) -> T) -> std::result::Result<T, u8> {
+        self.take_error();
+        let value = f(self);
+        unsafe { (self.xlib.XSync)(self.display, xlib::False) };
+        match self.take_error() {
+            Some(code) => Err(code),
+            None => Ok(value),
+        }
+    }
+
+    fn take_error(&self) -> Option<u8> {
+        let mut connections = OWN_CONNECTIONS.lock().ok()?;
+        connections.iter_mut()
+            .find(|(d, _)| *d == self.display as usize)
+            .and_then(|(_, last_error)| last_error.take())
+    }
+
+    /// 32-bit items of a window property (WINDOW, CARDINAL, ATOM, ...)
+    pub fn property_longs(&self, window: c_ulong, property: c_ulong) -> Vec<c_ulong> {
+        self.read_property(window, property, |format, items, data| {
+            if format == 32 {
+                // Xlib hands out format-32 data as C longs
+                unsafe { std::slice::from_raw_parts(data as *const c_ulong, items) }.to_vec()
+            } else {
+                Vec::new()
+            }
+        }).unwrap_or_default()
+    }
+
+    /// A STRING / UTF8_STRING window property
+    pub fn property_text(&self, window: c_ulong, property: c_ulong) -> Option<String> {
+        self.read_property(window, property, |format, items, data| {
+            if format == 8 {
+                let bytes = unsafe { std::slice::from_raw_parts(data, items) };
+                Some(String::from_utf8_lossy(bytes).trim_end_matches('\0').to_string())
+            } else {
+                None
+            }
+        }).flatten()
+    }
+
+    fn read_property<T>(&self, window: c_ulong, property: c_ulong, read: impl FnOnce(c_int, usize, *const c_uchar) -> T) -> Option<T> {
+        let mut actual_type: c_ulong = 0;
+        let mut actual_format: c_int = 0;
+        let mut items: c_ulong = 0;
+        let mut bytes_after: c_ulong = 0;
+        let mut data: *mut c_uchar = ptr::null_mut();
+
+        let status = unsafe {
+            (self.xlib.XGetWindowProperty)(
+                self.display, window, property, 0, 4096, xlib::False,
+                xlib::AnyPropertyType as c_ulong,
+                &mut actual_type, &mut actual_format, &mut items, &mut bytes_after, &mut data,
+            )
+        };
+        if status != xlib::Success as c_int || data.is_null() {
+            return None;
+        }
+
+        let value = read(actual_format, items as usize, data);
+        unsafe { (self.xlib.XFree)(data.cast()) };
+        Some(value)
+    }
+
+    /// Take ownership of an Xlib-allocated C string
+    ///
+    /// # Safety
+    /// `value` must be null or a NUL-terminated string allocated by Xlib and not freed elsewhere.
+    pub unsafe fn take_string(&self, value: *mut c_char) -> Option<String> {
+        if value.is_null() {
+            return None;
+        }
+        let text = CStr::from_ptr(value).to_string_lossy().to_string();
+        (self.xlib.XFree)(value.cast());
+        Some(text)
+    }
+}
+
+impl Drop for XConnection {
+    fn drop(&mut self) {
+        if let Ok(mut connections) = OWN_CONNECTIONS.lock() {
+            connections.retain(|(d, _)| *d != self.display as usize);
+        }
+        unsafe { (self.xlib.XCloseDisplay)(self.display) };
+    }
+}
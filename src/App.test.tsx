@@ -25,6 +25,7 @@ vi.mock('./lib/platform-api', () => ({
   tauriAPI: {
     getConfig: vi.fn(),
     saveConfig: vi.fn(),
+    getConfigLoadError: vi.fn().mockResolvedValue(null),
     showOverlay: vi.fn(),
     hideOverlay: vi.fn(),
     toggleOverlay: vi.fn(),
@@ -10,6 +10,7 @@ function App() {
   const [config, setConfig] = useState<QDeckConfig | null>(null);
   const [isLoading, setIsLoading] = useState(true);
   const [error, setError] = useState<string | null>(null);
+  const [loadWarning, setLoadWarning] = useState<string | null>(null);
   const [hotkeys, setHotkeys] = useState<ParsedHotkey[]>([]);
   const [newHotkey, setNewHotkey] = useState('');
   const [hotkeyAction, setHotkeyAction] = useState('show_overlay');
@@ -41,6 +42,9 @@ function App() {
       const loadedConfig = await tauriAPI.getConfig();
       setConfig(loadedConfig as QDeckConfig);
       setError(null);
+      tauriAPI.getConfigLoadError()
+        .then(setLoadWarning)
+        .catch((err) => console.error('Failed to check config load error:', err));
     } catch (err) {
       setError(`Failed to load config: ${err}`);
       console.error('Failed to load config:', err);
@@ -110,39 +114,8 @@ function App() {
     }
   };
 
-  const getHotkeyDisplayName = (hotkey: ParsedHotkey) => {
-    // Convert virtual key codes back to readable names
-    const modifiers = [];
-    if (hotkey.modifiers & 0x0002) modifiers.push('Ctrl'); // MOD_CONTROL
-    if (hotkey.modifiers & 0x0001) modifiers.push('Alt');  // MOD_ALT
-    if (hotkey.modifiers & 0x0004) modifiers.push('Shift'); // MOD_SHIFT
-    if (hotkey.modifiers & 0x0008) modifiers.push('Win');  // MOD_WIN
-
-    // Convert VK codes to key names
-    let keyName = '';
-    if (hotkey.vk_code >= 0x70 && hotkey.vk_code <= 0x7B) {
-      // Function keys F1-F12
-      keyName = `F${hotkey.vk_code - 0x70 + 1}`;
-    } else if (hotkey.vk_code >= 0x41 && hotkey.vk_code <= 0x5A) {
-      // Letter keys A-Z
-      keyName = String.fromCharCode(hotkey.vk_code);
-    } else if (hotkey.vk_code >= 0x30 && hotkey.vk_code <= 0x39) {
-      // Number keys 0-9
-      keyName = String.fromCharCode(hotkey.vk_code);
-    } else {
-      // Special keys
-      switch (hotkey.vk_code) {
-        case 0x1B: keyName = 'Escape'; break;
-        case 0x20: keyName = 'Space'; break;
-        case 0x0D: keyName = 'Enter'; break;
-        case 0x09: keyName = 'Tab'; break;
-        case 0xC0: keyName = '`'; break; // Backtick
-        default: keyName = `VK_${hotkey.vk_code.toString(16).toUpperCase()}`;
-      }
-    }
-
-    return modifiers.length > 0 ? `${modifiers.join('+')}+${keyName}` : keyName;
-  };
+  // The backend formats chords canonically; "+" can't be split on ("Ctrl++", "Ctrl+Space, G")
+  const getHotkeyDisplayName = (hotkey: ParsedHotkey) => hotkey.display || hotkey.hotkey;
 
   const getActionDisplayName = (action: string) => {
     if (action === 'show_overlay') return '🚀 Show Overlay';
@@ -196,6 +169,12 @@ function App() {
     <main className="container">
       <h1>Q-Deck Settings</h1>
       <p className="subtitle">Configure your overlay launcher</p>
+
+      {loadWarning && (
+        <div className="error">
+          <p>{loadWarning}</p>
+        </div>
+      )}
         
         <div className="controls">
           <button onClick={handleShowOverlay}>Show Overlay</button>
@@ -266,12 +266,10 @@ describe('ActionButton', () => {
 
     const launchAppButton = {
       position: { row: 1, col: 1 },
-      action_type: 'LaunchApp' as const,
       label: 'Test App',
       icon: undefined,
-      config: { path: 'C:\\test.exe' },
+      action: { type: 'LaunchApp', path: 'C:\\test.exe' },
       style: undefined,
-      action: undefined,
     };
 
     const { unmount } = render(
@@ -306,12 +304,10 @@ describe('ActionButton', () => {
     for (const testCase of testCases) {
       const button = {
         position: { row: 1, col: 1 },
-        action_type: testCase.action_type,
         label: `Test ${testCase.action_type}`,
         icon: undefined,
-        config: {},
+        action: { type: testCase.action_type },
         style: undefined,
-        action: undefined,
       };
 
       const { unmount: unmountCase } = render(
@@ -336,12 +332,10 @@ describe('ActionButton', () => {
 
     const buttonWithInvalidIcon = {
       position: { row: 1, col: 1 },
-      action_type: 'Open' as const,
       label: 'Test File',
       icon: 'invalid/path/to/icon.png',
-      config: { target: 'C:\\test.txt' },
+      action: { type: 'Open', target: 'C:\\test.txt' },
       style: undefined,
-      action: undefined,
     };
 
     render(
@@ -115,8 +115,8 @@ export const ActionButton: React.FC<ActionButtonProps> = React.memo(({ button, d
         
         // Determine fallback executable for icon extraction
         let fallbackExecutable: string | undefined;
-        if (button.action_type === 'LaunchApp' && button.config.path) {
-          fallbackExecutable = button.config.path as string;
+        if (button.action.type === 'LaunchApp' && button.action.path) {
+          fallbackExecutable = button.action.path;
         }
 
         const iconInfo = await tauriAPI.processIcon(button.icon, fallbackExecutable);
@@ -129,32 +129,31 @@ export const ActionButton: React.FC<ActionButtonProps> = React.memo(({ button, d
     };
 
     processButtonIcon();
-  }, [button.icon, button.action_type, button.config.path]);
+  }, [button.icon, button.action]);
 
   const handleClick = async () => {
     try {
       console.log('ActionButton clicked:', button.label, button.action);
       
       // Handle system actions
-      if (button.action?.action_type === 'system' && onSystemAction && button.action.system_action) {
+      if (button.action.type === 'System' && onSystemAction) {
         console.log('Executing system action:', button.action.system_action);
         onSystemAction(button.action.system_action);
         return;
       }
       
-      console.log('Executing action:', button.action_type, button.config);
+      console.log('Executing action:', button.action.type, button.action);
       // Pass the full action configuration to the backend
       const actionConfig = {
-        type: button.action_type,
+        ...button.action,
         label: button.label,
-        ...button.config
       };
       const result = await tauriAPI.executeAction(actionConfig);
       
       console.log('🔍 Action execution result:', {
         success: result?.success,
         actionType: result?.actionType,
-        buttonActionType: button.action_type,
+        buttonActionType: button.action.type,
         result: result
       });
       
@@ -321,7 +320,7 @@ export const ActionButton: React.FC<ActionButtonProps> = React.memo(({ button, d
     }
     
     // System action icons
-    if (button.action?.action_type === 'system') {
+    if (button.action.type === 'System') {
       switch (button.action.system_action) {
         case 'config':
           return '⚙️';
@@ -338,7 +337,7 @@ export const ActionButton: React.FC<ActionButtonProps> = React.memo(({ button, d
     }
     
     // Default icons based on action type
-    switch (button.action_type) {
+    switch (button.action.type) {
       case 'LaunchApp':
         return '🚀';
       case 'Open':
@@ -527,14 +526,13 @@ export const ActionButton: React.FC<ActionButtonProps> = React.memo(({ button, d
     processedIcon,
     button.icon,
     button.action,
-    button.action_type,
     iconError,
   ]);
 
   // Memoize title string
   const buttonTitle = useMemo(
-    () => `${button.label} (${button.action?.action_type === 'system' ? button.action.system_action : button.action_type})`,
-    [button.label, button.action, button.action_type]
+    () => `${button.label} (${button.action.type === 'System' ? button.action.system_action : button.action.type})`,
+    [button.label, button.action]
   );
 
   // Memoize label length category
@@ -559,7 +557,7 @@ export const ActionButton: React.FC<ActionButtonProps> = React.memo(({ button, d
         transition: { duration: 0.1 }
       }}
       title={buttonTitle}
-      data-system-action={button.action?.action_type === 'system' ? button.action.system_action : undefined}
+      data-system-action={button.action.type === 'System' ? button.action.system_action : undefined}
       data-label-length={labelLengthCategory}
     >
       {shortcutNumber && (
@@ -11,10 +11,10 @@ describe('Button Customization - Color and Font Settings', () => {
   beforeEach(() => {
     mockButton = {
       position: { row: 1, col: 1 },
-      action_type: 'LaunchApp',
       label: 'Test Button',
       icon: '🚀',
-      config: {
+      action: {
+        type: 'LaunchApp',
         path: 'notepad.exe',
       },
       style: {
@@ -66,10 +66,10 @@ describe('Button Edit and Delete Functionality', () => {
             buttons: [
               {
                 position: { row: 1, col: 1 },
-                action_type: 'LaunchApp',
                 label: 'Test Button',
                 icon: '🚀',
-                config: {
+                action: {
+                  type: 'LaunchApp',
                   path: 'notepad.exe',
                 },
                 style: {
@@ -245,7 +245,7 @@ export const ButtonEditModal: React.FC<ButtonEditModalProps> = ({
                   {renderPreviewIcon(editedButton.icon || button.icon || '🚀')}
                 </div>
                 <div className="preview-label">{button.label}</div>
-                <div className="preview-type">{button.action_type}</div>
+                <div className="preview-type">{button.action.type}</div>
               </div>
             </div>
           </div>
@@ -6,10 +6,9 @@ import { ActionButton as ActionButtonType } from '../lib/platform-api';
 describe('Button Shortcut Visibility', () => {
   const mockButton: ActionButtonType = {
     position: { row: 1, col: 1 },
-    action_type: 'LaunchApp',
     label: 'Test Button',
     icon: '🚀',
-    config: { path: 'test.exe' },
+    action: { type: 'LaunchApp', path: 'test.exe' },
   };
 
   beforeEach(() => {
@@ -325,7 +324,7 @@ describe('Button Shortcut Visibility', () => {
   });
 
   it('should be visible on buttons with different action types', () => {
-    const actionTypes: Array<ActionButtonType['action_type']> = [
+    const actionTypes: Array<ActionButtonType['action']['type']> = [
       'LaunchApp',
       'Open',
       'Terminal',
@@ -334,7 +333,7 @@ describe('Button Shortcut Visibility', () => {
     actionTypes.forEach(actionType => {
       const button: ActionButtonType = {
         ...mockButton,
-        action_type: actionType,
+        action: { ...mockButton.action, type: actionType } as ActionButtonType['action'],
       };
 
       const { container } = render(
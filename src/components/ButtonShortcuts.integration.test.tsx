@@ -43,68 +43,59 @@ describe('Button Shortcuts Integration', () => {
               // Row 1
               {
                 position: { row: 1, col: 1 },
-                action_type: 'LaunchApp',
                 label: 'Button 1',
                 icon: '🚀',
-                config: { path: 'app1.exe' },
+                action: { type: 'LaunchApp', path: 'app1.exe' },
               },
               {
                 position: { row: 1, col: 2 },
-                action_type: 'LaunchApp',
                 label: 'Button 2',
                 icon: '📁',
-                config: { path: 'app2.exe' },
+                action: { type: 'LaunchApp', path: 'app2.exe' },
               },
               {
                 position: { row: 1, col: 3 },
-                action_type: 'Open',
                 label: 'Button 3',
                 icon: '📂',
-                config: { path: 'file.txt' },
+                action: { type: 'Open', path: 'file.txt' },
               },
               // Row 2
               {
                 position: { row: 2, col: 1 },
-                action_type: 'Terminal',
                 label: 'Button 4',
                 icon: '💻',
-                config: { terminal: 'PowerShell' },
+                action: { type: 'Terminal', terminal: 'PowerShell' },
               },
               {
                 position: { row: 2, col: 2 },
-                action_type: 'LaunchApp',
                 label: 'Button 5',
                 icon: '🎮',
-                config: { path: 'game.exe' },
+                action: { type: 'LaunchApp', path: 'game.exe' },
               },
               // Row 3
               {
                 position: { row: 3, col: 1 },
-                action_type: 'LaunchApp',
                 label: 'Button 6',
                 icon: '🎵',
-                config: { path: 'music.exe' },
+                action: { type: 'LaunchApp', path: 'music.exe' },
               },
               {
                 position: { row: 3, col: 2 },
-                action_type: 'LaunchApp',
                 label: 'Button 7',
                 icon: '🎬',
-                config: { path: 'video.exe' },
+                action: { type: 'LaunchApp', path: 'video.exe' },
               },
               {
                 position: { row: 3, col: 3 },
-                action_type: 'LaunchApp',
                 label: 'Button 8',
                 icon: '📷',
-                config: { path: 'photo.exe' },
+                action: { type: 'LaunchApp', path: 'photo.exe' },
               },
               {
                 position: { row: 3, col: 4 },
-                action_type: 'LaunchApp',
                 label: 'Button 9',
                 icon: '🎨',
-                config: { path: 'paint.exe' },
+                action: { type: 'LaunchApp', path: 'paint.exe' },
               },
             ],
           },
@@ -278,10 +269,9 @@ describe('Button Shortcuts Integration', () => {
               cols: 4,
               buttons: Array.from({ length: 12 }, (_, i) => ({
                 position: { row: Math.floor(i / 4) + 1, col: (i % 4) + 1 },
-                action_type: 'LaunchApp' as const,
                 label: `Button ${i + 1}`,
                 icon: '🚀',
-                config: { path: `app${i + 1}.exe` },
+                action: { type: 'LaunchApp', path: `app${i + 1}.exe` },
               })),
             },
           ],
@@ -345,10 +335,9 @@ describe('Button Shortcuts Integration', () => {
               cols: 4,
               buttons: Array.from({ length: 20 }, (_, i) => ({
                 position: { row: Math.floor(i / 4) + 1, col: (i % 4) + 1 },
-                action_type: 'LaunchApp' as const,
                 label: `Button ${i + 1}`,
                 icon: '🚀',
-                config: { path: `app${i + 1}.exe` },
+                action: { type: 'LaunchApp', path: `app${i + 1}.exe` },
               })),
             },
           ],
@@ -412,10 +401,9 @@ describe('Button Shortcuts Integration', () => {
               cols: 4,
               buttons: Array.from({ length: 25 }, (_, i) => ({
                 position: { row: Math.floor(i / 4) + 1, col: (i % 4) + 1 },
-                action_type: 'LaunchApp' as const,
                 label: `Button ${i + 1}`,
                 icon: '🚀',
-                config: { path: `app${i + 1}.exe` },
+                action: { type: 'LaunchApp', path: `app${i + 1}.exe` },
               })),
             },
           ],
@@ -456,10 +444,9 @@ describe('Button Shortcuts Integration', () => {
               cols: 4,
               buttons: Array.from({ length: 15 }, (_, i) => ({
                 position: { row: Math.floor(i / 4) + 1, col: (i % 4) + 1 },
-                action_type: 'LaunchApp' as const,
                 label: `Button ${i + 1}`,
                 icon: '🚀',
-                config: { path: `app${i + 1}.exe` },
+                action: { type: 'LaunchApp', path: `app${i + 1}.exe` },
               })),
             },
           ],
@@ -612,31 +599,27 @@ describe('Button Shortcuts Integration', () => {
               buttons: [
                 {
                   position: { row: 1, col: 1 },
-                  action_type: 'LaunchApp',
                   label: 'App 1',
                   icon: '🚀',
-                  config: { path: 'app1.exe' },
+                  action: { type: 'LaunchApp', path: 'app1.exe' },
                 },
                 {
                   position: { row: 1, col: 2 },
-                  action_type: 'LaunchApp',
                   label: 'App 2',
                   icon: '📁',
-                  config: { path: 'app2.exe' },
+                  action: { type: 'LaunchApp', path: 'app2.exe' },
                 },
                 {
                   position: { row: 2, col: 1 },
-                  action_type: 'LaunchApp',
                   label: 'App 3',
                   icon: '📂',
-                  config: { path: 'app3.exe' },
+                  action: { type: 'LaunchApp', path: 'app3.exe' },
                 },
                 {
                   position: { row: 2, col: 2 },
-                  action_type: 'LaunchApp',
                   label: 'App 4',
                   icon: '💻',
-                  config: { path: 'app4.exe' },
+                  action: { type: 'LaunchApp', path: 'app4.exe' },
                 },
               ],
             },
@@ -685,10 +668,9 @@ describe('Button Shortcuts Integration', () => {
               cols: 5,
               buttons: Array.from({ length: 25 }, (_, i) => ({
                 position: { row: Math.floor(i / 5) + 1, col: (i % 5) + 1 },
-                action_type: 'LaunchApp' as const,
                 label: `App ${i + 1}`,
                 icon: '🚀',
-                config: { path: `app${i + 1}.exe` },
+                action: { type: 'LaunchApp', path: `app${i + 1}.exe` },
               })),
             },
           ],
@@ -754,10 +736,9 @@ describe('Button Shortcuts Integration', () => {
               cols: 10,
               buttons: Array.from({ length: 10 }, (_, i) => ({
                 position: { row: 1, col: i + 1 },
-                action_type: 'LaunchApp' as const,
                 label: `App ${i + 1}`,
                 icon: '🚀',
-                config: { path: `app${i + 1}.exe` },
+                action: { type: 'LaunchApp', path: `app${i + 1}.exe` },
               })),
             },
           ],
@@ -798,10 +779,9 @@ describe('Button Shortcuts Integration', () => {
               cols: 1,
               buttons: Array.from({ length: 10 }, (_, i) => ({
                 position: { row: i + 1, col: 1 },
-                action_type: 'LaunchApp' as const,
                 label: `App ${i + 1}`,
                 icon: '🚀',
-                config: { path: `app${i + 1}.exe` },
+                action: { type: 'LaunchApp', path: `app${i + 1}.exe` },
               })),
             },
           ],
@@ -844,40 +824,35 @@ describe('Button Shortcuts Integration', () => {
                 // Only place buttons in specific positions with gaps
                 {
                   position: { row: 1, col: 1 },
-                  action_type: 'LaunchApp',
                   label: 'App 1',
                   icon: '🚀',
-                  config: { path: 'app1.exe' },
+                  action: { type: 'LaunchApp', path: 'app1.exe' },
                 },
                 // Skip (1,2), (1,3)
                 {
                   position: { row: 1, col: 4 },
-                  action_type: 'LaunchApp',
                   label: 'App 2',
                   icon: '📁',
-                  config: { path: 'app2.exe' },
+                  action: { type: 'LaunchApp', path: 'app2.exe' },
                 },
                 // Skip row 2 entirely
                 {
                   position: { row: 3, col: 1 },
-                  action_type: 'LaunchApp',
                   label: 'App 3',
                   icon: '📂',
-                  config: { path: 'app3.exe' },
+                  action: { type: 'LaunchApp', path: 'app3.exe' },
                 },
                 {
                   position: { row: 3, col: 3 },
-                  action_type: 'LaunchApp',
                   label: 'App 4',
                   icon: '💻',
-                  config: { path: 'app4.exe' },
+                  action: { type: 'LaunchApp', path: 'app4.exe' },
                 },
                 {
                   position: { row: 4, col: 2 },
-                  action_type: 'LaunchApp',
                   label: 'App 5',
                   icon: '🎮',
-                  config: { path: 'app5.exe' },
+                  action: { type: 'LaunchApp', path: 'app5.exe' },
                 },
               ],
             },
@@ -966,33 +941,29 @@ describe('Button Shortcuts Integration', () => {
                 // Row 1: buttons at (1,1), (1,2), empty at (1,3), (1,4)
                 {
                   position: { row: 1, col: 1 },
-                  action_type: 'LaunchApp',
                   label: 'Button 1',
                   icon: '🚀',
-                  config: { path: 'app1.exe' },
+                  action: { type: 'LaunchApp', path: 'app1.exe' },
                 },
                 {
                   position: { row: 1, col: 2 },
-                  action_type: 'LaunchApp',
                   label: 'Button 2',
                   icon: '📁',
-                  config: { path: 'app2.exe' },
+                  action: { type: 'LaunchApp', path: 'app2.exe' },
                 },
                 // Row 2: empty at (2,1), button at (2,2), empty at (2,3), (2,4)
                 {
                   position: { row: 2, col: 2 },
-                  action_type: 'LaunchApp',
                   label: 'Button 3',
                   icon: '💻',
-                  config: { path: 'app3.exe' },
+                  action: { type: 'LaunchApp', path: 'app3.exe' },
                 },
                 // Row 3: button at (3,1), empty at (3,2), (3,3), (3,4)
                 {
                   position: { row: 3, col: 1 },
-                  action_type: 'LaunchApp',
                   label: 'Button 4',
                   icon: '🎮',
-                  config: { path: 'app4.exe' },
+                  action: { type: 'LaunchApp', path: 'app4.exe' },
                 },
               ],
             },
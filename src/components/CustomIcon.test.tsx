@@ -7,8 +7,8 @@ describe('Custom Icon Support', () => {
   const mockButton: ActionButtonType = {
     position: { row: 1, col: 1 },
     label: 'Test Button',
-    action_type: 'LaunchApp',
-    config: {
+    action: {
+      type: 'LaunchApp',
       path: 'C:\\test.exe'
     },
     icon: '🚀',
@@ -25,10 +25,10 @@ describe('Electron Integration Tests', () => {
     it('should render button with label and icon', () => {
       const button: ActionButtonType = {
         position: { row: 1, col: 1 },
-        action_type: 'LaunchApp',
         label: 'Test Button',
         icon: '📝',
-        config: {
+        action: {
+          type: 'LaunchApp',
           path: 'notepad.exe'
         }
       };
@@ -44,10 +44,10 @@ describe('Electron Integration Tests', () => {
       
       const button: ActionButtonType = {
         position: { row: 1, col: 1 },
-        action_type: 'LaunchApp',
         label: 'Notepad',
         icon: '📝',
-        config: {
+        action: {
+          type: 'LaunchApp',
           path: 'notepad.exe',
           args: ['test.txt']
         }
@@ -74,14 +74,9 @@ describe('Electron Integration Tests', () => {
       
       const button: ActionButtonType = {
         position: { row: 1, col: 1 },
-        action_type: 'LaunchApp',
         label: 'Settings',
         icon: '⚙️',
-        config: {},
-        action: {
-          action_type: 'system',
-          system_action: 'config'
-        }
+        action: { type: 'System', system_action: 'config' }
       };
 
       render(<ActionButton button={button} onSystemAction={onSystemAction} />);
@@ -97,10 +92,10 @@ describe('Electron Integration Tests', () => {
     it('should display emoji icons correctly', () => {
       const button: ActionButtonType = {
         position: { row: 1, col: 1 },
-        action_type: 'LaunchApp',
         label: 'Calculator',
         icon: '🔢',
-        config: {
+        action: {
+          type: 'LaunchApp',
           path: 'calc.exe'
         }
       };
@@ -114,10 +109,10 @@ describe('Electron Integration Tests', () => {
     it('should apply custom styles when provided', () => {
       const button: ActionButtonType = {
         position: { row: 1, col: 1 },
-        action_type: 'LaunchApp',
         label: 'Styled Button',
         icon: '🎨',
-        config: {
+        action: {
+          type: 'LaunchApp',
           path: 'test.exe'
         },
         style: {
@@ -429,7 +429,7 @@ describe('Grid - Button Deletion', () => {
       btn.position.col === 2
     );
     expect(remainingButton).toBeDefined();
-    expect(remainingButton?.action_type).toBe('Open');
+    expect(remainingButton?.action.type).toBe('Open');
     expect(remainingButton?.icon).toBe('📁');
 
     // Verify other configuration properties are preserved
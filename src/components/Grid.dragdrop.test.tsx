@@ -722,8 +722,8 @@ describe('Grid Button Auto-Generation', () => {
     const buttons = savedConfig.profiles[0].pages[0].buttons;
     const addedButton = buttons[buttons.length - 1];
     
-    expect(addedButton.action_type).toBe('LaunchApp');
-    expect(addedButton.config.path).toBe('C:\\Apps\\application.exe');
+    expect(addedButton.action.type).toBe('LaunchApp');
+    expect(addedButton.action.path).toBe('C:\\Apps\\application.exe');
   });
 
   it('should create Open action for non-executable files', async () => {
@@ -784,8 +784,8 @@ describe('Grid Button Auto-Generation', () => {
     const buttons = savedConfig.profiles[0].pages[0].buttons;
     const addedButton = buttons[buttons.length - 1];
     
-    expect(addedButton.action_type).toBe('Open');
-    expect(addedButton.config.target).toBe('C:\\Documents\\document.txt');
+    expect(addedButton.action.type).toBe('Open');
+    expect(addedButton.action.target).toBe('C:\\Documents\\document.txt');
   });
 });
 
@@ -1,4 +1,4 @@
-import React, { useCallback, useMemo } from 'react';
+import React, { useCallback, useEffect, useMemo } from 'react';
 import { motion } from 'framer-motion';
 import ContextMenu from './ContextMenu';
 import ThemeSelector from './ThemeSelector';
@@ -7,7 +7,7 @@ import ConfigModal from './ConfigModal';
 import ButtonEditModal from './ButtonEditModal';
 import GridCell from './GridCell';
 import PageIndicator from './PageIndicator';
-import { QDeckConfig, ProfileInfo, PageInfo } from '../lib/platform-api';
+import { QDeckConfig, ProfileInfo, PageInfo, ActionButton as ActionButtonType } from '../lib/platform-api';
 import { useScreenInfo } from '../hooks/useScreenInfo';
 import { useGridLayout } from '../hooks/useGridLayout';
 import { useConfigModal } from '../hooks/useConfigModal';
@@ -133,6 +133,14 @@ export const Grid: React.FC<GridProps> = ({ config, currentProfile, currentPage,
     handleSystemActionUtil(action, openConfig);
   }, [openConfig]);
 
+  // System buttons run from their hotkey ask the overlay to open the settings screen
+  useEffect(() => {
+    if (typeof tauriAPI.onSystemAction !== 'function') {
+      return;
+    }
+    return tauriAPI.onSystemAction(handleSystemAction);
+  }, [handleSystemAction]);
+
   const handleDeleteButton = useCallback(() => {
     if (contextMenu.button) {
       handleRemoveButton(contextMenu.button);
@@ -184,21 +192,20 @@ export const Grid: React.FC<GridProps> = ({ config, currentProfile, currentPage,
   }, [gridCells]);
 
   // Handle button click via shortcut
-  const handleButtonShortcutClick = useCallback(async (button: any) => {
+  const handleButtonShortcutClick = useCallback(async (button: ActionButtonType) => {
     try {
       console.log('Button shortcut triggered:', button.label);
       
       // Handle system actions
-      if (button.action?.action_type === 'system' && button.action.system_action) {
+      if (button.action.type === 'System') {
         handleSystemAction(button.action.system_action);
         return;
       }
       
       // Execute the action
       const actionConfig = {
-        type: button.action_type,
+        ...button.action,
         label: button.label,
-        ...button.config
       };
       const result = await tauriAPI.executeAction(actionConfig);
       
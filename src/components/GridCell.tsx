@@ -106,7 +106,7 @@ export const GridCell: React.FC<GridCellProps> = React.memo(({
           dpiScale={dpiScale}
           screenInfo={screenInfo}
           shortcutNumber={shortcutNumber}
-          onSystemAction={button.action?.action_type === 'system' ? onSystemAction : undefined}
+          onSystemAction={button.action.type === 'System' ? onSystemAction : undefined}
           onContextMenu={onContextMenu}
         />
       ) : (
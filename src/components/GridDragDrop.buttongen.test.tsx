@@ -187,21 +187,19 @@ describe('Button Generation Logic', () => {
 
       const button = {
         position: { row, col },
-        action_type: actionType,
         label: buttonLabel,
         icon: undefined,
-        config: isExecutable 
+        action: { type: actionType, ...(isExecutable 
           ? { path: filePath }
-          : { target: filePath },
+          : { target: filePath }) },
         style: undefined,
-        action: undefined,
       };
 
       expect(button.position.row).toBe(1);
       expect(button.position.col).toBe(1);
-      expect(button.action_type).toBe('LaunchApp');
+      expect(button.action.type).toBe('LaunchApp');
       expect(button.label).toBe('app');
-      expect(button.config.path).toBe('C:\\Program Files\\MyApp\\app.exe');
+      expect(button.action.path).toBe('C:\\Program Files\\MyApp\\app.exe');
       expect(button.icon).toBeUndefined();
     });
 
@@ -215,21 +213,19 @@ describe('Button Generation Logic', () => {
 
       const button = {
         position: { row: 1, col: 1 },
-        action_type: actionType,
         label: buttonLabel,
         icon: undefined,
-        config: isExecutable 
+        action: { type: actionType, ...(isExecutable 
           ? { path: filePath }
-          : { target: filePath },
+          : { target: filePath }) },
         style: undefined,
-        action: undefined,
       };
 
-      expect(button.action_type).toBe('LaunchApp');
+      expect(button.action.type).toBe('LaunchApp');
       expect(button.label).toBe('notepad');
-      expect(button.config).toHaveProperty('path');
-      expect(button.config.path).toBe(filePath);
-      expect(button.config).not.toHaveProperty('target');
+      expect(button.action).toHaveProperty('path');
+      expect(button.action.path).toBe(filePath);
+      expect(button.action).not.toHaveProperty('target');
     });
 
     it('should create LaunchApp button with path config (not target) for .exe files', () => {
@@ -242,25 +238,23 @@ describe('Button Generation Logic', () => {
 
       const button = {
         position: { row: 2, col: 3 },
-        action_type: actionType,
         label: buttonLabel,
         icon: undefined,
-        config: isExecutable 
+        action: { type: actionType, ...(isExecutable 
           ? { path: filePath }
-          : { target: filePath },
+          : { target: filePath }) },
         style: undefined,
-        action: undefined,
       };
 
       // Verify it's LaunchApp action
-      expect(button.action_type).toBe('LaunchApp');
+      expect(button.action.type).toBe('LaunchApp');
       
       // Verify config has 'path' property (for LaunchApp)
-      expect(button.config).toHaveProperty('path');
-      expect(button.config.path).toBe(filePath);
+      expect(button.action).toHaveProperty('path');
+      expect(button.action.path).toBe(filePath);
       
       // Verify config does NOT have 'target' property (which is for Open action)
-      expect(button.config).not.toHaveProperty('target');
+      expect(button.action).not.toHaveProperty('target');
     });
 
     it('should create correct button structure for .txt file', () => {
@@ -275,21 +269,19 @@ describe('Button Generation Logic', () => {
 
       const button = {
         position: { row, col },
-        action_type: actionType,
         label: buttonLabel,
         icon: undefined,
-        config: isExecutable 
+        action: { type: actionType, ...(isExecutable 
           ? { path: filePath }
-          : { target: filePath },
+          : { target: filePath }) },
         style: undefined,
-        action: undefined,
       };
 
       expect(button.position.row).toBe(2);
       expect(button.position.col).toBe(3);
-      expect(button.action_type).toBe('Open');
+      expect(button.action.type).toBe('Open');
       expect(button.label).toBe('notes');
-      expect(button.config.target).toBe('C:\\Documents\\notes.txt');
+      expect(button.action.target).toBe('C:\\Documents\\notes.txt');
       expect(button.icon).toBeUndefined();
     });
   });
@@ -465,23 +457,21 @@ describe('Button Generation Logic', () => {
         
         const button = {
           position: { row: 1, col: 1 },
-          action_type: actionType,
           label: fileName.replace(/\.[^/.]+$/, ''),
           icon: undefined,
-          config: isExecutable 
+          action: { type: actionType, ...(isExecutable 
             ? { path: filePath }
-            : { target: filePath },
+            : { target: filePath }) },
           style: undefined,
-          action: undefined,
         };
         
         // Verify action type is "Open" for non-executable files
-        expect(button.action_type).toBe('Open');
+        expect(button.action.type).toBe('Open');
         
         // Verify config uses "target" property (not "path")
-        expect(button.config).toHaveProperty('target');
-        expect(button.config.target).toBe(filePath);
-        expect(button.config).not.toHaveProperty('path');
+        expect(button.action).toHaveProperty('target');
+        expect(button.action.target).toBe(filePath);
+        expect(button.action).not.toHaveProperty('path');
       }
     });
 
@@ -521,22 +511,20 @@ describe('Button Generation Logic', () => {
       // Create button at drop position
       const button = {
         position: { row: dropPosition.row, col: dropPosition.col },
-        action_type: actionType,
         label: buttonLabel,
         icon: undefined,
-        config: isExecutable 
+        action: { type: actionType, ...(isExecutable 
           ? { path: filePath }
-          : { target: filePath },
+          : { target: filePath }) },
         style: undefined,
-        action: undefined,
       };
       
       // Verify button was created at the correct position
       expect(button.position.row).toBe(2);
       expect(button.position.col).toBe(3);
-      expect(button.action_type).toBe('LaunchApp');
+      expect(button.action.type).toBe('LaunchApp');
       expect(button.label).toBe('app');
-      expect(button.config.path).toBe(filePath);
+      expect(button.action.path).toBe(filePath);
     });
 
     it('should create button at position (1, 1) when dropped there', () => {
@@ -551,19 +539,17 @@ describe('Button Generation Logic', () => {
       
       const button = {
         position: { row: dropPosition.row, col: dropPosition.col },
-        action_type: actionType,
         label: buttonLabel,
         icon: undefined,
-        config: isExecutable 
+        action: { type: actionType, ...(isExecutable 
           ? { path: filePath }
-          : { target: filePath },
+          : { target: filePath }) },
         style: undefined,
-        action: undefined,
       };
       
       expect(button.position.row).toBe(1);
       expect(button.position.col).toBe(1);
-      expect(button.action_type).toBe('Open');
+      expect(button.action.type).toBe('Open');
       expect(button.label).toBe('notes');
     });
 
@@ -582,19 +568,17 @@ describe('Button Generation Logic', () => {
       
       const button = {
         position: { row: dropPosition.row, col: dropPosition.col },
-        action_type: actionType,
         label: buttonLabel,
         icon: undefined,
-        config: isExecutable 
+        action: { type: actionType, ...(isExecutable 
           ? { path: filePath }
-          : { target: filePath },
+          : { target: filePath }) },
         style: undefined,
-        action: undefined,
       };
       
       expect(button.position.row).toBe(3);
       expect(button.position.col).toBe(4);
-      expect(button.action_type).toBe('Open');
+      expect(button.action.type).toBe('Open');
       expect(button.label).toBe('vim');
     });
 
@@ -621,14 +605,12 @@ describe('Button Generation Logic', () => {
         
         const button = {
           position: { row: currentRow, col: currentCol },
-          action_type: actionType,
           label: buttonLabel,
           icon: undefined,
-          config: isExecutable 
+          action: { type: actionType, ...(isExecutable 
             ? { path: filePath }
-            : { target: filePath },
+            : { target: filePath }) },
           style: undefined,
-          action: undefined,
         };
         
         buttons.push(button);
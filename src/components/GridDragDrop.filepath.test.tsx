@@ -117,18 +117,16 @@ describe('GridDragDrop - File Path Retrieval', () => {
       
       const buttonConfig = {
         position: { row: 1, col: 1 },
-        action_type: actionType,
         label: buttonLabel,
         icon: undefined,
-        config: isExecutable 
+        action: { type: actionType, ...(isExecutable 
           ? { path: filePath }
-          : { target: filePath },
+          : { target: filePath }) },
         style: undefined,
-        action: undefined,
       };
       
       // Verify button config uses full path
-      expect(buttonConfig.action_type).toBe('LaunchApp');
+      expect(buttonConfig.action.type).toBe('LaunchApp');
       expect(buttonConfig.config.path).toBe(filePath);
       expect(buttonConfig.config.path).toBe('C:\\Program Files\\MyApp\\application.exe');
       expect(buttonConfig.label).toBe('application');
@@ -144,18 +142,16 @@ describe('GridDragDrop - File Path Retrieval', () => {
       
       const buttonConfig = {
         position: { row: 1, col: 1 },
-        action_type: actionType,
         label: buttonLabel,
         icon: undefined,
-        config: isExecutable 
+        action: { type: actionType, ...(isExecutable 
           ? { path: filePath }
-          : { target: filePath },
+          : { target: filePath }) },
         style: undefined,
-        action: undefined,
       };
       
       // Verify button config uses full path
-      expect(buttonConfig.action_type).toBe('Open');
+      expect(buttonConfig.action.type).toBe('Open');
       expect(buttonConfig.config.target).toBe(filePath);
       expect(buttonConfig.config.target).toBe('C:\\Users\\John\\Documents\\report.pdf');
       expect(buttonConfig.label).toBe('report');
@@ -177,26 +173,24 @@ describe('GridDragDrop - File Path Retrieval', () => {
         
         return {
           position: { row: 1, col: index + 1 },
-          action_type: actionType,
           label: buttonLabel,
           icon: undefined,
-          config: isExecutable 
+          action: { type: actionType, ...(isExecutable 
             ? { path: filePath }
-            : { target: filePath },
+            : { target: filePath }) },
           style: undefined,
-          action: undefined,
         };
       });
       
       // Verify all button configs preserve full paths
-      expect(buttonConfigs[0].config.path).toBe('C:\\Program Files\\VSCode\\Code.exe');
-      expect(buttonConfigs[0].action_type).toBe('LaunchApp');
+      expect(buttonConfigs[0].action.path).toBe('C:\\Program Files\\VSCode\\Code.exe');
+      expect(buttonConfigs[0].action.type).toBe('LaunchApp');
       
-      expect(buttonConfigs[1].config.target).toBe('C:\\Users\\John\\Documents\\notes.txt');
-      expect(buttonConfigs[1].action_type).toBe('Open');
+      expect(buttonConfigs[1].action.target).toBe('C:\\Users\\John\\Documents\\notes.txt');
+      expect(buttonConfigs[1].action.type).toBe('Open');
       
-      expect(buttonConfigs[2].config.target).toBe('D:\\Projects\\presentation.pptx');
-      expect(buttonConfigs[2].action_type).toBe('Open');
+      expect(buttonConfigs[2].action.target).toBe('D:\\Projects\\presentation.pptx');
+      expect(buttonConfigs[2].action.type).toBe('Open');
     });
 
     it('should preserve paths with spaces in button configs', () => {
@@ -332,19 +326,17 @@ describe('GridDragDrop - File Path Retrieval', () => {
       // Create button config as it would be created from dropped file
       const buttonConfig = {
         position: { row: 1, col: 1 },
-        action_type: actionType,
         label: buttonLabel,
         icon: undefined,
-        config: isExecutable 
+        action: { type: actionType, ...(isExecutable 
           ? { path: filePath }
-          : { target: filePath },
+          : { target: filePath }) },
         style: undefined,
-        action: undefined,
       };
       
       // Simulate button click - construct action config as ActionButton does
       const actionConfig = {
-        type: buttonConfig.action_type,
+        type: buttonConfig.action.type,
         label: buttonConfig.label,
         ...buttonConfig.config
       };
@@ -372,19 +364,17 @@ describe('GridDragDrop - File Path Retrieval', () => {
       // Create button config as it would be created from dropped file
       const buttonConfig = {
         position: { row: 1, col: 1 },
-        action_type: actionType,
         label: buttonLabel,
         icon: undefined,
-        config: isExecutable 
+        action: { type: actionType, ...(isExecutable 
           ? { path: filePath }
-          : { target: filePath },
+          : { target: filePath }) },
         style: undefined,
-        action: undefined,
       };
       
       // Simulate button click - construct action config as ActionButton does
       const actionConfig = {
-        type: buttonConfig.action_type,
+        type: buttonConfig.action.type,
         label: buttonConfig.label,
         ...buttonConfig.config
       };
@@ -418,21 +408,19 @@ describe('GridDragDrop - File Path Retrieval', () => {
         
         return {
           position: { row: 1, col: index + 1 },
-          action_type: actionType,
           label: buttonLabel,
           icon: undefined,
-          config: isExecutable 
+          action: { type: actionType, ...(isExecutable 
             ? { path: filePath }
-            : { target: filePath },
+            : { target: filePath }) },
           style: undefined,
-          action: undefined,
         };
       });
       
       // Simulate clicking each button
       for (const buttonConfig of buttonConfigs) {
         const actionConfig = {
-          type: buttonConfig.action_type,
+          type: buttonConfig.action.type,
           label: buttonConfig.label,
           ...buttonConfig.config
         };
@@ -498,18 +486,16 @@ describe('GridDragDrop - File Path Retrieval', () => {
         
         const buttonConfig = {
           position: { row: 1, col: 1 },
-          action_type: actionType,
           label: buttonLabel,
           icon: undefined,
-          config: isExecutable 
+          action: { type: actionType, ...(isExecutable 
             ? { path: testCase.filePath }
-            : { target: testCase.filePath },
+            : { target: testCase.filePath }) },
           style: undefined,
-          action: undefined,
         };
         
         const actionConfig = {
-          type: buttonConfig.action_type,
+          type: buttonConfig.action.type,
           label: buttonConfig.label,
           ...buttonConfig.config
         };
@@ -11,8 +11,7 @@ describe('Icon Integration with ActionButton', () => {
     const button: ActionButtonType = {
       position: { row: 1, col: 1 },
       label: 'Test',
-      action_type: 'LaunchApp',
-      config: { path: 'test.exe' },
+      action: { type: 'LaunchApp', path: 'test.exe' },
       icon: '🚀'
     };
 
@@ -33,8 +32,7 @@ describe('Icon Integration with ActionButton', () => {
     const button: ActionButtonType = {
       position: { row: 1, col: 1 },
       label: 'Test',
-      action_type: 'LaunchApp',
-      config: { path: 'test.exe' },
+      action: { type: 'LaunchApp', path: 'test.exe' },
       icon: 'C:\\icons\\app.png'
     };
 
@@ -58,8 +56,7 @@ describe('Icon Integration with ActionButton', () => {
     const button: ActionButtonType = {
       position: { row: 1, col: 1 },
       label: 'Test',
-      action_type: 'LaunchApp',
-      config: { path: 'test.exe' },
+      action: { type: 'LaunchApp', path: 'test.exe' },
       icon: 'C:\\icons\\app.svg'
     };
 
@@ -82,8 +79,7 @@ describe('Icon Integration with ActionButton', () => {
     const button: ActionButtonType = {
       position: { row: 1, col: 1 },
       label: 'Test',
-      action_type: 'LaunchApp',
-      config: { path: 'test.exe' },
+      action: { type: 'LaunchApp', path: 'test.exe' },
       icon: 'https://example.com/icon.png'
     };
 
@@ -106,8 +102,7 @@ describe('Icon Integration with ActionButton', () => {
     const button: ActionButtonType = {
       position: { row: 1, col: 1 },
       label: 'Test',
-      action_type: 'LaunchApp',
-      config: { path: 'test.exe' },
+      action: { type: 'LaunchApp', path: 'test.exe' },
       icon: 'C:\\icons\\app.ico'
     };
 
@@ -130,8 +125,7 @@ describe('Icon Integration with ActionButton', () => {
     const button: ActionButtonType = {
       position: { row: 1, col: 1 },
       label: 'Test',
-      action_type: 'LaunchApp',
-      config: { path: 'test.exe' }
+      action: { type: 'LaunchApp', path: 'test.exe' }
     };
 
     const { container } = render(
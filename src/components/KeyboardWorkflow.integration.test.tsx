@@ -48,38 +48,33 @@ describe('F11 → Number Key Workflow (Keyboard-Only Operation)', () => {
             buttons: [
               {
                 position: { row: 1, col: 1 },
-                action_type: 'LaunchApp',
                 label: 'VS Code',
                 icon: '💻',
-                config: { path: 'code.exe' },
+                action: { type: 'LaunchApp', path: 'code.exe' },
               },
               {
                 position: { row: 1, col: 2 },
-                action_type: 'LaunchApp',
                 label: 'Chrome',
                 icon: '🌐',
-                config: { path: 'chrome.exe' },
+                action: { type: 'LaunchApp', path: 'chrome.exe' },
               },
               {
                 position: { row: 1, col: 3 },
-                action_type: 'Open',
                 label: 'Documents',
                 icon: '📁',
-                config: { path: 'C:\\Users\\Documents' },
+                action: { type: 'Open', path: 'C:\\Users\\Documents' },
               },
               {
                 position: { row: 2, col: 1 },
-                action_type: 'Terminal',
                 label: 'PowerShell',
                 icon: '⚡',
-                config: { terminal: 'PowerShell' },
+                action: { type: 'Terminal', terminal: 'PowerShell' },
               },
               {
                 position: { row: 2, col: 2 },
-                action_type: 'LaunchApp',
                 label: 'Notepad',
                 icon: '📝',
-                config: { path: 'notepad.exe' },
+                action: { type: 'LaunchApp', path: 'notepad.exe' },
               },
             ],
           },
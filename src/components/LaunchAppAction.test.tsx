@@ -35,10 +35,10 @@ describe('LaunchApp Action Tests', () => {
       
       const button: ActionButtonType = {
         position: { row: 1, col: 1 },
-        action_type: 'LaunchApp',
         label: 'Notepad',
         icon: '📝',
-        config: {
+        action: {
+          type: 'LaunchApp',
           path: 'notepad.exe'
         }
       };
@@ -62,10 +62,10 @@ describe('LaunchApp Action Tests', () => {
       
       const button: ActionButtonType = {
         position: { row: 1, col: 1 },
-        action_type: 'LaunchApp',
         label: 'Calculator',
         icon: '🔢',
-        config: {
+        action: {
+          type: 'LaunchApp',
           path: 'calc.exe'
         }
       };
@@ -89,10 +89,10 @@ describe('LaunchApp Action Tests', () => {
       
       const button: ActionButtonType = {
         position: { row: 1, col: 1 },
-        action_type: 'LaunchApp',
         label: 'VS Code',
         icon: '💻',
-        config: {
+        action: {
+          type: 'LaunchApp',
           path: 'C:\\Program Files\\Microsoft VS Code\\Code.exe'
         }
       };
@@ -116,10 +116,10 @@ describe('LaunchApp Action Tests', () => {
       
       const button: ActionButtonType = {
         position: { row: 1, col: 1 },
-        action_type: 'LaunchApp',
         label: 'Test App',
         icon: '🚀',
-        config: {
+        action: {
+          type: 'LaunchApp',
           path: 'test.exe'
         }
       };
@@ -142,10 +142,10 @@ describe('LaunchApp Action Tests', () => {
     it('should display correct icon for LaunchApp buttons', () => {
       const button: ActionButtonType = {
         position: { row: 1, col: 1 },
-        action_type: 'LaunchApp',
         label: 'Notepad',
         icon: '📝',
-        config: {
+        action: {
+          type: 'LaunchApp',
           path: 'notepad.exe'
         }
       };
@@ -161,9 +161,9 @@ describe('LaunchApp Action Tests', () => {
     it('should use default rocket icon when no icon is provided', () => {
       const button: ActionButtonType = {
         position: { row: 1, col: 1 },
-        action_type: 'LaunchApp',
         label: 'App',
-        config: {
+        action: {
+          type: 'LaunchApp',
           path: 'app.exe'
         }
       };
@@ -182,10 +182,10 @@ describe('LaunchApp Action Tests', () => {
       
       const button: ActionButtonType = {
         position: { row: 1, col: 1 },
-        action_type: 'LaunchApp',
         label: 'Complex App',
         icon: '⚙️',
-        config: {
+        action: {
+          type: 'LaunchApp',
           path: 'app.exe',
           args: ['--flag', 'value'],
           workdir: 'C:\\workspace',
@@ -217,10 +217,10 @@ describe('LaunchApp Action Tests', () => {
       
       const button: ActionButtonType = {
         position: { row: 1, col: 1 },
-        action_type: 'LaunchApp',
         label: 'Notepad with File',
         icon: '📝',
-        config: {
+        action: {
+          type: 'LaunchApp',
           path: 'notepad.exe',
           args: ['C:\\test\\file.txt']
         }
@@ -246,10 +246,10 @@ describe('LaunchApp Action Tests', () => {
       
       const button: ActionButtonType = {
         position: { row: 1, col: 1 },
-        action_type: 'LaunchApp',
         label: 'App with Args',
         icon: '🚀',
-        config: {
+        action: {
+          type: 'LaunchApp',
           path: 'app.exe',
           args: ['--flag1', 'value1', '--flag2', 'value2']
         }
@@ -277,10 +277,10 @@ describe('LaunchApp Action Tests', () => {
       
       const button: ActionButtonType = {
         position: { row: 1, col: 1 },
-        action_type: 'LaunchApp',
         label: 'PowerShell in Dir',
         icon: '💻',
-        config: {
+        action: {
+          type: 'LaunchApp',
           path: 'powershell.exe',
           workdir: 'C:\\Projects\\MyProject'
         }
@@ -306,10 +306,10 @@ describe('LaunchApp Action Tests', () => {
       
       const button: ActionButtonType = {
         position: { row: 1, col: 1 },
-        action_type: 'LaunchApp',
         label: 'Git Status',
         icon: '📊',
-        config: {
+        action: {
+          type: 'LaunchApp',
           path: 'powershell.exe',
           args: ['-NoExit', '-Command', 'git status'],
           workdir: 'C:\\Projects\\MyRepo'
@@ -339,10 +339,10 @@ describe('LaunchApp Action Tests', () => {
       
       const button: ActionButtonType = {
         position: { row: 1, col: 1 },
-        action_type: 'LaunchApp',
         label: 'App with Env',
         icon: '⚙️',
-        config: {
+        action: {
+          type: 'LaunchApp',
           path: 'app.exe',
           env: {
             MY_VAR: 'my_value',
@@ -374,10 +374,10 @@ describe('LaunchApp Action Tests', () => {
       
       const button: ActionButtonType = {
         position: { row: 1, col: 1 },
-        action_type: 'LaunchApp',
         label: 'Full Config',
         icon: '🎯',
-        config: {
+        action: {
+          type: 'LaunchApp',
           path: 'powershell.exe',
           args: ['-NoExit', '-Command', 'Write-Host $env:TEST_VAR'],
           workdir: 'C:\\TestDir',
@@ -416,10 +416,10 @@ describe('LaunchApp Action Tests', () => {
       
       const button: ActionButtonType = {
         position: { row: 1, col: 1 },
-        action_type: 'LaunchApp',
         label: 'Failing App',
         icon: '❌',
-        config: {
+        action: {
+          type: 'LaunchApp',
           path: 'nonexistent.exe'
         }
       };
@@ -35,10 +35,10 @@ describe('Open Action Tests', () => {
       
       const button: ActionButtonType = {
         position: { row: 1, col: 1 },
-        action_type: 'Open',
         label: 'Documents',
         icon: '📁',
-        config: {
+        action: {
+          type: 'Open',
           target: 'C:\\Users\\TestUser\\Documents'
         }
       };
@@ -62,10 +62,10 @@ describe('Open Action Tests', () => {
       
       const button: ActionButtonType = {
         position: { row: 1, col: 1 },
-        action_type: 'Open',
         label: 'User Profile',
         icon: '👤',
-        config: {
+        action: {
+          type: 'Open',
           target: '%USERPROFILE%'
         }
       };
@@ -89,10 +89,10 @@ describe('Open Action Tests', () => {
       
       const button: ActionButtonType = {
         position: { row: 1, col: 1 },
-        action_type: 'Open',
         label: 'Downloads',
         icon: '⬇️',
-        config: {
+        action: {
+          type: 'Open',
           target: 'C:\\Users\\TestUser\\Downloads'
         }
       };
@@ -118,10 +118,10 @@ describe('Open Action Tests', () => {
       
       const button: ActionButtonType = {
         position: { row: 1, col: 1 },
-        action_type: 'Open',
         label: 'README',
         icon: '📄',
-        config: {
+        action: {
+          type: 'Open',
           target: 'C:\\Projects\\MyProject\\README.md'
         }
       };
@@ -145,10 +145,10 @@ describe('Open Action Tests', () => {
       
       const button: ActionButtonType = {
         position: { row: 1, col: 1 },
-        action_type: 'Open',
         label: 'Notes',
         icon: '📝',
-        config: {
+        action: {
+          type: 'Open',
           target: 'C:\\Users\\TestUser\\notes.txt'
         }
       };
@@ -172,10 +172,10 @@ describe('Open Action Tests', () => {
       
       const button: ActionButtonType = {
         position: { row: 1, col: 1 },
-        action_type: 'Open',
         label: 'Photo',
         icon: '🖼️',
-        config: {
+        action: {
+          type: 'Open',
           target: 'C:\\Pictures\\photo.jpg'
         }
       };
@@ -199,10 +199,10 @@ describe('Open Action Tests', () => {
       
       const button: ActionButtonType = {
         position: { row: 1, col: 1 },
-        action_type: 'Open',
         label: 'Manual',
         icon: '📕',
-        config: {
+        action: {
+          type: 'Open',
           target: 'C:\\Documents\\manual.pdf'
         }
       };
@@ -228,10 +228,10 @@ describe('Open Action Tests', () => {
       
       const button: ActionButtonType = {
         position: { row: 1, col: 1 },
-        action_type: 'Open',
         label: 'Test Folder',
         icon: '📂',
-        config: {
+        action: {
+          type: 'Open',
           target: 'C:\\Test'
         }
       };
@@ -254,10 +254,10 @@ describe('Open Action Tests', () => {
     it('should display correct icon for Open buttons', () => {
       const button: ActionButtonType = {
         position: { row: 1, col: 1 },
-        action_type: 'Open',
         label: 'Folder',
         icon: '📁',
-        config: {
+        action: {
+          type: 'Open',
           target: 'C:\\Folder'
         }
       };
@@ -273,9 +273,9 @@ describe('Open Action Tests', () => {
     it('should use default folder icon when no icon is provided', () => {
       const button: ActionButtonType = {
         position: { row: 1, col: 1 },
-        action_type: 'Open',
         label: 'Folder',
-        config: {
+        action: {
+          type: 'Open',
           target: 'C:\\Folder'
         }
       };
@@ -297,10 +297,10 @@ describe('Open Action Tests', () => {
       
       const button: ActionButtonType = {
         position: { row: 1, col: 1 },
-        action_type: 'Open',
         label: 'Invalid Path',
         icon: '❌',
-        config: {
+        action: {
+          type: 'Open',
           target: 'C:\\NonExistent\\Path'
         }
       };
@@ -328,10 +328,10 @@ describe('Open Action Tests', () => {
       
       const button: ActionButtonType = {
         position: { row: 1, col: 1 },
-        action_type: 'Open',
         label: 'Program Files',
         icon: '💾',
-        config: {
+        action: {
+          type: 'Open',
           target: 'C:\\Program Files'
         }
       };
@@ -355,10 +355,10 @@ describe('Open Action Tests', () => {
       
       const button: ActionButtonType = {
         position: { row: 1, col: 1 },
-        action_type: 'Open',
         label: 'Network Share',
         icon: '🌐',
-        config: {
+        action: {
+          type: 'Open',
           target: '\\\\server\\share'
         }
       };
@@ -382,10 +382,10 @@ describe('Open Action Tests', () => {
       
       const button: ActionButtonType = {
         position: { row: 1, col: 1 },
-        action_type: 'Open',
         label: 'Relative Folder',
         icon: '📂',
-        config: {
+        action: {
+          type: 'Open',
           target: '.\\subfolder'
         }
       };
@@ -411,10 +411,10 @@ describe('Open Action Tests', () => {
       
       const button: ActionButtonType = {
         position: { row: 1, col: 1 },
-        action_type: 'Open',
         label: 'Example Site',
         icon: '🌐',
-        config: {
+        action: {
+          type: 'Open',
           target: 'http://example.com'
         }
       };
@@ -438,10 +438,10 @@ describe('Open Action Tests', () => {
       
       const button: ActionButtonType = {
         position: { row: 1, col: 1 },
-        action_type: 'Open',
         label: 'Google',
         icon: '🔍',
-        config: {
+        action: {
+          type: 'Open',
           target: 'https://www.google.com'
         }
       };
@@ -465,10 +465,10 @@ describe('Open Action Tests', () => {
       
       const button: ActionButtonType = {
         position: { row: 1, col: 1 },
-        action_type: 'Open',
         label: 'Dev Server',
         icon: '🖥️',
-        config: {
+        action: {
+          type: 'Open',
           target: 'http://localhost:3000'
         }
       };
@@ -492,10 +492,10 @@ describe('Open Action Tests', () => {
       
       const button: ActionButtonType = {
         position: { row: 1, col: 1 },
-        action_type: 'Open',
         label: 'Search',
         icon: '🔎',
-        config: {
+        action: {
+          type: 'Open',
           target: 'https://www.google.com/search?q=electron'
         }
       };
@@ -519,10 +519,10 @@ describe('Open Action Tests', () => {
       
       const button: ActionButtonType = {
         position: { row: 1, col: 1 },
-        action_type: 'Open',
         label: 'GitHub Repo',
         icon: '📦',
-        config: {
+        action: {
+          type: 'Open',
           target: 'https://github.com/electron/electron'
         }
       };
@@ -546,10 +546,10 @@ describe('Open Action Tests', () => {
       
       const button: ActionButtonType = {
         position: { row: 1, col: 1 },
-        action_type: 'Open',
         label: 'Docs Section',
         icon: '📖',
-        config: {
+        action: {
+          type: 'Open',
           target: 'https://github.com/electron/electron#readme'
         }
       };
@@ -70,10 +70,10 @@ describe('Open Action Auto-Close Integration Test', () => {
 
     const button: ActionButtonType = {
       position: { row: 1, col: 1 },
-      action_type: 'Open',
       label: 'Test Document',
       icon: '📄',
-      config: {
+      action: {
+        type: 'Open',
         target: 'C:\\Documents\\test.txt'
       }
     };
@@ -128,10 +128,10 @@ describe('Open Action Auto-Close Integration Test', () => {
 
     const button: ActionButtonType = {
       position: { row: 1, col: 1 },
-      action_type: 'LaunchApp',
       label: 'Notepad',
       icon: '📝',
-      config: {
+      action: {
+        type: 'LaunchApp',
         path: 'notepad.exe'
       }
     };
@@ -174,10 +174,10 @@ describe('Open Action Auto-Close Integration Test', () => {
 
     const button: ActionButtonType = {
       position: { row: 1, col: 1 },
-      action_type: 'Open',
       label: 'Missing File',
       icon: '📄',
-      config: {
+      action: {
+        type: 'Open',
         target: 'C:\\nonexistent.txt'
       }
     };
@@ -220,18 +220,16 @@ describe('Open Action Auto-Close Integration Test', () => {
 
     const button1: ActionButtonType = {
       position: { row: 1, col: 1 },
-      action_type: 'Open',
       label: 'File 1',
       icon: '📄',
-      config: { target: 'C:\\file1.txt' }
+      action: { type: 'Open', target: 'C:\\file1.txt' }
     };
 
     const button2: ActionButtonType = {
       position: { row: 1, col: 2 },
-      action_type: 'Open',
       label: 'File 2',
       icon: '📄',
-      config: { target: 'C:\\file2.txt' }
+      action: { type: 'Open', target: 'C:\\file2.txt' }
     };
 
     const { rerender } = render(<ActionButton button={button1} />);
@@ -286,10 +284,10 @@ describe('Open Action Auto-Close Integration Test', () => {
 
     const button: ActionButtonType = {
       position: { row: 1, col: 1 },
-      action_type: 'Terminal',
       label: 'PowerShell',
       icon: '💻',
-      config: {
+      action: {
+        type: 'Terminal',
         terminal: 'PowerShell'
       }
     };
@@ -327,14 +325,9 @@ describe('Open Action Auto-Close Integration Test', () => {
 
     const button: ActionButtonType = {
       position: { row: 1, col: 1 },
-      action_type: 'LaunchApp', // System actions still use a base action_type
       label: 'Settings',
       icon: '⚙️',
-      config: {},
-      action: {
-        action_type: 'system',
-        system_action: 'config'
-      }
+      action: { type: 'System', system_action: 'config' }
     };
 
     render(<ActionButton button={button} onSystemAction={mockSystemActionHandler} />);
@@ -58,10 +58,10 @@ describe('Open Action Detection and Auto-Close', () => {
 
     const button: ActionButtonType = {
       position: { row: 1, col: 1 },
-      action_type: 'Open',
       label: 'Test File',
       icon: '📄',
-      config: {
+      action: {
+        type: 'Open',
         target: 'C:\\test.txt'
       }
     };
@@ -93,10 +93,10 @@ describe('Open Action Detection and Auto-Close', () => {
 
     const button: ActionButtonType = {
       position: { row: 1, col: 1 },
-      action_type: 'LaunchApp',
       label: 'Notepad',
       icon: '📝',
-      config: {
+      action: {
+        type: 'LaunchApp',
         path: 'notepad.exe'
       }
     };
@@ -123,10 +123,10 @@ describe('Open Action Detection and Auto-Close', () => {
 
     const button: ActionButtonType = {
       position: { row: 1, col: 1 },
-      action_type: 'Terminal',
       label: 'PowerShell',
       icon: '💻',
-      config: {
+      action: {
+        type: 'Terminal',
         terminal: 'PowerShell',
         workdir: 'C:\\Users'
       }
@@ -148,14 +148,9 @@ describe('Open Action Detection and Auto-Close', () => {
 
     const button: ActionButtonType = {
       position: { row: 1, col: 1 },
-      action_type: 'system',
       label: 'Settings',
       icon: '⚙️',
-      config: {},
-      action: {
-        action_type: 'system',
-        system_action: 'config'
-      }
+      action: { type: 'System', system_action: 'config' }
     };
 
     render(<ActionButton button={button} onSystemAction={onSystemAction} />);
@@ -181,10 +176,10 @@ describe('Open Action Detection and Auto-Close', () => {
 
     const button: ActionButtonType = {
       position: { row: 1, col: 1 },
-      action_type: 'Open',
       label: 'Missing File',
       icon: '📄',
-      config: {
+      action: {
+        type: 'Open',
         target: 'C:\\nonexistent.txt'
       }
     };
@@ -211,18 +206,16 @@ describe('Open Action Detection and Auto-Close', () => {
 
     const button1: ActionButtonType = {
       position: { row: 1, col: 1 },
-      action_type: 'Open',
       label: 'File 1',
       icon: '📄',
-      config: { target: 'C:\\file1.txt' }
+      action: { type: 'Open', target: 'C:\\file1.txt' }
     };
 
     const button2: ActionButtonType = {
       position: { row: 1, col: 2 },
-      action_type: 'Open',
       label: 'File 2',
       icon: '📄',
-      config: { target: 'C:\\file2.txt' }
+      action: { type: 'Open', target: 'C:\\file2.txt' }
     };
 
     const { rerender } = render(<ActionButton button={button1} />);
@@ -81,10 +81,10 @@ describe('Performance Tests', () => {
     it('should render ActionButton component efficiently', async () => {
       const mockButton: ActionButtonType = {
         position: { row: 0, col: 0 },
-        action_type: 'LaunchApp',
         label: 'Test Button',
         icon: '🚀',
-        config: {
+        action: {
+          type: 'LaunchApp',
           path: 'notepad.exe',
         },
       };
@@ -125,10 +125,9 @@ describe('Performance Tests', () => {
     it('should render GridCell component efficiently', async () => {
       const mockButton: ActionButtonType = {
         position: { row: 0, col: 0 },
-        action_type: 'LaunchApp',
         label: 'Test',
         icon: '🚀',
-        config: {},
+        action: { type: 'LaunchApp' },
       };
 
       const startTime = performance.now();
@@ -167,10 +166,9 @@ describe('Performance Tests', () => {
     it('should minimize re-renders when props do not change', async () => {
       const mockButton: ActionButtonType = {
         position: { row: 0, col: 0 },
-        action_type: 'LaunchApp',
         label: 'Test',
         icon: '🚀',
-        config: {},
+        action: { type: 'LaunchApp' },
       };
 
       let renderCount = 0;
@@ -207,18 +205,16 @@ describe('Performance Tests', () => {
     it('should re-render only when necessary props change', async () => {
       const mockButton1: ActionButtonType = {
         position: { row: 0, col: 0 },
-        action_type: 'LaunchApp',
         label: 'Test 1',
         icon: '🚀',
-        config: {},
+        action: { type: 'LaunchApp' },
       };
 
       const mockButton2: ActionButtonType = {
         position: { row: 0, col: 0 },
-        action_type: 'LaunchApp',
         label: 'Test 2',
         icon: '🎯',
-        config: {},
+        action: { type: 'LaunchApp' },
       };
 
       let renderCount = 0;
@@ -349,10 +345,9 @@ describe('Performance Tests', () => {
                 cols: 8,
                 buttons: Array.from({ length: 40 }, (_, i) => ({
                   position: { row: Math.floor(i / 8), col: i % 8 },
-                  action_type: 'LaunchApp' as const,
                   label: `Button ${i + 1}`,
                   icon: '🚀',
-                  config: { path: 'notepad.exe' },
+                  action: { type: 'LaunchApp', path: 'notepad.exe' },
                 })),
               },
             ],
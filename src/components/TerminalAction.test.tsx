@@ -35,10 +35,10 @@ describe('Terminal Action Tests', () => {
       
       const button: ActionButtonType = {
         position: { row: 1, col: 1 },
-        action_type: 'Terminal',
         label: 'PowerShell',
         icon: '💻',
-        config: {
+        action: {
+          type: 'Terminal',
           terminal: 'PowerShell'
         }
       };
@@ -62,10 +62,10 @@ describe('Terminal Action Tests', () => {
       
       const button: ActionButtonType = {
         position: { row: 1, col: 1 },
-        action_type: 'Terminal',
         label: 'PowerShell in Projects',
         icon: '💻',
-        config: {
+        action: {
+          type: 'Terminal',
           terminal: 'PowerShell',
           workdir: 'C:\\Projects'
         }
@@ -91,10 +91,10 @@ describe('Terminal Action Tests', () => {
       
       const button: ActionButtonType = {
         position: { row: 1, col: 1 },
-        action_type: 'Terminal',
         label: 'Git Status',
         icon: '📊',
-        config: {
+        action: {
+          type: 'Terminal',
           terminal: 'PowerShell',
           command: 'git status'
         }
@@ -120,10 +120,10 @@ describe('Terminal Action Tests', () => {
       
       const button: ActionButtonType = {
         position: { row: 1, col: 1 },
-        action_type: 'Terminal',
         label: 'Project Status',
         icon: '📊',
-        config: {
+        action: {
+          type: 'Terminal',
           terminal: 'PowerShell',
           workdir: 'C:\\Projects\\MyRepo',
           command: 'git status'
@@ -153,10 +153,10 @@ describe('Terminal Action Tests', () => {
       
       const button: ActionButtonType = {
         position: { row: 1, col: 1 },
-        action_type: 'Terminal',
         label: 'Command Prompt',
         icon: '⌨️',
-        config: {
+        action: {
+          type: 'Terminal',
           terminal: 'Cmd'
         }
       };
@@ -180,10 +180,10 @@ describe('Terminal Action Tests', () => {
       
       const button: ActionButtonType = {
         position: { row: 1, col: 1 },
-        action_type: 'Terminal',
         label: 'Cmd in Projects',
         icon: '⌨️',
-        config: {
+        action: {
+          type: 'Terminal',
           terminal: 'Cmd',
           workdir: 'C:\\Projects'
         }
@@ -209,10 +209,10 @@ describe('Terminal Action Tests', () => {
       
       const button: ActionButtonType = {
         position: { row: 1, col: 1 },
-        action_type: 'Terminal',
         label: 'Dir Listing',
         icon: '📋',
-        config: {
+        action: {
+          type: 'Terminal',
           terminal: 'Cmd',
           command: 'dir'
         }
@@ -238,10 +238,10 @@ describe('Terminal Action Tests', () => {
       
       const button: ActionButtonType = {
         position: { row: 1, col: 1 },
-        action_type: 'Terminal',
         label: 'Project Dir',
         icon: '📋',
-        config: {
+        action: {
+          type: 'Terminal',
           terminal: 'Cmd',
           workdir: 'C:\\Projects\\MyRepo',
           command: 'dir'
@@ -271,10 +271,10 @@ describe('Terminal Action Tests', () => {
       
       const button: ActionButtonType = {
         position: { row: 1, col: 1 },
-        action_type: 'Terminal',
         label: 'Test Terminal',
         icon: '💻',
-        config: {
+        action: {
+          type: 'Terminal',
           terminal: 'PowerShell'
         }
       };
@@ -297,10 +297,10 @@ describe('Terminal Action Tests', () => {
     it('should display correct icon for Terminal buttons', () => {
       const button: ActionButtonType = {
         position: { row: 1, col: 1 },
-        action_type: 'Terminal',
         label: 'Terminal',
         icon: '💻',
-        config: {
+        action: {
+          type: 'Terminal',
           terminal: 'PowerShell'
         }
       };
@@ -316,9 +316,9 @@ describe('Terminal Action Tests', () => {
     it('should use default terminal icon when no icon is provided', () => {
       const button: ActionButtonType = {
         position: { row: 1, col: 1 },
-        action_type: 'Terminal',
         label: 'Terminal',
-        config: {
+        action: {
+          type: 'Terminal',
           terminal: 'PowerShell'
         }
       };
@@ -337,10 +337,10 @@ describe('Terminal Action Tests', () => {
       
       const button: ActionButtonType = {
         position: { row: 1, col: 1 },
-        action_type: 'Terminal',
         label: 'PowerShell with Env',
         icon: '⚙️',
-        config: {
+        action: {
+          type: 'Terminal',
           terminal: 'PowerShell',
           env: {
             MY_VAR: 'my_value',
@@ -372,10 +372,10 @@ describe('Terminal Action Tests', () => {
       
       const button: ActionButtonType = {
         position: { row: 1, col: 1 },
-        action_type: 'Terminal',
         label: 'Full Config',
         icon: '🎯',
-        config: {
+        action: {
+          type: 'Terminal',
           terminal: 'PowerShell',
           workdir: 'C:\\TestDir',
           command: 'Write-Host $env:TEST_VAR',
@@ -411,10 +411,10 @@ describe('Terminal Action Tests', () => {
       
       const button: ActionButtonType = {
         position: { row: 1, col: 1 },
-        action_type: 'Terminal',
         label: 'Windows Terminal',
         icon: '🪟',
-        config: {
+        action: {
+          type: 'Terminal',
           terminal: 'WindowsTerminal'
         }
       };
@@ -438,10 +438,10 @@ describe('Terminal Action Tests', () => {
       
       const button: ActionButtonType = {
         position: { row: 1, col: 1 },
-        action_type: 'Terminal',
         label: 'WT PowerShell',
         icon: '🪟',
-        config: {
+        action: {
+          type: 'Terminal',
           terminal: 'WindowsTerminal',
           profile: 'PowerShell'
         }
@@ -467,10 +467,10 @@ describe('Terminal Action Tests', () => {
       
       const button: ActionButtonType = {
         position: { row: 1, col: 1 },
-        action_type: 'Terminal',
         label: 'WT in Projects',
         icon: '🪟',
-        config: {
+        action: {
+          type: 'Terminal',
           terminal: 'WindowsTerminal',
           workdir: 'C:\\Projects'
         }
@@ -496,10 +496,10 @@ describe('Terminal Action Tests', () => {
       
       const button: ActionButtonType = {
         position: { row: 1, col: 1 },
-        action_type: 'Terminal',
         label: 'WT Git Status',
         icon: '📊',
-        config: {
+        action: {
+          type: 'Terminal',
           terminal: 'WindowsTerminal',
           command: 'git status'
         }
@@ -525,10 +525,10 @@ describe('Terminal Action Tests', () => {
       
       const button: ActionButtonType = {
         position: { row: 1, col: 1 },
-        action_type: 'Terminal',
         label: 'WT Full Config',
         icon: '🎯',
-        config: {
+        action: {
+          type: 'Terminal',
           terminal: 'WindowsTerminal',
           profile: 'PowerShell',
           workdir: 'C:\\Projects\\MyRepo',
@@ -566,10 +566,10 @@ describe('Terminal Action Tests', () => {
       
       const button: ActionButtonType = {
         position: { row: 1, col: 1 },
-        action_type: 'Terminal',
         label: 'WSL',
         icon: '🐧',
-        config: {
+        action: {
+          type: 'Terminal',
           terminal: 'WSL'
         }
       };
@@ -593,10 +593,10 @@ describe('Terminal Action Tests', () => {
       
       const button: ActionButtonType = {
         position: { row: 1, col: 1 },
-        action_type: 'Terminal',
         label: 'Ubuntu',
         icon: '🐧',
-        config: {
+        action: {
+          type: 'Terminal',
           terminal: 'WSL',
           profile: 'Ubuntu'
         }
@@ -622,10 +622,10 @@ describe('Terminal Action Tests', () => {
       
       const button: ActionButtonType = {
         position: { row: 1, col: 1 },
-        action_type: 'Terminal',
         label: 'WSL in Projects',
         icon: '🐧',
-        config: {
+        action: {
+          type: 'Terminal',
           terminal: 'WSL',
           workdir: 'C:\\Projects'
         }
@@ -651,10 +651,10 @@ describe('Terminal Action Tests', () => {
       
       const button: ActionButtonType = {
         position: { row: 1, col: 1 },
-        action_type: 'Terminal',
         label: 'WSL ls',
         icon: '📋',
-        config: {
+        action: {
+          type: 'Terminal',
           terminal: 'WSL',
           command: 'ls -la'
         }
@@ -680,10 +680,10 @@ describe('Terminal Action Tests', () => {
       
       const button: ActionButtonType = {
         position: { row: 1, col: 1 },
-        action_type: 'Terminal',
         label: 'WSL Full Config',
         icon: '🎯',
-        config: {
+        action: {
+          type: 'Terminal',
           terminal: 'WSL',
           profile: 'Ubuntu',
           workdir: 'C:\\Projects\\MyRepo',
@@ -724,10 +724,10 @@ describe('Terminal Action Tests', () => {
       
       const button: ActionButtonType = {
         position: { row: 1, col: 1 },
-        action_type: 'Terminal',
         label: 'Failing Terminal',
         icon: '❌',
-        config: {
+        action: {
+          type: 'Terminal',
           terminal: 'PowerShell'
         }
       };
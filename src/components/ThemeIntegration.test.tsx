@@ -23,9 +23,8 @@ vi.mock('../lib/platform-api', async () => {
                 buttons: [
                   {
                     position: { row: 1, col: 1 },
-                    type: 'LaunchApp',
                     label: 'Test Button',
-                    config: { path: 'test.exe' },
+                    action: { type: 'LaunchApp', path: 'test.exe' },
                     style: {
                       background_color: '#1e40af',
                       text_color: '#ffffff',
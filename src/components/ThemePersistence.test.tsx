@@ -35,9 +35,8 @@ function TestComponent({ config, setConfig }: {
     <div>
       <button onClick={() => handleThemeButton({
         position: { row: 1, col: 1 },
-        type: 'LaunchApp',
         label: 'Test Button',
-        config: { path: 'test.exe' },
+        action: { type: 'LaunchApp', path: 'test.exe' },
         style: {
           background_color: '#000000',
           text_color: '#ffffff',
@@ -86,9 +85,8 @@ describe('Theme Persistence Tests', () => {
               buttons: [
                 {
                   position: { row: 1, col: 1 },
-                  type: 'LaunchApp',
                   label: 'Test Button',
-                  config: { path: 'test.exe' },
+                  action: { type: 'LaunchApp', path: 'test.exe' },
                   style: {
                     background_color: '#000000',
                     text_color: '#ffffff',
@@ -237,7 +235,7 @@ describe('Theme Persistence Tests', () => {
 
     const originalButton = mockConfig.profiles[0].pages[0].buttons[0];
     const originalLabel = originalButton.label;
-    const originalPath = originalButton.config.path;
+    const originalPath = originalButton.action.path;
 
     // Apply theme
     fireEvent.click(screen.getByText('Open Theme Selector'));
@@ -252,9 +250,9 @@ describe('Theme Persistence Tests', () => {
 
     // Verify other properties are preserved
     expect(updatedButton.label).toBe(originalLabel);
-    expect(updatedButton.config.path).toBe(originalPath);
+    expect(updatedButton.action.path).toBe(originalPath);
     expect(updatedButton.position).toEqual(originalButton.position);
-    expect(updatedButton.type).toBe(originalButton.type);
+    expect(updatedButton.action.type).toBe(originalButton.action.type);
   });
 
   it('handles theme with all style properties', async () => {
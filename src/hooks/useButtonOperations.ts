@@ -158,15 +158,15 @@ export const useButtonOperations = ({
     
     try {
       const newButton: ActionButtonType = {
+        id: crypto.randomUUID(),
         position: { row, col },
-        action_type: 'LaunchApp',
         label: 'New Button',
         icon: '🚀',
-        config: {
+        style: undefined,
+        action: {
+          type: 'LaunchApp',
           path: 'notepad.exe'
         },
-        style: undefined,
-        action: undefined,
       };
       
       const newConfig = JSON.parse(JSON.stringify(tempConfig));
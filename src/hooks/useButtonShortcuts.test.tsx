@@ -7,24 +7,21 @@ describe('useButtonShortcuts', () => {
   const mockButtons: ActionButton[] = [
     {
       position: { row: 1, col: 1 },
-      action_type: 'LaunchApp',
       label: 'Button 1',
       icon: '🚀',
-      config: { path: 'app1.exe' },
+      action: { type: 'LaunchApp', path: 'app1.exe' },
     },
     {
       position: { row: 1, col: 2 },
-      action_type: 'LaunchApp',
       label: 'Button 2',
       icon: '📁',
-      config: { path: 'app2.exe' },
+      action: { type: 'LaunchApp', path: 'app2.exe' },
     },
     {
       position: { row: 1, col: 3 },
-      action_type: 'Open',
       label: 'Button 3',
       icon: '📂',
-      config: { path: 'file.txt' },
+      action: { type: 'Open', path: 'file.txt' },
     },
   ];
 
@@ -56,10 +53,9 @@ describe('useButtonShortcuts', () => {
   it('should return "0" for the 10th button (index 9)', () => {
     const tenButtons = Array.from({ length: 10 }, (_, i) => ({
       position: { row: 1, col: i + 1 },
-      action_type: 'LaunchApp' as const,
       label: `Button ${i + 1}`,
       icon: '🚀',
-      config: { path: `app${i + 1}.exe` },
+      action: { type: 'LaunchApp', path: `app${i + 1}.exe` },
     }));
 
     const { result } = renderHook(() =>
@@ -77,10 +73,9 @@ describe('useButtonShortcuts', () => {
   it('should return Shift+number shortcuts for buttons 11-20', () => {
     const twentyButtons = Array.from({ length: 20 }, (_, i) => ({
       position: { row: 1, col: i + 1 },
-      action_type: 'LaunchApp' as const,
       label: `Button ${i + 1}`,
       icon: '🚀',
-      config: { path: `app${i + 1}.exe` },
+      action: { type: 'LaunchApp', path: `app${i + 1}.exe` },
     }));
 
     const { result } = renderHook(() =>
@@ -103,10 +98,9 @@ describe('useButtonShortcuts', () => {
   it('should return null for buttons beyond the first 20', () => {
     const twentyOneButtons = Array.from({ length: 21 }, (_, i) => ({
       position: { row: 1, col: i + 1 },
-      action_type: 'LaunchApp' as const,
       label: `Button ${i + 1}`,
       icon: '🚀',
-      config: { path: `app${i + 1}.exe` },
+      action: { type: 'LaunchApp', path: `app${i + 1}.exe` },
     }));
 
     const { result } = renderHook(() =>
@@ -195,10 +189,9 @@ describe('useButtonShortcuts', () => {
 
     const unknownButton: ActionButton = {
       position: { row: 5, col: 5 },
-      action_type: 'LaunchApp',
       label: 'Unknown Button',
       icon: '❓',
-      config: { path: 'unknown.exe' },
+      action: { type: 'LaunchApp', path: 'unknown.exe' },
     };
 
     expect(result.current.getButtonShortcut(unknownButton)).toBeNull();
@@ -207,10 +200,9 @@ describe('useButtonShortcuts', () => {
   it('should call onButtonClick for Shift+number shortcuts (buttons 11-20)', () => {
     const twentyButtons = Array.from({ length: 20 }, (_, i) => ({
       position: { row: 1, col: i + 1 },
-      action_type: 'LaunchApp' as const,
       label: `Button ${i + 1}`,
       icon: '🚀',
-      config: { path: `app${i + 1}.exe` },
+      action: { type: 'LaunchApp', path: `app${i + 1}.exe` },
     }));
 
     renderHook(() =>
@@ -236,10 +228,9 @@ describe('useButtonShortcuts', () => {
   it('should call onButtonClick for Shift+0 (button 20)', () => {
     const twentyButtons = Array.from({ length: 20 }, (_, i) => ({
       position: { row: 1, col: i + 1 },
-      action_type: 'LaunchApp' as const,
       label: `Button ${i + 1}`,
       icon: '🚀',
-      config: { path: `app${i + 1}.exe` },
+      action: { type: 'LaunchApp', path: `app${i + 1}.exe` },
     }));
 
     renderHook(() =>
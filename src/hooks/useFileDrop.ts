@@ -1,5 +1,5 @@
 import { useCallback, useEffect } from 'react';
-import { QDeckConfig, tauriAPI } from '../lib/platform-api';
+import { ActionButton, QDeckConfig, tauriAPI } from '../lib/platform-api';
 import { useDragDrop } from './useDragDrop';
 import { isElectron } from '../lib/electron-adapter';
 import { logger } from '../utils/logger';
@@ -89,7 +89,7 @@ export const useFileDrop = ({
     try {
       
       // Generate buttons for all dropped files
-      const newButtons = [];
+      const newButtons: ActionButton[] = [];
       let currentRow = dropPosition.row;
       let currentCol = dropPosition.col;
       
@@ -104,7 +104,6 @@ export const useFileDrop = ({
 
         // Determine action type based on file extension
         const isExecutable = fileExtension === 'exe';
-        const actionType = isExecutable ? 'LaunchApp' : 'Open';
         
         // Create button label (filename without extension)
         // Special handling for dot files (e.g., .gitignore, .env)
@@ -139,16 +138,15 @@ export const useFileDrop = ({
         }
 
         // Create button with proper structure
-        const newButton = {
+        const newButton: ActionButton = {
+          id: crypto.randomUUID(),
           position: { row: currentRow, col: currentCol },
-          action_type: actionType,
           label: buttonLabel,
           icon: iconPath,
-          config: isExecutable 
-            ? { path: filePath }
-            : { target: filePath },
           style: undefined,
-          action: undefined,
+          action: isExecutable
+            ? { type: 'LaunchApp', path: filePath }
+            : { type: 'Open', target: filePath },
         };
 
         newButtons.push(newButton);
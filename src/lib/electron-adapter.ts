@@ -70,6 +70,15 @@ export const platformAPI = {
     throw new Error('No platform API available');
   },
 
+  // Set when config.yaml failed to load and a backup or the defaults are in use
+  getConfigLoadError: async (): Promise<string | null> => {
+    if (isTauri()) {
+      const { invoke } = await import('@tauri-apps/api/core');
+      return invoke('get_config_load_error');
+    }
+    return null;
+  },
+
   showOverlay: async () => {
     if (isElectron()) {
       return window.electronAPI!.showOverlay();
@@ -110,6 +119,31 @@ export const platformAPI = {
     throw new Error('No platform API available');
   },
 
+  listRunningActions: async () => {
+    if (isTauri()) {
+      const { invoke } = await import('@tauri-apps/api/core');
+      return invoke('list_running_actions');
+    }
+    return [];
+  },
+
+  // Kills the processes of a running action, including the ones they started
+  cancelAction: async (invocationId: string) => {
+    if (isTauri()) {
+      const { invoke } = await import('@tauri-apps/api/core');
+      return invoke('cancel_action', { invocationId });
+    }
+    throw new Error('No platform API available');
+  },
+
+  // Tells the backend which button a held summon key should run when it is let go
+  setHoveredButton: async (buttonId: string | null) => {
+    if (isTauri()) {
+      const { invoke } = await import('@tauri-apps/api/core');
+      return invoke('set_hovered_button', { buttonId });
+    }
+  },
+
   getCurrentProfile: async () => {
     if (!isElectron() && !isTauri()) {
       await waitForElectronAPI();
@@ -241,6 +275,51 @@ export const platformAPI = {
     }
   },
 
+  // Lines printed by actions run with wait or capture, tagged with their invocation ID
+  onActionOutput: (callback: (line: any) => void) => {
+    if (isTauri()) {
+      import('@tauri-apps/api/event').then(({ listen }) => {
+        listen('action-output', (event) => {
+          callback(event.payload);
+        });
+      });
+    }
+  },
+
+  // Fired before a button's action runs, so its output can be matched and cancelled while it runs
+  onActionStarted: (callback: (started: any) => void) => {
+    if (isTauri()) {
+      import('@tauri-apps/api/event').then(({ listen }) => {
+        listen('action-started', (event) => {
+          callback(event.payload);
+        });
+      });
+    }
+  },
+
+  // Overlay commands of System buttons run from their hotkey; returns an unsubscribe function
+  onSystemAction: (callback: (action: string) => void) => {
+    let unlisten: (() => void) | undefined;
+    let stopped = false;
+    if (isTauri()) {
+      import('@tauri-apps/api/event')
+        .then(({ listen }) => listen('system-action', (event) => {
+          callback(event.payload as string);
+        }))
+        .then((stop) => {
+          if (stopped) {
+            stop();
+          } else {
+            unlisten = stop;
+          }
+        });
+    }
+    return () => {
+      stopped = true;
+      unlisten?.();
+    };
+  },
+
   getPlatform: () => {
     if (isElectron()) {
       return window.electronAPI!.platform;
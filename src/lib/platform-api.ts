@@ -40,6 +40,10 @@ import type {
   CacheStats,
   ActionResult,
   ActionLog,
+  RunningAction,
+  RunningProcess,
+  ActionOutputLine,
+  ActionStarted,
   DroppedFile,
   DroppedFileType,
   ButtonGenerationRequest,
@@ -98,6 +102,10 @@ export type {
   CacheStats,
   ActionResult,
   ActionLog,
+  RunningAction,
+  RunningProcess,
+  ActionOutputLine,
+  ActionStarted,
   DroppedFile,
   DroppedFileType,
   ButtonGenerationRequest,
@@ -125,6 +133,7 @@ export const tauriAPI = {
   // Config - now using electron-adapter
   getConfig: () => platformAPI.getConfig(),
   saveConfig: (config: QDeckConfig) => platformAPI.saveConfig(config),
+  getConfigLoadError: () => platformAPI.getConfigLoadError(),
   
   // Overlay - now using electron-adapter
   showOverlay: () => platformAPI.showOverlay(),
@@ -133,6 +142,12 @@ export const tauriAPI = {
   
   // Actions - now using electron-adapter
   executeAction: (actionConfig: any): Promise<ActionResult> => platformAPI.executeAction(actionConfig) as Promise<ActionResult>,
+  setHoveredButton: (buttonId: string | null) => platformAPI.setHoveredButton(buttonId),
+  listRunningActions: (): Promise<RunningAction[]> => platformAPI.listRunningActions() as Promise<RunningAction[]>,
+  cancelAction: (invocationId: string): Promise<RunningAction> => platformAPI.cancelAction(invocationId) as Promise<RunningAction>,
+  onActionOutput: (callback: (line: ActionOutputLine) => void) => platformAPI.onActionOutput(callback),
+  onActionStarted: (callback: (started: ActionStarted) => void) => platformAPI.onActionStarted(callback),
+  onSystemAction: (callback: (action: SystemAction) => void) => platformAPI.onSystemAction(callback as (action: string) => void),
   
   // Profile management - now using electron-adapter
   getProfiles: () => platformAPI.getAllProfiles(),
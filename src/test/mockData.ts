@@ -65,11 +65,11 @@ export const mockConfig: QDeckConfig = {
           cols: 6,
           buttons: [
             {
+              id: 'test-app',
               position: { row: 1, col: 1 },
-              action_type: 'LaunchApp',
               label: 'Test App',
               icon: '🚀',
-              config: {},
+              action: { type: 'LaunchApp', path: 'notepad.exe' },
               style: {
                 background_color: '#007ACC',
                 text_color: '#FFFFFF',
@@ -77,30 +77,17 @@ export const mockConfig: QDeckConfig = {
                 font_family: 'Arial',
                 responsive_font_scaling: true,
               },
-              action: {
-                action_type: 'app',
-                app_config: {
-                  path: 'notepad.exe',
-                },
-              },
             },
             {
+              id: 'test-folder',
               position: { row: 1, col: 2 },
-              action_type: 'Open',
               label: 'Test Folder',
               icon: '📁',
-              config: {},
+              action: { type: 'Open', target: '.' },
               style: {
                 background_color: '#34D399',
                 text_color: '#FFFFFF',
               },
-              action: {
-                action_type: 'command',
-                command_config: {
-                  command: 'explorer',
-                  args: '.',
-                },
-              },
             },
           ],
         },
@@ -110,11 +97,10 @@ export const mockConfig: QDeckConfig = {
 };
 
 export const mockButton: ActionButton = {
+  id: 'test-button',
   position: { row: 1, col: 1 },
-  action_type: 'LaunchApp',
   label: 'Test Button',
   icon: '🚀',
-  config: {},
   style: {
     background_color: '#007ACC',
     text_color: '#FFFFFF',
@@ -146,10 +132,7 @@ export const mockButton: ActionButton = {
       transition_duration: 200,
     },
   },
-  action: {
-    action_type: 'system',
-    system_action: 'config',
-  },
+  action: { type: 'System', system_action: 'config' },
 };
 
 export const mockProfile: ProfileInfo = {
@@ -1,18 +1,19 @@
 // Button-related type definitions
 
 import type { Position } from './grid';
+import type { Page } from './config';
 
 /**
  * Action button configuration
  */
 export interface ActionButton {
+  id: string; // Persistent identifier; the backend assigns one when empty
   position: Position;
-  action_type: ActionType;
   label: string;
-  icon?: string;
-  config: Record<string, any>;
-  style?: ButtonStyle;
-  action?: ActionConfig;
+  icon?: string | null;
+  style?: ButtonStyle | null;
+  hotkey?: string | null; // Runs the button without opening the overlay
+  action: ActionConfig;
 }
 
 /**
@@ -25,17 +26,69 @@ export type ActionType =
   | 'SendKeys'
   | 'PowerShell'
   | 'Folder'
-  | 'MultiAction';
+  | 'MultiAction'
+  | 'System';
 
 /**
- * Action configuration
+ * Action configuration, tagged by `type`
  */
-export interface ActionConfig {
-  action_type: 'system' | 'app' | 'command';
-  system_action?: SystemAction;
-  app_config?: Record<string, any>;
-  command_config?: Record<string, any>;
-}
+export type ActionConfig =
+  | {
+      type: 'LaunchApp';
+      path: string;
+      args?: string[] | null;
+      workdir?: string | null;
+      env?: Record<string, string> | null;
+      timeout_ms?: number | null;
+      wait?: boolean | null; // Wait for the app to exit and report its exit code
+      capture?: boolean | null; // Wait, streaming and returning its output
+    }
+  | {
+      type: 'Open';
+      target: string;
+      verb?: string | null;
+      fallback_editor?: string | null;
+    }
+  | {
+      type: 'Terminal';
+      terminal: string;
+      profile?: string | null;
+      workdir?: string | null;
+      command?: string | null;
+      env?: Record<string, string> | null;
+      args?: string[] | null;
+      timeout_ms?: number | null;
+    }
+  | {
+      type: 'SendKeys';
+      keys: string;
+      delay_ms?: number | null;
+    }
+  | {
+      type: 'PowerShell';
+      script: string;
+      workdir?: string | null;
+      execution_policy?: string | null;
+      shell?: string | null;
+      wait?: boolean | null; // Wait for the script to finish and report its exit code
+      timeout_ms?: number | null;
+      capture?: boolean | null; // Wait, streaming and returning its output
+    }
+  | {
+      type: 'MultiAction';
+      actions: ActionConfig[];
+      delay_between_ms?: number | null;
+      stop_on_error?: boolean | null;
+    }
+  | {
+      type: 'Folder';
+      page?: Page | null; // Inline child page
+      page_ref?: string | null; // Name of another page in the same profile
+    }
+  | {
+      type: 'System';
+      system_action: SystemAction;
+    };
 
 /**
  * System actions
@@ -146,6 +199,7 @@ export interface ActionResult {
   output?: string;
   error_code?: number;
   actionType?: string; // Type of action executed (for auto-close detection)
+  invocation_id?: string; // Identifies the run for cancel_action
 }
 
 /**
@@ -161,6 +215,44 @@ export interface ActionLog {
   context: Record<string, any>;
 }
 
+/**
+ * Output line of an action that waits on its process (action-output event)
+ */
+export interface ActionOutputLine {
+  invocation_id: string;
+  stream: 'stdout' | 'stderr';
+  line: string;
+}
+
+/**
+ * Button action that has begun running (action-started event); pass the ID to cancelAction
+ */
+export interface ActionStarted {
+  invocation_id: string;
+  button_id?: string | null;
+}
+
+/**
+ * Process started by a running action
+ */
+export interface RunningProcess {
+  pid?: number;
+  command: string;
+  timeout_ms?: number;
+}
+
+/**
+ * Action that is still running (list_running_actions)
+ */
+export interface RunningAction {
+  invocation_id: string;
+  button_id?: string;
+  action_type: ActionType;
+  started_at: string;
+  elapsed_ms: number;
+  processes: RunningProcess[];
+}
+
 /**
  * Dropped file information
  */
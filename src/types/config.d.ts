@@ -30,6 +30,12 @@ export interface UIConfig {
 export interface SummonConfig {
   hotkeys: string[];
   edge_trigger?: EdgeTriggerConfig;
+  sequence_timeout_ms?: number; // Time allowed between the keys of a hotkey like "Ctrl+Space, G, P"
+  fallback_hotkeys?: string[]; // Tried in order when none of `hotkeys` can be registered
+  mode?: 'toggle' | 'hold'; // Hold: the overlay shows while the key is down and runs the hovered button on release
+  hold_threshold_ms?: number; // Hold mode: shorter presses leave the overlay open
+  double_tap?: string | null; // Modifier that toggles the overlay when tapped twice, e.g. "Ctrl"
+  double_tap_interval_ms?: number; // Longest tap, and longest gap between the two taps
 }
 
 /**
@@ -47,6 +53,8 @@ export interface EdgeTriggerConfig {
  */
 export interface WindowConfig {
   placement: WindowPlacement;
+  /** "primary", "cursor" (the monitor under the pointer) or a monitor name */
+  monitor?: string;
   width_px: number;
   height_px: number;
   cell_size_px: number;
@@ -66,7 +74,11 @@ export interface WindowConfig {
 export type WindowPlacement = 
   | 'dropdown-top'
   | 'dropdown-bottom'
+  | 'slide-left'
+  | 'slide-right'
   | 'center'
+  | 'at-cursor'
+  | 'remember-last'
   | 'left-dock'
   | 'right-dock'
   | 'custom';
@@ -97,9 +109,19 @@ export interface AnimationConfig {
 export interface Profile {
   name: string;
   hotkey?: string;
+  match?: ProfileMatch | null;
   pages: Page[];
 }
 
+/**
+ * Foreground-window rule for automatic profile switching; every set field must match
+ */
+export interface ProfileMatch {
+  exe?: string | null;
+  window_class?: string | null;
+  title?: string | null; // Regex
+}
+
 /**
  * Page configuration
  */
@@ -107,6 +129,7 @@ export interface Page {
   name: string;
   rows: number;
   cols: number;
+  hotkey?: string | null; // Jumps to this page
   buttons: ActionButton[];
 }
 
@@ -161,9 +184,59 @@ export interface HotkeyConfig {
  */
 export interface ParsedHotkey {
   id: number;
-  modifiers: number;
-  vk_code: number;
+  hotkey: string; // As written in the config, e.g. "Ctrl+Alt+F1" or "Ctrl+Space, G, P"
+  display: string; // Canonical form for display, e.g. "Ctrl+=" for "ctrl++"
+  modifiers: HotkeyModifiers;
+  key: string | Record<string, string | number>; // Platform-neutral key, e.g. "Escape" or { Function: 11 }
+  sequence: { modifiers: HotkeyModifiers; key: string | Record<string, string | number> }[]; // Keys after the leader
   action: string;
+  release_action?: string | null; // Fired when the key is let go after a hold
+}
+
+/**
+ * Outcome of registering the config's hotkeys (get_hotkey_conflict_report)
+ */
+export interface HotkeyConflictReport {
+  duplicates: HotkeyConflict[]; // Skipped: an earlier binding claims the same keys
+  unavailable: UnavailableHotkey[]; // Refused by the OS
+  effective: EffectiveHotkey[];
+}
+
+export interface HotkeyConflict {
+  hotkey: string;
+  source: string; // Config path, e.g. "profiles[1].hotkey"
+  conflicts_with: string;
+  message: string;
+}
+
+export interface UnavailableHotkey {
+  hotkey: string;
+  action: string;
+  source: string;
+  reason: string;
+}
+
+export interface EffectiveHotkey {
+  id: number;
+  hotkey: string;
+  action: string;
+  source: string;
+}
+
+/**
+ * A key accepted in hotkey strings (list_supported_keys)
+ */
+export interface KeyInfo {
+  name: string; // Canonical spelling, e.g. "Numpad5" or "VolumeUp"
+  aliases: string[]; // Also accepted, case-insensitive
+  category: 'letter' | 'digit' | 'function' | 'navigation' | 'editing' | 'system' | 'numpad' | 'media' | 'punctuation';
+}
+
+export interface HotkeyModifiers {
+  ctrl: boolean;
+  alt: boolean;
+  shift: boolean;
+  meta: boolean;
 }
 
 /**
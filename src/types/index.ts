@@ -33,6 +33,10 @@ export type {
   CacheStats,
   ActionResult,
   ActionLog,
+  RunningAction,
+  ActionOutputLine,
+  ActionStarted,
+  RunningProcess,
   DroppedFile,
   DroppedFileType,
   ButtonGenerationRequest,